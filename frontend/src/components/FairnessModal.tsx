@@ -77,7 +77,7 @@ export function FairnessModal({ tableState, onClose }: FairnessModalProps) {
               <div>
                 <h4 className="text-sm font-semibold text-white mb-1">Deck Shuffling</h4>
                 <p className="text-sm text-gray-400 leading-relaxed">
-                  The dealer generates a cryptographic seed and deterministically shuffles the deck using SHA256.
+                  The dealer generates a cryptographic seed and deterministically shuffles the deck with a ChaCha20 stream keyed from it.
                   This shuffle is <strong className="text-[var(--cyan)]">provably random</strong> and can be verified
                   by anyone who knows the seed.
                 </p>
@@ -31,6 +31,9 @@ export function GameControls({
   const myTurnSeat = currentPlayer === 'A' ? 'Player1' : 'Player2'
   const isMyTurn = tableState?.turn_seat === myTurnSeat
 
+  // No-limit: open with BET, otherwise RAISE TO a new total for the street
+  const facingBet = parseFloat(tableState?.current_bet || '0') > 0
+
   // Check if player has joined by looking at table players list
   const hasJoined = tableState?.players?.some(
     p => p.chain_id === (currentPlayer === 'A'
@@ -132,11 +135,15 @@ export function GameControls({
               className="input-stakes flex-1"
             />
             <button
-              onClick={() => handleBet({ Raise: raiseAmount.toString() })}
+              onClick={() => handleBet(
+                facingBet
+                  ? { RaiseTo: raiseAmount.toString() }
+                  : { Bet: raiseAmount.toString() }
+              )}
               disabled={loading}
               className="btn-poker btn-raise"
             >
-              RAISE
+              {facingBet ? 'RAISE TO' : 'BET'}
             </button>
           </div>
 
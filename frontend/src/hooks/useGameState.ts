@@ -55,8 +55,14 @@ const TABLE_STATE_QUERY = `
   }
 `
 
+// Hole cards are sealed to the player's card key: the hand service opens
+// them with the card secret, which only ever goes to the player's own node
 const HAND_STATE_QUERY = `
-  query {
+  query HandState($secret: String!) {
+    dealtCards(secret: $secret) {
+      suit
+      rank
+    }
     state {
       tableChain
       gameId
@@ -75,6 +81,25 @@ const HAND_STATE_QUERY = `
   }
 `
 
+// Deck entropy: each player commits to 32 random bytes when they join and
+// reveals them once the blinds are in, so no one can predict the shuffle
+function randomEntropy(): Uint8Array {
+  return crypto.getRandomValues(new Uint8Array(32))
+}
+
+function toHex(bytes: Uint8Array): string {
+  return Array.from(bytes, (b) => b.toString(16).padStart(2, '0')).join('')
+}
+
+// Must match entropy_commitment in the shared crate
+async function entropyCommitment(entropy: Uint8Array): Promise<string> {
+  const tag = new TextEncoder().encode('LINERA_POKER_ENTROPY')
+  const data = new Uint8Array(tag.length + entropy.length)
+  data.set(tag)
+  data.set(entropy, tag.length)
+  return toHex(new Uint8Array(await crypto.subtle.digest('SHA-256', data)))
+}
+
 // Fetch with error handling - uses HTTP to local linera service
 async function graphqlFetch<T>(
   endpoint: string,
@@ -150,7 +175,7 @@ function transformHandState(data: any): HandState | null {
     table_chain_id: state.tableChain,
     table_app_id: null, // Not exposed by backend
     game_id: state.gameId,
-    hole_cards: state.holeCards || [],
+    hole_cards: data.dealtCards?.length ? data.dealtCards : state.holeCards || [],
     my_turn: state.myTurn || false,
     current_bet: state.currentBet || '0',
     game_result: state.gameResult
@@ -165,8 +190,42 @@ function transformHandState(data: any): HandState | null {
 
 // Mutation queries - Now call TABLE SERVICE directly (not hand service)
 const JOIN_TABLE_MUTATION = `
-  mutation JoinTable($playerChainId: String!, $stake: String!, $handAppId: String) {
-    joinTable(playerChainId: $playerChainId, stake: $stake, handAppId: $handAppId)
+  mutation JoinTable(
+    $playerChainId: String!
+    $stake: String!
+    $handAppId: String
+    $entropyCommitment: String!
+    $cardKey: String!
+  ) {
+    joinTable(
+      playerChainId: $playerChainId
+      stake: $stake
+      handAppId: $handAppId
+      entropyCommitment: $entropyCommitment
+      cardKey: $cardKey
+    )
+  }
+`
+
+const CARD_KEY_QUERY = `
+  query CardKey($secret: String!) {
+    cardKey(secret: $secret)
+  }
+`
+
+const REVEAL_ENTROPY_MUTATION = `
+  mutation RevealEntropy(
+    $playerChainId: String!
+    $gameId: Int!
+    $entropy: String!
+    $nextCommitment: String!
+  ) {
+    revealEntropy(
+      playerChainId: $playerChainId
+      gameId: $gameId
+      entropy: $entropy
+      nextCommitment: $nextCommitment
+    )
   }
 `
 
@@ -240,6 +299,18 @@ export function useGameState(): UseGameStateReturn {
 
   const messageIdCounter = useRef(0)
 
+  // Entropy each player committed to for their next hand (never leaves the
+  // browser until it is revealed), and the last game each one revealed for
+  const entropy = useRef<Record<'A' | 'B', Uint8Array | null>>({ A: null, B: null })
+  const revealedGame = useRef<Record<'A' | 'B', number | null>>({ A: null, B: null })
+
+  // Card secret behind the card key each player registers when they join;
+  // the table seals their hole cards to that key
+  const cardSecret = useRef<Record<'A' | 'B', string>>({
+    A: toHex(randomEntropy()),
+    B: toHex(randomEntropy()),
+  })
+
   // Add a cross-chain message to the log
   const addMessage = useCallback(
     (type: MessageType, from: string, to: string, data?: unknown) => {
@@ -282,7 +353,9 @@ export function useGameState(): UseGameStateReturn {
             PLAYER_A_CHAIN_ID,
             PLAYER_A_HAND_APP_ID
           )
-          const playerAData = await graphqlFetch(playerAEndpoint, HAND_STATE_QUERY)
+          const playerAData = await graphqlFetch(playerAEndpoint, HAND_STATE_QUERY, {
+            secret: cardSecret.current.A,
+          })
           setPlayerAState(transformHandState(playerAData))
           setConnectionStatus(prev => ({ ...prev, playerA: 'connected' }))
         } catch (err) {
@@ -300,7 +373,9 @@ export function useGameState(): UseGameStateReturn {
             PLAYER_B_CHAIN_ID,
             PLAYER_B_HAND_APP_ID
           )
-          const playerBData = await graphqlFetch(playerBEndpoint, HAND_STATE_QUERY)
+          const playerBData = await graphqlFetch(playerBEndpoint, HAND_STATE_QUERY, {
+            secret: cardSecret.current.B,
+          })
           setPlayerBState(transformHandState(playerBData))
           setConnectionStatus(prev => ({ ...prev, playerB: 'connected' }))
         } catch (err) {
@@ -346,14 +421,33 @@ export function useGameState(): UseGameStateReturn {
           throw new Error('Table not configured. Please run deployment script.')
         }
 
+        if (!handAppId) {
+          throw new Error(`Player ${player} hand app not configured. Please run deployment script.`)
+        }
+
+        // The player's own hand service derives the card key from the secret
+        const handEndpoint = buildGraphQLEndpoint(playerChainId, handAppId)
+        const { cardKey } = await graphqlFetch<{ cardKey: string | null }>(
+          handEndpoint,
+          CARD_KEY_QUERY,
+          { secret: cardSecret.current[player] }
+        )
+        if (!cardKey) {
+          throw new Error(`Player ${player} card key unavailable`)
+        }
+
         // Call TABLE service via HTTP
         const tableEndpoint = buildGraphQLEndpoint(TABLE_CHAIN_ID, TABLE_APP_ID)
 
+        const playerEntropy = randomEntropy()
         await graphqlFetch(tableEndpoint, JOIN_TABLE_MUTATION, {
           playerChainId,
           stake: stake.toString(),
-          handAppId: handAppId || null,
+          handAppId,
+          entropyCommitment: await entropyCommitment(playerEntropy),
+          cardKey,
         })
+        entropy.current[player] = playerEntropy
 
         // Log the action
         addMessage(
@@ -376,6 +470,49 @@ export function useGameState(): UseGameStateReturn {
     [fetchState, addMessage]
   )
 
+  // Reveal each player's entropy once the table is waiting on it to shuffle,
+  // committing to fresh entropy for the next hand
+  useEffect(() => {
+    if (!tableState || tableState.phase !== 'Dealing' || !TABLE_CHAIN_ID || !TABLE_APP_ID) {
+      return
+    }
+    const tableEndpoint = buildGraphQLEndpoint(TABLE_CHAIN_ID, TABLE_APP_ID)
+    const gameId = tableState.game_id
+
+    for (const player of ['A', 'B'] as const) {
+      const playerChainId = player === 'A' ? PLAYER_A_CHAIN_ID : PLAYER_B_CHAIN_ID
+      const playerEntropy = entropy.current[player]
+      if (
+        !playerChainId ||
+        !playerEntropy ||
+        revealedGame.current[player] === gameId ||
+        !tableState.players.some((p) => p.chain_id === playerChainId)
+      ) {
+        continue
+      }
+      revealedGame.current[player] = gameId
+
+      const nextEntropy = randomEntropy()
+      entropyCommitment(nextEntropy)
+        .then((nextCommitment) =>
+          graphqlFetch(tableEndpoint, REVEAL_ENTROPY_MUTATION, {
+            playerChainId,
+            gameId,
+            entropy: toHex(playerEntropy),
+            nextCommitment,
+          })
+        )
+        .then(() => {
+          entropy.current[player] = nextEntropy
+          addMessage('RevealEntropy', `Player ${player}`, 'Table', { gameId })
+        })
+        .catch((err) => {
+          console.error('Entropy reveal failed:', err)
+          revealedGame.current[player] = null
+        })
+    }
+  }, [tableState, addMessage])
+
   // Bet action - calls TABLE service via HTTP
   const bet = useCallback(
     async (player: 'A' | 'B', action: BetAction) => {
@@ -402,8 +539,10 @@ export function useGameState(): UseGameStateReturn {
           actionInput = { actionType: 'CHECK' }
         } else if ('Call' in action) {
           actionInput = { actionType: 'CALL' }
-        } else if ('Raise' in action) {
-          actionInput = { actionType: 'RAISE', amount: action.Raise }
+        } else if ('Bet' in action) {
+          actionInput = { actionType: 'BET', amount: action.Bet }
+        } else if ('RaiseTo' in action) {
+          actionInput = { actionType: 'RAISE_TO', amount: action.RaiseTo }
         } else if ('AllIn' in action) {
           actionInput = { actionType: 'ALL_IN' }
         } else if ('Fold' in action) {
@@ -34,7 +34,8 @@ export type Seat = 'Player1' | 'Player2'
 export type BetAction =
   | { Check: null }
   | { Call: null }
-  | { Raise: string }
+  | { Bet: string }
+  | { RaiseTo: string }
   | { AllIn: null }
   | { Fold: null }
 
@@ -135,6 +136,7 @@ export type MessageType =
   | 'GameResult'
   | 'Settlement'
   | 'StartNewGame'
+  | 'RevealEntropy'
 
 export interface CrossChainMessage {
   id: string
@@ -5,11 +5,13 @@ mod state;
 use self::state::HandState;
 use linera_poker_hand::{
     BetAction, Card, CardReveal, GamePhase, GameResultInfo, HandAbi, HandOperation, HandResult,
-    InstantiationArgument, Message,
+    InstantiationArgument, MaskedCard, MentalRequest, MentalResponse, Message,
 };
-use linera_poker_shared::{DealingProof, RevealProof};
+use linera_poker_shared::{BoardCardProof, DealingProof, DrawProof, RevealProof};
+use linera_poker_shared::zk::{proof_size_for, verify_board_card, verify_draw_proof};
+use linera_poker_token::{TokenAbi, TokenOperation, TokenResult};
 use linera_sdk::{
-    linera_base_types::{Amount, WithContractAbi},
+    linera_base_types::{Amount, ApplicationId, ChainId, WithContractAbi},
     views::{RootView, View},
     Contract, ContractRuntime,
 };
@@ -48,17 +50,46 @@ impl Contract for HandContract {
         self.state.current_bet.set(Amount::ZERO);
         self.state.my_turn.set(false);
         self.state.game_result.set(None);
+        self.state.cashed_out.set(None);
+        self.state.tournament_finish.set(None);
+        self.state.entropy_request.set(None);
+        self.state.mental_request.set(None);
+        self.state.mental_hole_cards.set(Vec::new());
         self.state.dealer_secret.set(Vec::new());
     }
 
     async fn execute_operation(&mut self, operation: HandOperation) -> HandResult {
         match operation {
-            HandOperation::JoinTable { stake } => {
-                self.join_table(Amount::from_tokens(stake.into())).await
-            }
+            HandOperation::JoinTable {
+                stake,
+                entropy_commitment,
+                card_key,
+                token_app,
+            } => self.join_table(stake, entropy_commitment, card_key, token_app).await,
+            HandOperation::RevealEntropy {
+                entropy,
+                next_commitment,
+            } => self.reveal_entropy(entropy, next_commitment),
             HandOperation::Bet { action } => self.send_bet_action(action).await,
-            HandOperation::Reveal => self.reveal_cards().await,
+            HandOperation::Draw { discarded } => self.send_draw(discarded).await,
+            HandOperation::Reveal { cards, randomness } => {
+                self.reveal_cards(cards, randomness).await
+            }
             HandOperation::LeaveTable => self.leave_table().await,
+            HandOperation::SitOut => self.send_to_table(Message::SitOut),
+            HandOperation::SitIn => self.send_to_table(Message::SitIn),
+            HandOperation::TopUp { amount } => self.send_to_table(Message::TopUp {
+                amount: Amount::from_tokens(amount.into()),
+            }),
+            HandOperation::CashOut => self.send_to_table(Message::CashOut),
+            HandOperation::AcceptDeal { kind } => {
+                self.send_to_table(Message::AcceptDeal { kind })
+            }
+            HandOperation::Mental { response } => self.send_mental_response(response),
+            HandOperation::MoveTable {
+                table_chain,
+                table_app,
+            } => self.move_table(table_chain, table_app),
         }
     }
 
@@ -115,21 +146,29 @@ impl Contract for HandContract {
                 self.handle_deal_cards_zk(game_id, dealing_proof);
             }
 
+            // ZK-SNARK replacement cards for a draw
+            Message::DrawCardsZK {
+                game_id,
+                draw_proof,
+            } => {
+                // Only process if we're on a player chain (source should be table)
+                if source_chain != table_chain {
+                    return; // Reject messages from unauthorized chains
+                }
+                self.handle_draw_cards_zk(game_id, draw_proof);
+            }
+
             // ZK-SNARK community cards (Phase 3)
             Message::CommunityCardsZK {
                 game_id,
-                phase,
-                dealing_proof,
+                phase: _,
+                board,
             } => {
                 // Only process if we're on a player chain (source should be table)
                 if source_chain != table_chain {
                     return; // Reject messages from unauthorized chains
                 }
-                // For community cards, we just need to track the phase
-                // The actual cards are stored in the proof commitments
-                self.state.game_id.set(Some(game_id));
-                // Store deck root for verification
-                self.state.table_deck_root.set(Some(dealing_proof.deck_root));
+                self.handle_community_cards_zk(game_id, board);
             }
             Message::RequestReveal { game_id: _ } => {
                 // Only process if we're on a player chain (source should be table)
@@ -156,21 +195,30 @@ impl Contract for HandContract {
                 you_won,
                 payout,
                 opponent_cards,
-                forfeited,
+                forfeited: _, // Phase 3: opponent auto-forfeits aren't tracked yet
+                uncalled_refund,
+                awards,
+                rake,
             } => {
                 // Only process if we're on a player chain (source should be table)
                 if source_chain != table_chain {
                     return; // Reject messages from unauthorized chains
                 }
-                self.handle_game_result(game_id, you_won, payout, opponent_cards, forfeited);
+                let result = GameResultInfo {
+                    won: you_won,
+                    payout,
+                    my_cards: self.state.hole_cards.get().clone(),
+                    opponent_cards,
+                    uncalled_refund,
+                    awards,
+                    rake,
+                };
+                self.handle_game_result(game_id, result);
             }
 
             // RELAY messages from player chains to table app
             // These messages arrive here when sent to table_chain via send_to()
-            Message::JoinTable {
-                stake: _,
-                hand_app_id: _,
-            } => {
+            Message::JoinTable { .. } => {
                 if is_relay {
                     // We're the relay on table chain - forward to table app
                     self.relay_to_table(message).await;
@@ -192,6 +240,15 @@ impl Contract for HandContract {
                     self.relay_to_table(message).await;
                 }
             }
+            Message::Draw {
+                game_id: _,
+                discarded: _,
+            } => {
+                if is_relay {
+                    // We're the relay on table chain - forward to table app
+                    self.relay_to_table(message).await;
+                }
+            }
             #[allow(deprecated)]
             Message::RevealCards {
                 game_id: _,
@@ -224,6 +281,53 @@ impl Contract for HandContract {
                     self.relay_to_table(message).await;
                 }
             }
+            Message::SitOut
+            | Message::SitIn
+            | Message::TopUp { .. }
+            | Message::CashOut
+            | Message::AcceptDeal { .. }
+            | Message::RevealEntropy { .. }
+            | Message::MentalResponse { .. } => {
+                if is_relay {
+                    // We're the relay on table chain - forward to table app
+                    self.relay_to_table(message).await;
+                }
+            }
+            Message::CashedOut { amount } => {
+                // Only process if we're on a player chain (source should be table)
+                if source_chain != table_chain {
+                    return; // Reject messages from unauthorized chains
+                }
+                self.handle_cashed_out(amount);
+            }
+            Message::TournamentFinished { place, prize } => {
+                // Only process if we're on a player chain (source should be table)
+                if source_chain != table_chain {
+                    return; // Reject messages from unauthorized chains
+                }
+                self.handle_tournament_finished(place, prize);
+            }
+            Message::RequestEntropy { game_id } => {
+                // Only process if we're on a player chain (source should be table)
+                if source_chain != table_chain {
+                    return; // Reject messages from unauthorized chains
+                }
+                self.state.entropy_request.set(Some(game_id));
+            }
+            Message::MentalRequest { request } => {
+                // Only process if we're on a player chain (source should be table)
+                if source_chain != table_chain {
+                    return; // Reject messages from unauthorized chains
+                }
+                self.handle_mental_request(request);
+            }
+            Message::MentalHoleCards { game_id, cards } => {
+                // Only process if we're on a player chain (source should be table)
+                if source_chain != table_chain {
+                    return; // Reject messages from unauthorized chains
+                }
+                self.handle_mental_hole_cards(game_id, cards);
+            }
         }
     }
 
@@ -253,16 +357,28 @@ impl HandContract {
         use linera_poker_table::TableOperation;
 
         let operation = match message {
-            Message::JoinTable { stake, hand_app_id } => TableOperation::RelayJoinTable {
+            Message::JoinTable {
+                stake,
+                hand_app_id,
+                entropy_commitment,
+                card_key,
+            } => TableOperation::RelayJoinTable {
                 player_chain: source_chain,
                 stake,
                 hand_app_id,
+                entropy_commitment,
+                card_key,
             },
             Message::BetAction { game_id, action } => TableOperation::RelayBetAction {
                 player_chain: source_chain,
                 game_id,
                 action,
             },
+            Message::Draw { game_id, discarded } => TableOperation::RelayDraw {
+                player_chain: source_chain,
+                game_id,
+                discarded,
+            },
             Message::RevealCards {
                 game_id,
                 cards,
@@ -280,6 +396,38 @@ impl HandContract {
                 player_chain: source_chain,
                 game_id,
             },
+            Message::SitOut => TableOperation::RelaySitOut {
+                player_chain: source_chain,
+            },
+            Message::SitIn => TableOperation::RelaySitIn {
+                player_chain: source_chain,
+            },
+            Message::TopUp { amount } => TableOperation::RelayTopUp {
+                player_chain: source_chain,
+                amount,
+            },
+            Message::CashOut => TableOperation::RelayCashOut {
+                player_chain: source_chain,
+            },
+            Message::AcceptDeal { kind } => TableOperation::RelayAcceptDeal {
+                player_chain: source_chain,
+                kind,
+            },
+            Message::RevealEntropy {
+                game_id,
+                entropy,
+                next_commitment,
+            } => TableOperation::RelayRevealEntropy {
+                player_chain: source_chain,
+                game_id,
+                entropy,
+                next_commitment,
+            },
+            Message::MentalResponse { game_id, response } => TableOperation::RelayMental {
+                player_chain: source_chain,
+                game_id,
+                response,
+            },
             // Table->Hand messages should not be relayed
             _ => return,
         };
@@ -300,7 +448,13 @@ impl HandContract {
     }
 
     /// Join a table
-    async fn join_table(&mut self, stake: Amount) -> HandResult {
+    async fn join_table(
+        &mut self,
+        stake: u64,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+        token_app: Option<ApplicationId>,
+    ) -> HandResult {
         if self.state.game_id.get().is_some() {
             return HandResult::Error(linera_poker_hand::HandError::AlreadyInGame);
         }
@@ -312,10 +466,28 @@ impl HandContract {
 
         let our_app_id = self.runtime.application_id();
 
+        // Pay the buy-in first: the table only seats us once it holds it
+        if let Some(token_app) = token_app {
+            let paid = self.runtime.call_application(
+                /* authenticated */ true,
+                token_app.with_abi::<TokenAbi>(),
+                &TokenOperation::Transfer {
+                    to_chain: table_chain,
+                    amount: stake,
+                    game_id: 0,
+                },
+            );
+            if !matches!(paid, TokenResult::Success) {
+                return HandResult::Error(linera_poker_hand::HandError::BuyInNotPaid);
+            }
+        }
+
         self.runtime
             .prepare_message(Message::JoinTable {
-                stake,
+                stake: Amount::from_tokens(stake.into()),
                 hand_app_id: our_app_id.forget_abi(),
+                entropy_commitment,
+                card_key,
             })
             .with_authentication()
             .send_to(table_chain);
@@ -323,6 +495,70 @@ impl HandContract {
         HandResult::Success
     }
 
+    /// Reveal our deck entropy for the hand the table is waiting to shuffle
+    fn reveal_entropy(&mut self, entropy: [u8; 32], next_commitment: [u8; 32]) -> HandResult {
+        let game_id = match *self.state.entropy_request.get() {
+            Some(game_id) => game_id,
+            None => return HandResult::Error(linera_poker_hand::HandError::InvalidState),
+        };
+        self.state.entropy_request.set(None);
+        self.send_to_table(Message::RevealEntropy {
+            game_id,
+            entropy,
+            next_commitment,
+        })
+    }
+
+    /// Dealerless: keep the table's request until we answer it
+    ///
+    /// Share requests for the same hand add up, so the answer covers every
+    /// card asked for; anything else replaces what was pending.
+    fn handle_mental_request(&mut self, request: MentalRequest) {
+        let merged = match (self.state.mental_request.get().clone(), request) {
+            (
+                Some(MentalRequest::Shares { game_id, mut cards }),
+                MentalRequest::Shares {
+                    game_id: requested,
+                    cards: more,
+                },
+            ) if game_id == requested => {
+                for (position, card) in more {
+                    if cards.iter().all(|(p, _)| *p != position) {
+                        cards.push((position, card));
+                    }
+                }
+                MentalRequest::Shares { game_id, cards }
+            }
+            (_, request) => request,
+        };
+        self.state.mental_request.set(Some(merged));
+    }
+
+    /// Dealerless: our hole cards for the hand, encrypted under our key alone
+    ///
+    /// A deal restarted without a player who stalled sends new ones for the
+    /// same hand, which replace the old.
+    fn handle_mental_hole_cards(&mut self, game_id: u64, cards: Vec<MaskedCard>) {
+        if matches!(*self.state.game_id.get(), Some(current) if game_id < current) {
+            return;
+        }
+        self.state.game_id.set(Some(game_id));
+        self.state.hole_cards.set(Vec::new());
+        self.state.community_cards.set(Vec::new());
+        self.state.game_result.set(None);
+        self.state.mental_hole_cards.set(cards);
+    }
+
+    /// Dealerless: send our answer to the pending request to the table
+    fn send_mental_response(&mut self, response: MentalResponse) -> HandResult {
+        let game_id = match self.state.mental_request.get() {
+            Some(request) => request.game_id(),
+            None => return HandResult::Error(linera_poker_hand::HandError::InvalidState),
+        };
+        self.state.mental_request.set(None);
+        self.send_to_table(Message::MentalResponse { game_id, response })
+    }
+
     /// Handle receiving cards (DEPRECATED - legacy plaintext mode)
     #[allow(deprecated)]
     fn handle_community_cards(&mut self, game_id: u64, phase: GamePhase, cards: Vec<CardReveal>) {
@@ -355,45 +591,114 @@ impl HandContract {
     /// Handle ZK card dealing (Phase 3: Production-Ready Privacy)
     ///
     /// Receives hole cards via ZK dealing proof from the table.
-    /// The cards are extracted from the commitments but the proof
-    /// ensures the dealer can't cheat.
+    /// The cards and the openings of their commitments arrive sealed to our
+    /// card key: they are kept as they are and opened off-chain by our client
+    /// (see `dealtCards` in the service), while the proof ensures the dealer
+    /// can't cheat.
     fn handle_deal_cards_zk(&mut self, game_id: u64, dealing_proof: DealingProof) {
-        if self.state.game_id.get() != &Some(game_id) && self.state.game_id.get().is_some() {
+        // One sealed opening per commitment, or we couldn't play the cards
+        if dealing_proof.sealed_openings.len() != dealing_proof.card_commitments.len() {
             return;
         }
 
+        // A newer game ID is the next hand at a cash-game table; the same one
+        // is a later stud street dealing more down cards
+        let same_hand = match *self.state.game_id.get() {
+            Some(current) if game_id < current => return,
+            Some(current) if game_id > current => {
+                self.clear_hand();
+                false
+            }
+            Some(_) => true,
+            None => false,
+        };
+
         self.state.game_id.set(Some(game_id));
 
         // Store the commitments for later reveal proof generation
-        self.state.card_commitments.set(Some(dealing_proof.card_commitments.to_vec()));
+        let mut commitments = match self.state.card_commitments.get() {
+            Some(held) if same_hand => held.clone(),
+            _ => Vec::new(),
+        };
+        commitments.extend(dealing_proof.card_commitments.iter().cloned());
+        self.state.card_commitments.set(Some(commitments));
 
         // Store the deck root for verification
         self.state.table_deck_root.set(Some(dealing_proof.deck_root));
 
-        // Phase 3: Extract cards from commitments
-        // In a real implementation, the player would derive the cards from
-        // their own knowledge. For Phase 3, we use the commitment nonce
-        // as a deterministic way to "recover" the card index.
-        // This is a mock - Phase 4 will use proper ZK card extraction.
-        let extracted_cards = self.extract_cards_from_commitments(&dealing_proof.card_commitments);
-        self.state.hole_cards.set(extracted_cards);
+        // Keep our cards sealed alongside their commitments
+        let mut sealed_openings = if same_hand {
+            self.state.sealed_openings.get().clone()
+        } else {
+            Vec::new()
+        };
+        sealed_openings.extend(dealing_proof.sealed_openings);
+        self.state.sealed_openings.set(sealed_openings);
     }
 
-    /// Extract cards from ZK commitments (Phase 3: Mock implementation)
+    /// Handle replacement cards for a draw
     ///
-    /// In Phase 3, we use the nonce to deterministically derive the card index.
-    /// This is NOT cryptographically secure - Phase 4 will use proper ZK extraction.
-    fn extract_cards_from_commitments(&self, commitments: &[linera_poker_shared::CardCommitment]) -> Vec<Card> {
-        use linera_poker_shared::Card;
+    /// The draw proof must be for the deck we were dealt from; each
+    /// replacement takes the place of the discarded card, both in our sealed
+    /// cards and in the commitments used for the reveal proof.
+    fn handle_draw_cards_zk(&mut self, game_id: u64, draw_proof: DrawProof) {
+        if self.state.game_id.get() != &Some(game_id) {
+            return;
+        }
+
+        let deck_root = match self.state.table_deck_root.get() {
+            Some(root) => *root,
+            None => return,
+        };
+        let mut commitments = self.state.card_commitments.get().clone().unwrap_or_default();
+        if !verify_draw_proof(&draw_proof, commitments.len(), &deck_root)
+            || draw_proof.sealed_openings.len() != draw_proof.card_commitments.len()
+        {
+            return; // Replacements not provably from the undealt deck
+        }
 
-        commitments
+        let mut sealed_openings = self.state.sealed_openings.get().clone();
+        for ((&position, commitment), sealed) in draw_proof
+            .discarded
             .iter()
-            .filter_map(|commitment| {
-                // Use first byte of nonce as card index (mod 52)
-                let card_idx = commitment.nonce[0] % 52;
-                Card::from_index(card_idx)
-            })
-            .collect()
+            .zip(draw_proof.card_commitments)
+            .zip(draw_proof.sealed_openings)
+        {
+            if let Some(slot) = commitments.get_mut(position as usize) {
+                *slot = commitment;
+            }
+            if let Some(slot) = sealed_openings.get_mut(position as usize) {
+                *slot = sealed;
+            }
+        }
+        self.state.card_commitments.set(Some(commitments));
+        self.state.sealed_openings.set(sealed_openings);
+    }
+
+    /// Handle board cards opened by the table (ZK path)
+    ///
+    /// Each card must be the leaf at its deck position under the deck root
+    /// we were dealt under, so the table can't pick the board after the deal.
+    fn handle_community_cards_zk(&mut self, game_id: u64, board: Vec<BoardCardProof>) {
+        if self.state.game_id.get() != &Some(game_id) {
+            return;
+        }
+
+        let deck_root = match self.state.table_deck_root.get() {
+            Some(root) => *root,
+            None => return,
+        };
+        if !board.iter().all(|proof| verify_board_card(proof, &deck_root)) {
+            return; // Not the board committed to at the deal
+        }
+
+        let mut community = self.state.community_cards.get().clone();
+        for proof in board {
+            if !community.contains(&proof.card) {
+                community.push(proof.card);
+            }
+        }
+        self.state.community_cards.set(community);
     }
 
     /// Handle it's our turn
@@ -433,11 +738,54 @@ impl HandContract {
         HandResult::Success
     }
 
+    /// Draw: send the positions of the hole cards we discard
+    async fn send_draw(&mut self, discarded: Vec<u8>) -> HandResult {
+        if !*self.state.my_turn.get() {
+            return HandResult::Error(linera_poker_hand::HandError::NotYourTurn);
+        }
+
+        let game_id = match self.state.game_id.get() {
+            Some(id) => *id,
+            None => return HandResult::Error(linera_poker_hand::HandError::InvalidState),
+        };
+
+        let table_chain = match self.state.table_chain.get() {
+            Some(c) => *c,
+            None => return HandResult::Error(linera_poker_hand::HandError::NotRegistered),
+        };
+
+        // Each discard must be a different card we hold
+        let hand_size = self
+            .state
+            .card_commitments
+            .get()
+            .as_ref()
+            .map_or(0, Vec::len);
+        let mut distinct = discarded.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() != discarded.len()
+            || discarded.iter().any(|&i| i as usize >= hand_size)
+        {
+            return HandResult::Error(linera_poker_hand::HandError::InvalidDiscard);
+        }
+
+        self.runtime
+            .prepare_message(Message::Draw { game_id, discarded })
+            .with_authentication()
+            .send_to(table_chain);
+
+        self.state.my_turn.set(false);
+
+        HandResult::Success
+    }
+
     /// Reveal our cards
     ///
-    /// Phase 3: Uses RevealCardsZK when ZK mode is enabled (card_commitments present).
+    /// Phase 3: Uses RevealCardsZK when ZK mode is enabled (card_commitments present),
+    /// once the cards and blinding factors open the commitments we were dealt.
     /// Falls back to legacy RevealCards for backward compatibility.
-    async fn reveal_cards(&mut self) -> HandResult {
+    async fn reveal_cards(&mut self, cards: Vec<Card>, randomness: Vec<Vec<u8>>) -> HandResult {
         let game_id = match self.state.game_id.get() {
             Some(id) => *id,
             None => return HandResult::Error(linera_poker_hand::HandError::InvalidState),
@@ -448,15 +796,27 @@ impl HandContract {
             None => return HandResult::Error(linera_poker_hand::HandError::NotRegistered),
         };
 
-        let cards = self.state.hole_cards.get().clone();
-
         // Check if we're in ZK mode (have card commitments)
-        if self.state.card_commitments.get().is_some() {
+        if let Some(commitments) = self.state.card_commitments.get() {
+            let opens = cards.len() == commitments.len()
+                && randomness.len() == commitments.len()
+                && commitments
+                    .iter()
+                    .zip(&cards)
+                    .zip(&randomness)
+                    .all(|((commitment, card), blinding)| commitment.opens(*card, blinding));
+            if !opens {
+                return HandResult::Error(linera_poker_hand::HandError::InvalidOpening);
+            }
+
+            // Shown from now on, so they can be kept in the clear
+            self.state.hole_cards.set(cards.clone());
+
             // ZK mode: Send RevealCardsZK with proof
             let reveal_proof = RevealProof {
-                proof: vec![0u8; RevealProof::PROOF_SIZE],  // Phase 3: Mock proof
-                cards: cards.clone(),
-                randomness: vec![],  // Phase 3: Not needed for mock verification
+                proof: vec![0u8; proof_size_for(cards.len())],  // Phase 3: Mock proof per card pair
+                cards,
+                randomness,
             };
 
             self.runtime
@@ -496,29 +856,72 @@ impl HandContract {
     }
 
     /// Handle game result
-    fn handle_game_result(
-        &mut self,
-        game_id: u64,
-        won: bool,
-        payout: Amount,
-        opponent_cards: Option<Vec<Card>>,
-        _forfeited: bool,  // Phase 3: Track if opponent was auto-forfeited
-    ) {
+    fn handle_game_result(&mut self, game_id: u64, result: GameResultInfo) {
         if self.state.game_id.get() != &Some(game_id) {
             return;
         }
 
-        self.state.game_result.set(Some(GameResultInfo {
-            won,
-            payout,
-            my_cards: self.state.hole_cards.get().clone(),
-            opponent_cards,
-        }));
+        self.state.game_result.set(Some(result));
 
         self.state.my_turn.set(false);
         self.state.turn_deadline_block.set(None);
     }
 
+    /// Send `message` to the table we sit at, authenticated as this chain
+    /// (session requests, deal acceptance, entropy and dealerless responses)
+    fn send_to_table(&mut self, message: Message) -> HandResult {
+        let table_chain = match self.state.table_chain.get() {
+            Some(c) => *c,
+            None => return HandResult::Error(linera_poker_hand::HandError::NotRegistered),
+        };
+
+        self.runtime
+            .prepare_message(message)
+            .with_authentication()
+            .send_to(table_chain);
+
+        HandResult::Success
+    }
+
+    /// Handle the table paying out our stack after a cash out
+    fn handle_cashed_out(&mut self, amount: Amount) {
+        self.state.cashed_out.set(Some(amount));
+        self.state.game_id.set(None);
+        self.clear_hand();
+    }
+
+    /// Handle being knocked out of a tournament (or winning it)
+    fn handle_tournament_finished(&mut self, place: u32, prize: Amount) {
+        self.state.tournament_finish.set(Some((place, prize)));
+        self.state.game_id.set(None);
+        self.clear_hand();
+    }
+
+    /// Multi-table tournament: take messages from, and send actions to, the
+    /// table the player now sits at
+    fn move_table(&mut self, table_chain: ChainId, table_app: ApplicationId) -> HandResult {
+        self.state.table_chain.set(Some(table_chain));
+        self.state.table_app.set(Some(table_app));
+        self.state.seat.set(None);
+        self.state.game_id.set(None);
+        self.state.entropy_request.set(None);
+        self.clear_hand();
+        HandResult::Success
+    }
+
+    /// Forget everything about the previous hand
+    fn clear_hand(&mut self) {
+        self.state.hole_cards.set(Vec::new());
+        self.state.card_commitments.set(None);
+        self.state.sealed_openings.set(Vec::new());
+        self.state.mental_request.set(None);
+        self.state.mental_hole_cards.set(Vec::new());
+        self.state.community_cards.set(Vec::new());
+        self.state.my_turn.set(false);
+        self.state.game_result.set(None);
+        self.state.turn_deadline_block.set(None);
+    }
+
     /// Leave the table
     async fn leave_table(&mut self) -> HandResult {
         let table_chain = match self.state.table_chain.get() {
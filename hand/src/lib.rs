@@ -8,7 +8,8 @@ use linera_sdk::linera_base_types::{ApplicationId, ChainId, ContractAbi, Service
 use serde::{Deserialize, Serialize};
 
 pub use linera_poker_shared::{
-    BetAction, Card, CardReveal, EncryptedCard, GamePhase, GameResultInfo, Seat,
+    BetAction, Card, CardReveal, DealKind, EncryptedCard, GamePhase, GameResultInfo, MaskedCard,
+    MentalRequest, MentalResponse, PotAward, SealedOpening, Seat,
 };
 
 /// Hand contract ABI
@@ -46,19 +47,67 @@ pub enum HandError {
     Unauthorized,
     #[error("Invalid message source")]
     InvalidSource,
+    #[error("Invalid discard")]
+    InvalidDiscard,
+    #[error("Cards don't open the commitments they were dealt with")]
+    InvalidOpening,
+    #[error("Buy-in could not be paid")]
+    BuyInNotPaid,
 }
 
 /// Hand operations (called by player on their own chain)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum HandOperation {
-    /// Join a table with stake
-    JoinTable { stake: u64 },
+    /// Join a table with stake, committing to the entropy for the first hand
+    /// (the commitment is `entropy_commitment` of 32 bytes kept off-chain)
+    /// and registering the card key our cards are sealed to (`zk::card_key`
+    /// of a secret kept off-chain).
+    ///
+    /// A tournament buy-in is paid to the table chain through `token_app`,
+    /// the tournament's token application, before joining.
+    JoinTable {
+        stake: u64,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+        token_app: Option<ApplicationId>,
+    },
+    /// Reveal the entropy the table asked for and commit to the next
+    RevealEntropy {
+        entropy: [u8; 32],
+        next_commitment: [u8; 32],
+    },
     /// Send a betting action
     Bet { action: BetAction },
-    /// Reveal cards for showdown
-    Reveal,
+    /// Draw: discard the hole cards at these positions for replacements
+    /// (empty = stand pat)
+    Draw { discarded: Vec<u8> },
+    /// Reveal cards for showdown, with the blinding factors that open their
+    /// commitments (opened off-chain from our sealed cards, see `reveal`)
+    Reveal {
+        cards: Vec<Card>,
+        randomness: Vec<Vec<u8>>,
+    },
     /// Leave the table
     LeaveTable,
+    /// Cash game: stop being dealt in but keep the seat and stack
+    SitOut,
+    /// Cash game: be dealt in again from the next hand
+    SitIn,
+    /// Cash game: add chips to the stack before the next hand
+    TopUp { amount: u64 },
+    /// Cash game: leave with the stack once the current hand ends
+    CashOut,
+    /// Sit-and-go: agree to settle with a deal at the end of this hand
+    /// (None withdraws)
+    AcceptDeal { kind: Option<DealKind> },
+    /// Dealerless: answer the table's pending request with a key share,
+    /// shuffle or decryption shares computed off-chain (see `mentalRespond`)
+    Mental { response: MentalResponse },
+    /// Multi-table tournament: play at the table the player was moved to
+    MoveTable {
+        table_chain: ChainId,
+        table_app: ApplicationId,
+    },
 }
 
 /// Instantiation argument
@@ -6,9 +6,14 @@ use std::sync::Arc;
 
 use self::state::HandState;
 use async_graphql::{EmptySubscription, Enum, InputObject, Object, Request, Response, Schema};
-use linera_poker_hand::{BetAction, HandAbi, HandOperation};
+use hex::FromHex;
+use linera_poker_hand::{
+    BetAction, Card, DealKind, GameResultInfo, HandAbi, HandOperation, MentalRequest,
+};
+use linera_poker_shared::mental::{mental_context, mental_response, open_card};
+use linera_poker_shared::zk::{card_key, open_sealed};
 use linera_sdk::{
-    linera_base_types::{Amount, WithServiceAbi},
+    linera_base_types::{Amount, ApplicationId, WithServiceAbi},
     views::View,
     Service, ServiceRuntime,
 };
@@ -43,6 +48,7 @@ impl Service for HandService {
                 state: self.state.clone(),
             },
             MutationRoot {
+                state: self.state.clone(),
                 runtime: self.runtime.clone(),
             },
             EmptySubscription,
@@ -91,10 +97,7 @@ impl QueryRoot {
                 .game_result
                 .get()
                 .as_ref()
-                .map(|r| GameResultView {
-                    won: r.won,
-                    payout: r.payout.to_string(),
-                }),
+                .map(GameResultView::from),
         }
     }
 
@@ -103,7 +106,7 @@ impl QueryRoot {
         *self.state.game_id.get()
     }
 
-    /// Get our hole cards (PRIVATE!)
+    /// Get our hole cards once shown (PRIVATE!)
     async fn hole_cards(&self) -> Vec<CardView> {
         self.state
             .hole_cards
@@ -116,6 +119,26 @@ impl QueryRoot {
             .collect()
     }
 
+    /// Card key for our card secret (hex), to register when joining a table
+    async fn card_key(&self, secret: String) -> Option<String> {
+        let secret = Vec::from_hex(&secret).ok()?;
+        Some(hex::encode(card_key(&secret)))
+    }
+
+    /// Our hole cards, opened here from what the table sealed to our card key
+    /// with our card secret (hex; it never leaves this node). Empty if they
+    /// don't open the commitments they were dealt with.
+    async fn dealt_cards(&self, secret: String) -> Vec<CardView> {
+        open_dealt_cards(&self.state, &secret)
+            .unwrap_or_default()
+            .iter()
+            .map(|(c, _)| CardView {
+                suit: format!("{:?}", c.suit),
+                rank: format!("{:?}", c.rank),
+            })
+            .collect()
+    }
+
     /// Get community cards
     async fn community_cards(&self) -> Vec<CardView> {
         self.state
@@ -134,48 +157,159 @@ impl QueryRoot {
         *self.state.my_turn.get()
     }
 
+    /// Game the table is waiting on our deck entropy for
+    async fn entropy_request(&self) -> Option<u64> {
+        *self.state.entropy_request.get()
+    }
+
+    /// Dealerless: what the table is waiting on us for (answer it with
+    /// `mentalRespond`)
+    async fn mental_request(&self) -> Option<MentalRequestView> {
+        let request = self.state.mental_request.get().as_ref()?;
+        let (kind, cards) = match request {
+            MentalRequest::Key { .. } => ("Key", 0),
+            MentalRequest::Shuffle { deck, .. } => ("Shuffle", deck.len()),
+            MentalRequest::Shares { cards, .. } => ("Shares", cards.len()),
+        };
+        Some(MentalRequestView {
+            game_id: request.game_id(),
+            kind: kind.to_string(),
+            cards: cards as u32,
+        })
+    }
+
+    /// Dealerless: our hole cards, opened here with our secret for the hand
+    /// (hex; it never leaves this node)
+    async fn mental_hole_cards(&self, secret: String) -> Vec<CardView> {
+        let (secret, table_chain, game_id) = match (
+            Vec::from_hex(&secret),
+            *self.state.table_chain.get(),
+            *self.state.game_id.get(),
+        ) {
+            (Ok(secret), Some(table_chain), Some(game_id)) => (secret, table_chain, game_id),
+            _ => return Vec::new(),
+        };
+        let context = mental_context(table_chain, game_id);
+        self.state
+            .mental_hole_cards
+            .get()
+            .iter()
+            .filter_map(|card| open_card(&secret, &context, card))
+            .map(|c| CardView {
+                suit: format!("{:?}", c.suit),
+                rank: format!("{:?}", c.rank),
+            })
+            .collect()
+    }
+
+    /// Stack paid back after cashing out of a cash game
+    async fn cashed_out(&self) -> Option<String> {
+        self.state.cashed_out.get().map(|a| a.to_string())
+    }
+
+    /// Tournament: where we finished and the prize paid
+    async fn tournament_finish(&self) -> Option<TournamentFinishView> {
+        self.state
+            .tournament_finish
+            .get()
+            .map(|(place, prize)| TournamentFinishView {
+                place,
+                prize: prize.to_string(),
+            })
+    }
+
     /// Get game result
     async fn game_result(&self) -> Option<GameResultView> {
         self.state
             .game_result
             .get()
             .as_ref()
-            .map(|r| GameResultView {
-                won: r.won,
-                payout: r.payout.to_string(),
-            })
+            .map(GameResultView::from)
     }
 }
 
 struct MutationRoot {
+    state: Arc<HandState>,
     runtime: Arc<ServiceRuntime<HandService>>,
 }
 
 #[Object]
 impl MutationRoot {
-    /// Join a poker table with the specified stake
-    async fn join_table(&self, stake: String) -> bool {
+    /// Join a poker table with the specified stake and entropy commitment
+    /// (hex), registering the card key of our card secret (hex; only the key
+    /// goes on chain) and paying a tournament buy-in through `token_app`
+    async fn join_table(
+        &self,
+        stake: String,
+        entropy_commitment: String,
+        secret: String,
+        token_app: Option<String>,
+    ) -> bool {
         let stake_amount: u64 = stake.parse().unwrap_or(0);
+        let (entropy_commitment, secret) =
+            match (<[u8; 32]>::from_hex(&entropy_commitment), Vec::from_hex(&secret)) {
+                (Ok(commitment), Ok(secret)) => (commitment, secret),
+                _ => return false,
+            };
+        let token_app = match token_app.map(|app| app.parse::<ApplicationId>()) {
+            Some(Ok(app)) => Some(app),
+            Some(Err(_)) => return false,
+            None => None,
+        };
         let operation = HandOperation::JoinTable {
             stake: stake_amount,
+            entropy_commitment,
+            card_key: card_key(&secret),
+            token_app,
         };
         self.runtime.schedule_operation(&operation);
         true
     }
 
-    /// Place a bet action (CHECK, CALL, RAISE, ALL_IN, FOLD)
+    /// Reveal our deck entropy and commit to the next hand's (hex)
+    async fn reveal_entropy(&self, entropy: String, next_commitment: String) -> bool {
+        let (entropy, next_commitment) =
+            match (<[u8; 32]>::from_hex(&entropy), <[u8; 32]>::from_hex(&next_commitment)) {
+                (Ok(entropy), Ok(next_commitment)) => (entropy, next_commitment),
+                _ => return false,
+            };
+        let operation = HandOperation::RevealEntropy {
+            entropy,
+            next_commitment,
+        };
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Dealerless: answer the table's pending request with our secret for
+    /// the hand (hex). The key share, shuffle or decryption shares are
+    /// computed here; only they go on chain.
+    async fn mental_respond(&self, secret: String) -> bool {
+        let (secret, table_chain, request) = match (
+            Vec::from_hex(&secret),
+            *self.state.table_chain.get(),
+            self.state.mental_request.get().as_ref(),
+        ) {
+            (Ok(secret), Some(table_chain), Some(request)) => (secret, table_chain, request),
+            _ => return false,
+        };
+        let player_chain = self.runtime.chain_id();
+        let response = match mental_response(request, &secret, table_chain, player_chain) {
+            Some(response) => response,
+            None => return false,
+        };
+        let operation = HandOperation::Mental { response };
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Place a bet action (CHECK, CALL, BET, RAISE_TO, ALL_IN, FOLD)
     async fn bet(&self, action: BetActionInput) -> bool {
         let bet_action = match action.action_type {
             BetActionType::Check => BetAction::Check,
             BetActionType::Call => BetAction::Call,
-            BetActionType::Raise => {
-                let amount = action
-                    .amount
-                    .unwrap_or_default()
-                    .parse::<u128>()
-                    .unwrap_or(0);
-                BetAction::Raise(Amount::from_attos(amount))
-            }
+            BetActionType::Bet => BetAction::Bet(parse_bet_amount(action.amount.as_deref())),
+            BetActionType::RaiseTo => BetAction::RaiseTo(parse_bet_amount(action.amount.as_deref())),
             BetActionType::AllIn => BetAction::AllIn,
             BetActionType::Fold => BetAction::Fold,
         };
@@ -184,9 +318,22 @@ impl MutationRoot {
         true
     }
 
-    /// Reveal hole cards for showdown
-    async fn reveal(&self) -> bool {
-        let operation = HandOperation::Reveal;
+    /// Draw: discard the hole cards at these positions (0-based) for
+    /// replacements; an empty list stands pat
+    async fn draw(&self, discarded: Vec<u8>) -> bool {
+        let operation = HandOperation::Draw { discarded };
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Reveal hole cards for showdown, opening them here with our card
+    /// secret (hex) so the reveal carries the openings of their commitments
+    async fn reveal(&self, secret: String) -> bool {
+        let (cards, randomness) = match open_dealt_cards(&self.state, &secret) {
+            Some(opened) => opened.into_iter().unzip(),
+            None => return false,
+        };
+        let operation = HandOperation::Reveal { cards, randomness };
         self.runtime.schedule_operation(&operation);
         true
     }
@@ -197,14 +344,85 @@ impl MutationRoot {
         self.runtime.schedule_operation(&operation);
         true
     }
+
+    /// Cash game: sit out from the next hand
+    async fn sit_out(&self) -> bool {
+        let operation = HandOperation::SitOut;
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Cash game: be dealt in again
+    async fn sit_in(&self) -> bool {
+        let operation = HandOperation::SitIn;
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Cash game: add chips to the stack
+    async fn top_up(&self, amount: String) -> bool {
+        let amount: u64 = amount.parse().unwrap_or(0);
+        let operation = HandOperation::TopUp { amount };
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Cash game: leave with the stack after the current hand
+    async fn cash_out(&self) -> bool {
+        let operation = HandOperation::CashOut;
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Sit-and-go: agree to settle with an ICM deal or a chip chop at the end
+    /// of this hand (no kind withdraws)
+    async fn accept_deal(&self, kind: Option<DealKindInput>) -> bool {
+        let operation = HandOperation::AcceptDeal {
+            kind: kind.map(|kind| match kind {
+                DealKindInput::Icm => DealKind::Icm,
+                DealKindInput::ChipChop => DealKind::ChipChop,
+            }),
+        };
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+}
+
+/// Open the cards the table sealed to our card key with our card secret
+/// (hex): each card with the blinding factor that opens its commitment, or
+/// None if any of them doesn't
+fn open_dealt_cards(state: &HandState, secret: &str) -> Option<Vec<(Card, Vec<u8>)>> {
+    let secret = Vec::from_hex(secret).ok()?;
+    let commitments = state.card_commitments.get().as_ref()?;
+    let sealed_openings = state.sealed_openings.get();
+    if sealed_openings.len() != commitments.len() {
+        return None;
+    }
+    sealed_openings
+        .iter()
+        .zip(commitments)
+        .map(|(sealed, commitment)| {
+            open_sealed(&secret, sealed)
+                .filter(|(card, blinding)| commitment.opens(*card, blinding))
+        })
+        .collect()
+}
+
+/// Bet/raise amount in attos (sent as a string to handle large numbers)
+fn parse_bet_amount(amount: Option<&str>) -> Amount {
+    let attos = amount
+        .unwrap_or_default()
+        .parse::<u128>()
+        .unwrap_or(0);
+    Amount::from_attos(attos)
 }
 
 /// GraphQL input for bet actions
 #[derive(InputObject)]
 struct BetActionInput {
-    /// The type of action: CHECK, CALL, RAISE, ALL_IN, FOLD
+    /// The type of action: CHECK, CALL, BET, RAISE_TO, ALL_IN, FOLD
     action_type: BetActionType,
-    /// Amount for RAISE action (as string to handle large numbers)
+    /// Total for the street on BET and RAISE_TO (as string to handle large numbers)
     amount: Option<String>,
 }
 
@@ -213,11 +431,19 @@ struct BetActionInput {
 enum BetActionType {
     Check,
     Call,
-    Raise,
+    Bet,
+    RaiseTo,
     AllIn,
     Fold,
 }
 
+/// Deal kinds for GraphQL
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum DealKindInput {
+    Icm,
+    ChipChop,
+}
+
 #[derive(async_graphql::SimpleObject)]
 struct HandStateView {
     game_id: Option<u64>,
@@ -230,14 +456,64 @@ struct HandStateView {
     game_result: Option<GameResultView>,
 }
 
+#[derive(async_graphql::SimpleObject)]
+struct MentalRequestView {
+    game_id: u64,
+    /// Key, Shuffle or Shares
+    kind: String,
+    /// Cards to shuffle or to share
+    cards: u32,
+}
+
 #[derive(async_graphql::SimpleObject)]
 struct CardView {
     suit: String,
     rank: String,
 }
 
+#[derive(async_graphql::SimpleObject)]
+struct TournamentFinishView {
+    /// Finishing position (1 = winner)
+    place: u32,
+    prize: String,
+}
+
 #[derive(async_graphql::SimpleObject)]
 struct GameResultView {
     won: bool,
     payout: String,
+    /// Our bet nobody called, returned before the pots were split
+    uncalled_refund: String,
+    /// Exact split of every pot
+    awards: Vec<PotAwardView>,
+    /// Taken from the pots for the house before they were split
+    rake: String,
+}
+
+impl From<&GameResultInfo> for GameResultView {
+    fn from(result: &GameResultInfo) -> Self {
+        GameResultView {
+            won: result.won,
+            payout: result.payout.to_string(),
+            uncalled_refund: result.uncalled_refund.to_string(),
+            awards: result
+                .awards
+                .iter()
+                .map(|award| PotAwardView {
+                    pot: award.pot as u32,
+                    seat: format!("{:?}", award.seat),
+                    amount: award.amount.to_string(),
+                })
+                .collect(),
+            rake: result.rake.to_string(),
+        }
+    }
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct PotAwardView {
+    /// 0 = main pot, then side pots
+    pot: u32,
+    seat: String,
+    amount: String,
 }
@@ -1,6 +1,8 @@
 //! Hand contract state using Linera views
 
-use linera_poker_shared::{Card, CardCommitment, GameResultInfo, Seat};
+use linera_poker_shared::{
+    Card, CardCommitment, GameResultInfo, MaskedCard, MentalRequest, SealedOpening, Seat,
+};
 use linera_sdk::{
     linera_base_types::{Amount, ApplicationId, ChainId},
     views::{linera_views, RegisterView, RootView, ViewStorageContext},
@@ -18,9 +20,10 @@ pub struct HandState {
     pub table_app: RegisterView<Option<ApplicationId>>,
     /// Our seat at the table
     pub seat: RegisterView<Option<Seat>>,
-    /// Our hole cards (PRIVATE - only visible on this chain)
+    /// Our hole cards once we have shown them (until then they stay sealed,
+    /// see `sealed_openings`)
     pub hole_cards: RegisterView<Vec<Card>>,
-    /// Community cards we've received
+    /// Community cards the table has opened, checked against its deck root
     pub community_cards: RegisterView<Vec<Card>>,
     /// Current bet to match
     pub current_bet: RegisterView<Amount>,
@@ -28,6 +31,18 @@ pub struct HandState {
     pub my_turn: RegisterView<bool>,
     /// Game result (if game ended)
     pub game_result: RegisterView<Option<GameResultInfo>>,
+    /// Stack paid back when we cashed out of a cash game
+    pub cashed_out: RegisterView<Option<Amount>>,
+    /// Tournament: our finishing position and the prize paid for it
+    pub tournament_finish: RegisterView<Option<(u32, Amount)>>,
+    /// Game the table is waiting on our deck entropy for
+    pub entropy_request: RegisterView<Option<u64>>,
+    /// Dealerless: what the table is waiting on us for (key share, shuffle
+    /// or decryption shares)
+    pub mental_request: RegisterView<Option<MentalRequest>>,
+    /// Dealerless: our hole cards, still encrypted under our key for the
+    /// hand (opened off-chain with our secret)
+    pub mental_hole_cards: RegisterView<Vec<MaskedCard>>,
 
     // ========================================================================
     // DEPRECATED: INSECURE FIELDS (Phase 3: Marked for Removal)
@@ -44,9 +59,10 @@ pub struct HandState {
     /// Card commitments received from table (for reveal proof generation)
     pub card_commitments: RegisterView<Option<Vec<CardCommitment>>>,
 
-    /// Blinding factors used in commitments (for reveal proof)
-    /// These are sent by the table along with the DealingProof
-    pub blinding_factors: RegisterView<Option<Vec<Vec<u8>>>>,
+    /// Our cards and the blinding factors that open their commitments, one
+    /// per commitment, sealed by the table to our card key (opened off-chain
+    /// with our card secret, for play and for the reveal proof)
+    pub sealed_openings: RegisterView<Vec<SealedOpening>>,
 
     /// Deck root from table (for verification)
     pub table_deck_root: RegisterView<Option<[u8; 32]>>,
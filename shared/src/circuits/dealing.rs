@@ -1,7 +1,7 @@
 // Dealing Circuit: Prove dealer committed to 2 valid cards from shuffled deck
 //
 // Public Inputs:
-//   - deck_root: Merkle root of 52-card shuffled deck
+//   - deck_root: Merkle root of 52-card (or 36-card short) shuffled deck
 //   - card_commitments: [C1, C2] Pedersen commitments
 //
 // Private Witness:
@@ -12,7 +12,7 @@
 //
 // Constraints:
 //   1. idx1 ≠ idx2 (no duplicates)
-//   2. 0 ≤ idx1, idx2 < 52 (valid range)
+//   2. 0 ≤ idx1, idx2 < 52 (valid range; < 36 for the short deck)
 //   3. deck[idx1] = v1, deck[idx2] = v2 (cards match positions)
 //   4. C1 = Pedersen(v1, r1), C2 = Pedersen(v2, r2)
 //
@@ -53,6 +53,11 @@ pub struct DealingCircuit {
 
     /// Merkle proofs for card inclusion
     pub merkle_proofs: Option<[MerkleProof; 2]>,
+
+    // ========== Parameters ==========
+    /// Dealing from the 36-card short deck: indices and values are short-deck
+    /// indices (see `Card::to_short_deck_index`)
+    pub short_deck: bool,
 }
 
 impl DealingCircuit {
@@ -65,6 +70,7 @@ impl DealingCircuit {
             card_values: None,
             randomness: None,
             merkle_proofs: None,
+            short_deck: false,
         }
     }
 
@@ -84,9 +90,35 @@ impl DealingCircuit {
             card_values: Some(card_values),
             randomness: Some(randomness),
             merkle_proofs: Some(merkle_proofs),
+            short_deck: false,
         }
     }
 
+    /// Deal from the 36-card short deck instead of the full 52 cards
+    pub fn with_short_deck(mut self) -> Self {
+        self.short_deck = true;
+        self
+    }
+
+    /// Number of cards in the deck being dealt from
+    fn deck_size(&self) -> u8 {
+        if self.short_deck {
+            crate::SHORT_DECK_SIZE as u8
+        } else {
+            52
+        }
+    }
+
+    /// Range check a card index or value against the deck size
+    fn check_range(&self, value: &FpVar<Fr>) -> Result<(), SynthesisError> {
+        if self.short_deck {
+            RangeCheckGadget::check_short_deck_range(value)?;
+        } else {
+            RangeCheckGadget::check_card_range(value)?;
+        }
+        Ok(())
+    }
+
     /// Validate witness data before circuit synthesis
     fn validate_witness(&self) -> Result<(), SynthesisError> {
         if let (Some(indices), Some(values), Some(randomness), Some(proofs)) = (
@@ -97,14 +129,14 @@ impl DealingCircuit {
         ) {
             // Check indices are in valid range
             for &idx in indices.iter() {
-                if idx >= 52 {
+                if idx >= self.deck_size() {
                     return Err(SynthesisError::Unsatisfiable);
                 }
             }
 
             // Check values are in valid range
             for &val in values.iter() {
-                if val >= 52 {
+                if val >= self.deck_size() {
                     return Err(SynthesisError::Unsatisfiable);
                 }
             }
@@ -194,12 +226,12 @@ impl ConstraintSynthesizer<Fr> for DealingCircuit {
         // ~2 constraints
         RangeCheckGadget::enforce_not_equal(&idx1_var, &idx2_var)?;
 
-        // ========== CONSTRAINT 2: Valid Range (0 ≤ idx, val < 52) ==========
+        // ========== CONSTRAINT 2: Valid Range (0 ≤ idx, val < deck size) ==========
         // ~12 constraints per check, 4 checks = ~48 constraints
-        RangeCheckGadget::check_card_range(&idx1_var)?;
-        RangeCheckGadget::check_card_range(&idx2_var)?;
-        RangeCheckGadget::check_card_range(&val1_var)?;
-        RangeCheckGadget::check_card_range(&val2_var)?;
+        self.check_range(&idx1_var)?;
+        self.check_range(&idx2_var)?;
+        self.check_range(&val1_var)?;
+        self.check_range(&val2_var)?;
 
         // ========== CONSTRAINT 3: Merkle Path Verification ==========
         // deck[idx1] = v1, deck[idx2] = v2
@@ -380,6 +412,33 @@ mod tests {
         assert!(circuit.validate_witness().is_err());
     }
 
+    #[test]
+    fn test_dealing_circuit_short_deck_range() {
+        let merkle_proof = MerkleProof::new(
+            vec![[0u8; 32]; 6],
+            vec![false; 6],
+        );
+        let circuit = |card_indices: [u8; 2]| {
+            DealingCircuit::new_with_witness(
+                [1u8; 32],
+                [vec![2u8; 32], vec![3u8; 32]],
+                card_indices,
+                [10u8, 20u8],
+                [Fr::from(100u64), Fr::from(200u64)],
+                [merkle_proof.clone(), merkle_proof.clone()],
+            )
+            .with_short_deck()
+        };
+
+        // Position 35 is the last card of the short deck
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit([0u8, 35u8]).generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        // Position 40 exists in the full deck but not the short one
+        assert!(circuit([0u8, 40u8]).validate_witness().is_err());
+    }
+
     #[test]
     fn test_dealing_circuit_zero_randomness() {
         let deck_root = [1u8; 32];
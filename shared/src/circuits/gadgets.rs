@@ -1,7 +1,7 @@
 // Reusable constraint gadgets for mental poker circuits
 //
 // This module provides optimized, auditable constraint gadgets:
-// - Range checks for card values (0-51)
+// - Range checks for card values (0-51, or 0-35 for the short deck)
 // - Pedersen commitment verification
 // - Merkle tree path verification
 // - Inequality constraints
@@ -56,6 +56,31 @@ impl RangeCheckGadget {
         Ok(bits_6.to_vec())
     }
 
+    /// Enforce that `value` is in range [0, 36) for the short deck
+    ///
+    /// Decomposes both `value` and `35 - value` into 6 bits: a value above 35
+    /// would make the difference wrap around the field and fail to fit.
+    pub fn check_short_deck_range(
+        value: &FpVar<Fr>,
+    ) -> Result<Vec<Boolean<Fr>>, SynthesisError> {
+        let bits = value.to_bits_le()?;
+        if bits.len() < 6 {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let bits_6 = &bits[0..6];
+        let reconstructed = Boolean::le_bits_to_fp_var(bits_6)?;
+        reconstructed.enforce_equal(value)?;
+
+        // Check value <= 35
+        let headroom = FpVar::constant(Fr::from(35u64)) - value;
+        let headroom_bits = headroom.to_bits_le()?;
+        let headroom_value = Boolean::le_bits_to_fp_var(&headroom_bits[0..6])?;
+        headroom_value.enforce_equal(&headroom)?;
+
+        Ok(bits_6.to_vec())
+    }
+
     /// Enforce that 6-bit value < 52 (binary: 110100)
     fn enforce_less_than_52(bits: &[Boolean<Fr>]) -> Result<(), SynthesisError> {
         assert_eq!(bits.len(), 6);
@@ -268,6 +293,23 @@ mod tests {
         assert!(cs.is_satisfied().unwrap());
     }
 
+    #[test]
+    fn test_short_deck_range_check() {
+        for value in [0u64, 20, 35] {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let value_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(value))).unwrap();
+            RangeCheckGadget::check_short_deck_range(&value_var).unwrap();
+            assert!(cs.is_satisfied().unwrap(), "Value {} should pass", value);
+        }
+
+        for value in [36u64, 51] {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let value_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(value))).unwrap();
+            RangeCheckGadget::check_short_deck_range(&value_var).unwrap();
+            assert!(!cs.is_satisfied().unwrap(), "Value {} should fail", value);
+        }
+    }
+
     #[test]
     fn test_not_equal_constraint() {
         let cs = ConstraintSystem::<Fr>::new_ref();
@@ -5,6 +5,10 @@
 
 use async_graphql::{Enum, SimpleObject};
 use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, ChainId};
+use rand_chacha::{
+    rand_core::{RngCore, SeedableRng},
+    ChaCha20Rng,
+};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
@@ -15,7 +19,7 @@ use sha2::{Digest, Sha256};
 pub mod zk;
 
 // Re-export ZK types for convenience
-pub use zk::{CardCommitment, DealingProof, RevealProof};
+pub use zk::{BoardCardProof, CardCommitment, DealingProof, DrawProof, RevealProof, SealedOpening};
 
 // ============================================================================
 // R1CS CIRCUITS (Phase 2: arkworks Implementation)
@@ -24,6 +28,24 @@ pub use zk::{CardCommitment, DealingProof, RevealProof};
 #[cfg(not(target_arch = "wasm32"))]
 pub mod circuits;
 
+// ============================================================================
+// GAME VARIANTS
+// ============================================================================
+
+pub mod variant;
+
+pub use variant::GameVariant;
+
+// ============================================================================
+// DEALERLESS SHUFFLE (Mental Poker)
+// ============================================================================
+
+pub mod mental;
+
+pub use mental::{
+    DecryptionShare, KeyShare, MaskedCard, MentalDeal, MentalRequest, MentalResponse, ShuffleProof,
+};
+
 // ============================================================================
 // CARD REPRESENTATION
 // ============================================================================
@@ -99,6 +121,24 @@ impl Card {
         };
         Some(Card { suit, rank })
     }
+
+    /// Convert card to its index 0-35 in the short deck (sixes and up)
+    pub fn to_short_deck_index(&self) -> Option<u8> {
+        let rank = self.rank as u8;
+        if rank < 6 {
+            return None;
+        }
+        Some((self.suit as u8) * 9 + (rank - 6))
+    }
+
+    /// Create card from short-deck index 0-35
+    pub fn from_short_deck_index(idx: u8) -> Option<Self> {
+        if idx >= SHORT_DECK_SIZE as u8 {
+            return None;
+        }
+        // Skip the 2-5 of the matching suit in the full-deck numbering
+        Self::from_index((idx / 9) * 13 + (idx % 9) + 4)
+    }
 }
 
 // ============================================================================
@@ -163,7 +203,7 @@ impl EncryptedCard {
 pub struct CardReveal {
     /// The actual card
     pub card: Card,
-    /// The dealer's secret used in commitment (SECURITY ISSUE!)
+    /// The blinding factor that opens the card's commitment
     pub secret: Vec<u8>,
 }
 
@@ -171,18 +211,55 @@ pub struct CardReveal {
 // PLAYER SEAT
 // ============================================================================
 
+/// Numbered seat at the table, clockwise from `Player1` (full ring = 9 seats)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, Enum)]
 pub enum Seat {
     Player1,
     Player2,
+    Player3,
+    Player4,
+    Player5,
+    Player6,
+    Player7,
+    Player8,
+    Player9,
 }
 
 impl Seat {
-    pub fn other(&self) -> Self {
-        match self {
-            Seat::Player1 => Seat::Player2,
-            Seat::Player2 => Seat::Player1,
-        }
+    /// Maximum number of seats at a full-ring table
+    pub const MAX_SEATS: usize = 9;
+
+    /// All seats in clockwise order
+    pub const ALL: [Seat; Self::MAX_SEATS] = [
+        Seat::Player1,
+        Seat::Player2,
+        Seat::Player3,
+        Seat::Player4,
+        Seat::Player5,
+        Seat::Player6,
+        Seat::Player7,
+        Seat::Player8,
+        Seat::Player9,
+    ];
+
+    /// Zero-based seat number
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Seat from zero-based seat number
+    pub fn from_index(idx: usize) -> Option<Self> {
+        Self::ALL.get(idx).copied()
+    }
+
+    /// Next seat clockwise from this one among `seats` (wraps around the table).
+    ///
+    /// `seats` does not need to be sorted and may or may not contain `self`.
+    /// Returns `None` if no other seat qualifies.
+    pub fn next_in(&self, seats: &[Seat]) -> Option<Seat> {
+        (1..=Self::MAX_SEATS)
+            .filter_map(|offset| Self::from_index((self.index() + offset) % Self::MAX_SEATS))
+            .find(|seat| seat != self && seats.contains(seat))
     }
 }
 
@@ -195,11 +272,50 @@ pub struct PlayerInfo {
     pub seat: Seat,
     pub chain_id: ChainId,
     pub owner: AccountOwner,
+    /// Chips the player had when the current hand started
     pub stake: Amount,
+    /// Chips behind: not yet committed to the pot
+    pub stack: Amount,
     pub hand_app: Option<ApplicationId>,
     pub has_folded: bool,
     pub current_bet: Amount,
     pub has_revealed: bool,
+    /// Whether the player has acted since the last bet or raise this round
+    pub has_acted: bool,
+    /// Chips put into the pot this hand, across all betting rounds
+    pub total_contribution: Amount,
+    /// Cash game: keeps the seat and stack but is not dealt in
+    pub sitting_out: bool,
+    /// Cash game: chips to add to the stack before the next hand
+    pub pending_top_up: Amount,
+    /// Cash game: leaves with the stack once the current hand ends
+    pub cashing_out: bool,
+}
+
+impl PlayerInfo {
+    /// Player has committed their whole stack
+    pub fn is_all_in(&self) -> bool {
+        self.stack == Amount::ZERO
+    }
+
+    /// Move up to `amount` from the stack into this street's bet.
+    /// Returns the chips actually committed (less when going all-in).
+    pub fn commit(&mut self, amount: Amount) -> Amount {
+        let committed = amount.min(self.stack);
+        self.stack = self.stack.saturating_sub(committed);
+        self.current_bet = self.current_bet.saturating_add(committed);
+        self.total_contribution = self.total_contribution.saturating_add(committed);
+        committed
+    }
+
+    /// Post an ante: dead money that counts toward the pot but not toward
+    /// matching bets on the street. Capped at the stack.
+    pub fn post_ante(&mut self, amount: Amount) -> Amount {
+        let posted = amount.min(self.stack);
+        self.stack = self.stack.saturating_sub(posted);
+        self.total_contribution = self.total_contribution.saturating_add(posted);
+        posted
+    }
 }
 
 // ============================================================================
@@ -212,14 +328,156 @@ pub enum BetAction {
     Check,
     /// Match the current bet (when there's a raise to match)
     Call,
-    /// Increase the bet
-    Raise(Amount),
+    /// Open the betting on a street: total chips for this street
+    Bet(Amount),
+    /// Raise the current bet to this total for the street
+    RaiseTo(Amount),
     /// Go all-in
     AllIn,
     /// Give up the hand
     Fold,
 }
 
+/// Which poker game is dealt at the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PokerVariant {
+    /// Two hole cards; hands use any five of the seven cards
+    #[default]
+    HoldEm,
+    /// Four hole cards; hands use exactly two of them and three from the board
+    Omaha,
+    /// Hold'em with the 2-5 removed (36 cards); A-6-7-8-9 is the low straight
+    /// and a flush beats a full house
+    ShortDeck,
+    /// Seven-card stud: no board; each player gets three down cards and four
+    /// up cards over five streets, with a bring-in instead of blinds
+    SevenCardStud,
+    /// Five-card draw: five down cards, one betting round, a draw of up to
+    /// three replacement cards, then a second betting round
+    FiveCardDraw,
+    /// Omaha split between the best high and the best eight-or-better low
+    OmahaHiLo,
+    /// Seven-card stud where the best ace-to-five low wins the whole pot
+    Razz,
+}
+
+impl PokerVariant {
+    /// Rules of this game
+    pub fn rules(&self) -> &'static dyn GameVariant {
+        match self {
+            PokerVariant::HoldEm => &variant::HoldEm,
+            PokerVariant::Omaha => &variant::Omaha,
+            PokerVariant::ShortDeck => &variant::ShortDeck,
+            PokerVariant::SevenCardStud => &variant::SevenCardStud,
+            PokerVariant::FiveCardDraw => &variant::FiveCardDraw,
+            PokerVariant::OmahaHiLo => &variant::OmahaHiLo,
+            PokerVariant::Razz => &variant::Razz,
+        }
+    }
+}
+
+/// How much a player may bet or raise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum BettingStructure {
+    /// Any amount up to the whole stack
+    #[default]
+    NoLimit,
+    /// Raises capped at the size of the pot
+    PotLimit,
+    /// Fixed bet sizes: `small_bet` pre-flop and on the flop (third and fourth
+    /// street in stud, before the draw), `big_bet` on the turn and river
+    /// (fifth street on, after the draw), at most `raise_cap` bets and raises
+    /// per street
+    FixedLimit {
+        small_bet: Amount,
+        big_bet: Amount,
+        raise_cap: u8,
+    },
+}
+
+/// Betting on the current street, as seen by the player to act
+#[derive(Debug, Clone, Copy)]
+pub struct StreetBetting {
+    /// Highest bet this street
+    pub current_bet: Amount,
+    /// Smallest raise increment
+    pub min_raise: Amount,
+    /// Chips committed this hand, including this street
+    pub pot: Amount,
+    /// Bets and full raises made this street (the big blind counts pre-flop)
+    pub raises: u8,
+}
+
+impl BettingStructure {
+    /// Minimum bet or raise increment when a street opens
+    pub fn opening_min_raise(&self, phase: GamePhase, big_blind: Amount) -> Amount {
+        match (self, phase) {
+            (
+                BettingStructure::FixedLimit { small_bet, .. },
+                GamePhase::PreFlop
+                | GamePhase::Flop
+                | GamePhase::ThirdStreet
+                | GamePhase::FourthStreet
+                | GamePhase::PreDraw,
+            ) => *small_bet,
+            (BettingStructure::FixedLimit { big_bet, .. }, _) => *big_bet,
+            _ => big_blind,
+        }
+    }
+
+    /// Smallest and largest total a player may bet or raise to this street,
+    /// or `None` if they may only check, call or fold.
+    ///
+    /// A player short of the minimum may still go all-in for less.
+    pub fn raise_limits(
+        &self,
+        player: &PlayerInfo,
+        street: &StreetBetting,
+    ) -> Option<(Amount, Amount)> {
+        // Action not reopened since this player last acted
+        if player.has_acted {
+            return None;
+        }
+        let all_in_to = player.current_bet.saturating_add(player.stack);
+        if all_in_to <= street.current_bet {
+            return None;
+        }
+
+        let min = self
+            .completion(street)
+            .unwrap_or(street.current_bet.saturating_add(street.min_raise));
+        let max = match self {
+            BettingStructure::NoLimit => all_in_to,
+            BettingStructure::PotLimit => {
+                // Call first, then raise by the size of the pot
+                let to_call = street.current_bet.saturating_sub(player.current_bet);
+                street
+                    .current_bet
+                    .saturating_add(street.pot)
+                    .saturating_add(to_call)
+            }
+            BettingStructure::FixedLimit { raise_cap, .. } => {
+                if street.raises >= *raise_cap {
+                    return None;
+                }
+                min
+            }
+        };
+        Some((min.min(all_in_to), max.max(min).min(all_in_to)))
+    }
+
+    /// Fixed limit: the full bet a bet short of one (the stud bring-in) is
+    /// completed to, instead of raised by a whole bet
+    pub fn completion(&self, street: &StreetBetting) -> Option<Amount> {
+        match self {
+            BettingStructure::FixedLimit { .. } if street.current_bet < street.min_raise => {
+                Some(street.min_raise)
+            }
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // GAME PHASES (State Machine)
 // ============================================================================
@@ -239,6 +497,22 @@ pub enum GamePhase {
     Turn,
     /// River dealt, betting
     River,
+    /// Stud: two down cards and one up card dealt, bring-in betting
+    ThirdStreet,
+    /// Stud: second up card dealt, betting
+    FourthStreet,
+    /// Stud: third up card dealt, betting
+    FifthStreet,
+    /// Stud: fourth up card dealt, betting
+    SixthStreet,
+    /// Stud: last card dealt face down, betting
+    SeventhStreet,
+    /// Draw: five cards dealt face down, betting
+    PreDraw,
+    /// Draw: players discard and receive replacements in turn, no betting
+    Draw,
+    /// Draw: replacements dealt, betting
+    PostDraw,
     /// All betting complete, waiting for reveals
     Showdown,
     /// Winner determined, paying out
@@ -247,6 +521,26 @@ pub enum GamePhase {
     Finished,
 }
 
+impl GamePhase {
+    /// True on a street where players bet
+    pub fn is_betting_round(&self) -> bool {
+        matches!(
+            self,
+            GamePhase::PreFlop
+                | GamePhase::Flop
+                | GamePhase::Turn
+                | GamePhase::River
+                | GamePhase::ThirdStreet
+                | GamePhase::FourthStreet
+                | GamePhase::FifthStreet
+                | GamePhase::SixthStreet
+                | GamePhase::SeventhStreet
+                | GamePhase::PreDraw
+                | GamePhase::PostDraw
+        )
+    }
+}
+
 // ============================================================================
 // HAND RANKINGS
 // ============================================================================
@@ -265,11 +559,32 @@ pub enum HandRank {
     RoyalFlush = 9,
 }
 
+impl HandRank {
+    /// Position in the ranking; short deck swaps flush above full house
+    fn strength(&self, short_deck: bool) -> u8 {
+        match self {
+            HandRank::Flush if short_deck => HandRank::FullHouse as u8,
+            HandRank::FullHouse if short_deck => HandRank::Flush as u8,
+            rank => *rank as u8,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct HandScore {
     pub rank: HandRank,
     /// Tiebreaker values (e.g., kickers)
     pub tiebreakers: Vec<u8>,
+    /// Scored with short-deck rankings (flush beats full house)
+    #[serde(default)]
+    pub short_deck: bool,
+}
+
+impl HandScore {
+    /// Whether this hand ranks `rank` or better
+    pub fn at_least(&self, rank: HandRank) -> bool {
+        self.rank.strength(self.short_deck) >= rank.strength(self.short_deck)
+    }
 }
 
 impl PartialOrd for HandScore {
@@ -280,7 +595,8 @@ impl PartialOrd for HandScore {
 
 impl Ord for HandScore {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.rank.cmp(&other.rank) {
+        let strength = self.rank.strength(self.short_deck);
+        match strength.cmp(&other.rank.strength(other.short_deck)) {
             std::cmp::Ordering::Equal => self.tiebreakers.cmp(&other.tiebreakers),
             ord => ord,
         }
@@ -427,6 +743,12 @@ pub enum Message {
         opponent_cards: Option<Vec<Card>>,
         /// True if opponent was auto-forfeited due to timeout or invalid proof
         forfeited: bool,
+        /// Your bet nobody called, returned before the pots were split
+        uncalled_refund: Amount,
+        /// Exact split of every pot, odd chips included
+        awards: Vec<PotAward>,
+        /// Taken from the pots for the house before they were split
+        rake: Amount,
     },
 
     // ═══════════════════════════════════════════════════════════════════
@@ -440,22 +762,36 @@ pub enum Message {
         dealing_proof: DealingProof,
     },
 
-    /// Dealer sends community cards with ZK proof
+    /// Dealer opens the board cards a street shows, each with its Merkle
+    /// path to the deck root sent with the hole cards
     /// Replaces CommunityCards with cryptographic privacy
     CommunityCardsZK {
         game_id: u64,
         phase: GamePhase,
-        dealing_proof: DealingProof,
+        board: Vec<BoardCardProof>,
+    },
+
+    /// Dealer sends replacement cards for a draw, with a ZK proof that they
+    /// come from undealt deck positions
+    DrawCardsZK {
+        game_id: u64,
+        draw_proof: DrawProof,
     },
 
     // ═══════════════════════════════════════════════════════════════════
     // Hand → Table messages (indices 7-12)
     // ═══════════════════════════════════════════════════════════════════
 
-    /// Player joins table with stake
+    /// Player joins table with stake, committing to the entropy they will
+    /// reveal for the first hand they are dealt into, and registers the card
+    /// key the table seals their cards to; a tournament buy-in is transferred
+    /// to the table chain first, and the table only seats the player once its
+    /// token app confirms the tokens arrived
     JoinTable {
         stake: Amount,
         hand_app_id: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
     },
 
     /// Player acknowledges receiving cards
@@ -464,6 +800,10 @@ pub enum Message {
     /// Player's betting action
     BetAction { game_id: u64, action: BetAction },
 
+    /// Player discards the hole cards at these positions on the draw
+    /// (empty = stand pat)
+    Draw { game_id: u64, discarded: Vec<u8> },
+
     /// DEPRECATED: Player reveals cards with plaintext proofs
     /// Use RevealCardsZK instead for production
     #[deprecated(since = "0.2.0", note = "Use RevealCardsZK with ZK proofs")]
@@ -487,6 +827,73 @@ pub enum Message {
     /// Anyone can trigger timeout check (permissionless)
     /// Used to enforce liveness - auto-forfeits stalled players
     TriggerTimeoutCheck { game_id: u64 },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Cash-game session messages
+    // ═══════════════════════════════════════════════════════════════════
+
+    /// Player keeps their seat and stack but is not dealt in (Hand → Table)
+    SitOut,
+
+    /// Player is dealt in again from the next hand (Hand → Table)
+    SitIn,
+
+    /// Player adds chips to their stack before the next hand (Hand → Table)
+    TopUp { amount: Amount },
+
+    /// Player leaves with their stack once the current hand ends (Hand → Table)
+    CashOut,
+
+    /// Stack handed back to a player that cashed out (Table → Hand)
+    CashedOut { amount: Amount },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Tournament messages
+    // ═══════════════════════════════════════════════════════════════════
+
+    /// Player was knocked out of (or won) a tournament (Table → Hand)
+    TournamentFinished { place: u32, prize: Amount },
+
+    /// Player agrees to settle the tournament with this deal at the end of
+    /// the current hand, or withdraws their agreement (Hand → Table)
+    AcceptDeal { kind: Option<DealKind> },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Deck entropy messages
+    // ═══════════════════════════════════════════════════════════════════
+
+    /// Blinds are in; reveal the committed entropy so the deck can be
+    /// shuffled (Table → Hand)
+    RequestEntropy { game_id: u64 },
+
+    /// Player reveals the entropy they committed to and commits to the
+    /// entropy for their next hand (Hand → Table)
+    RevealEntropy {
+        game_id: u64,
+        entropy: [u8; 32],
+        next_commitment: [u8; 32],
+    },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Dealerless shuffle messages
+    // ═══════════════════════════════════════════════════════════════════
+
+    /// Key share, shuffle or decryption shares needed from the player to
+    /// deal a dealerless hand (Table → Hand)
+    MentalRequest { request: MentalRequest },
+
+    /// Player's answer to a mental-poker request (Hand → Table)
+    MentalResponse {
+        game_id: u64,
+        response: MentalResponse,
+    },
+
+    /// Hole cards with every other player's share removed: only the
+    /// player's own key can open them (Table → Hand)
+    MentalHoleCards {
+        game_id: u64,
+        cards: Vec<MaskedCard>,
+    },
 }
 
 // ============================================================================
@@ -503,9 +910,11 @@ pub struct TableState {
     pub min_raise: Amount,
     pub community_cards: Vec<Card>,
     pub turn_seat: Option<Seat>,
-    pub winner: Option<Seat>,
+    /// Winning seats (more than one when the pot is split)
+    pub winners: Vec<Seat>,
     pub min_stake: Amount,
     pub max_stake: Amount,
+    pub max_players: u8,
 }
 
 // ============================================================================
@@ -531,6 +940,10 @@ pub struct GameResultInfo {
     pub payout: Amount,
     pub my_cards: Vec<Card>,
     pub opponent_cards: Option<Vec<Card>>,
+    pub uncalled_refund: Amount,
+    pub awards: Vec<PotAward>,
+    /// Taken from the pots for the house
+    pub rake: Amount,
 }
 
 // ============================================================================
@@ -549,6 +962,16 @@ pub struct TokenState {
 // ============================================================================
 
 pub fn evaluate_hand(hole_cards: &[Card], community: &[Card]) -> HandScore {
+    best_of_seven(hole_cards, community, false)
+}
+
+/// Short-deck evaluation: any five of the seven cards, with A-6-7-8-9 as the
+/// low straight and a flush ranked above a full house
+pub fn evaluate_short_deck(hole_cards: &[Card], community: &[Card]) -> HandScore {
+    best_of_seven(hole_cards, community, true)
+}
+
+fn best_of_seven(hole_cards: &[Card], community: &[Card], short_deck: bool) -> HandScore {
     let mut all_cards: Vec<Card> = hole_cards.to_vec();
     all_cards.extend(community.iter().cloned());
 
@@ -556,11 +979,12 @@ pub fn evaluate_hand(hole_cards: &[Card], community: &[Card]) -> HandScore {
     let mut best_score = HandScore {
         rank: HandRank::HighCard,
         tiebreakers: vec![],
+        short_deck,
     };
 
     // Generate all 5-card combinations from 7 cards
     for combo in combinations(&all_cards, 5) {
-        let score = evaluate_five_cards(&combo);
+        let score = evaluate_five_cards(&combo, short_deck);
         if score > best_score {
             best_score = score;
         }
@@ -569,6 +993,233 @@ pub fn evaluate_hand(hole_cards: &[Card], community: &[Card]) -> HandScore {
     best_score
 }
 
+/// Omaha evaluation: exactly two hole cards combined with exactly three board cards
+pub fn evaluate_omaha(hole_cards: &[Card], community: &[Card]) -> HandScore {
+    let mut best_score = HandScore {
+        rank: HandRank::HighCard,
+        tiebreakers: vec![],
+        short_deck: false,
+    };
+
+    for hole in combinations(hole_cards, 2) {
+        for board in combinations(community, 3) {
+            let mut combo = hole.clone();
+            combo.extend(board);
+            let score = evaluate_five_cards(&combo, false);
+            if score > best_score {
+                best_score = score;
+            }
+        }
+    }
+
+    best_score
+}
+
+/// Stud: strength of the up cards showing, used to pick who acts first.
+///
+/// Only pairs, two pair, trips and quads count on fewer than five cards;
+/// straights and flushes are ignored.
+pub fn evaluate_up_cards(cards: &[Card]) -> HandScore {
+    let mut rank_counts: std::collections::HashMap<u8, u8> = std::collections::HashMap::new();
+    for card in cards {
+        *rank_counts.entry(card.rank as u8).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<(u8, u8)> = rank_counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+    let rank_groups: Vec<u8> = counts.iter().map(|(_, count)| *count).collect();
+
+    let rank = match rank_groups.as_slice() {
+        [4, ..] => HandRank::FourOfAKind,
+        [3, ..] => HandRank::ThreeOfAKind,
+        [2, 2, ..] => HandRank::TwoPair,
+        [2, ..] => HandRank::OnePair,
+        _ => HandRank::HighCard,
+    };
+
+    HandScore {
+        rank,
+        tiebreakers: counts.iter().map(|(rank, _)| *rank).collect(),
+        short_deck: false,
+    }
+}
+
+/// Stud: seat showing the lowest up card on third street, which must bring it in.
+///
+/// Aces are high; equal ranks are broken by suit, clubs lowest, then
+/// diamonds, hearts and spades.
+pub fn bring_in_seat(up_cards: &[(Seat, Card)]) -> Option<Seat> {
+    up_cards
+        .iter()
+        .min_by_key(|(_, card)| (card.rank as u8, suit_order(card.suit)))
+        .map(|(seat, _)| *seat)
+}
+
+/// Razz: seat showing the highest up card on third street, which must bring it in.
+///
+/// Aces are low; equal ranks are broken by suit, spades highest.
+pub fn razz_bring_in_seat(up_cards: &[(Seat, Card)]) -> Option<Seat> {
+    up_cards
+        .iter()
+        .max_by_key(|(_, card)| (ace_low(card.rank), suit_order(card.suit)))
+        .map(|(seat, _)| *seat)
+}
+
+/// Suit order for breaking bring-in ties: clubs, diamonds, hearts, spades
+fn suit_order(suit: Suit) -> u8 {
+    match suit {
+        Suit::Clubs => 0,
+        Suit::Diamonds => 1,
+        Suit::Hearts => 2,
+        Suit::Spades => 3,
+    }
+}
+
+fn ace_low(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 1,
+        rank => rank as u8,
+    }
+}
+
+// ============================================================================
+// LOW HAND EVALUATION (hi/lo split and lowball)
+// ============================================================================
+
+/// Strength of a low hand. The lowest hand wins, so a better low compares
+/// greater - the same way round as [`HandScore`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LowScore {
+    /// Pairs and the like count against a low; straights and flushes only in deuce-to-seven
+    pub rank: HandRank,
+    /// Card ranks to compare, highest first (aces are 1 in ace-to-five)
+    pub ranks: Vec<u8>,
+}
+
+impl LowScore {
+    /// Ace-to-five: five unpaired cards, none above an eight
+    pub fn qualifies_eight_or_better(&self) -> bool {
+        self.rank == HandRank::HighCard
+            && self.ranks.len() == 5
+            && self.ranks.iter().all(|rank| *rank <= 8)
+    }
+}
+
+impl PartialOrd for LowScore {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LowScore {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other
+            .rank
+            .cmp(&self.rank)
+            .then_with(|| other.ranks.cmp(&self.ranks))
+    }
+}
+
+/// Ace-to-five low: best five of the cards, aces low, straights and flushes
+/// ignored, so A-2-3-4-5 is the nut low (Razz, and the low half of hi/lo)
+pub fn ace_to_five_low(cards: &[Card]) -> LowScore {
+    best_low(cards, score_ace_to_five)
+}
+
+/// Deuce-to-seven low: best five of the cards, aces high, straights and
+/// flushes count against the hand, so 7-5-4-3-2 is the nut low
+pub fn deuce_to_seven_low(cards: &[Card]) -> LowScore {
+    best_low(cards, score_deuce_to_seven)
+}
+
+/// Hi/lo: best ace-to-five low from any five of the hole and board cards,
+/// if it qualifies eight or better
+pub fn eight_or_better_low(hole_cards: &[Card], community: &[Card]) -> Option<LowScore> {
+    let mut all_cards: Vec<Card> = hole_cards.to_vec();
+    all_cards.extend(community.iter().cloned());
+    Some(ace_to_five_low(&all_cards)).filter(LowScore::qualifies_eight_or_better)
+}
+
+/// Omaha hi/lo: best qualifying eight-or-better low using exactly two hole
+/// cards and exactly three board cards
+pub fn omaha_eight_or_better_low(hole_cards: &[Card], community: &[Card]) -> Option<LowScore> {
+    let mut best: Option<LowScore> = None;
+    for hole in combinations(hole_cards, 2) {
+        for board in combinations(community, 3) {
+            let mut combo = hole.clone();
+            combo.extend(board);
+            let score = score_ace_to_five(&combo);
+            if score.qualifies_eight_or_better() && best.as_ref().is_none_or(|top| score > *top) {
+                best = Some(score);
+            }
+        }
+    }
+    best
+}
+
+/// Best low from any five of the cards (all of them if there are fewer)
+fn best_low(cards: &[Card], score: fn(&[Card]) -> LowScore) -> LowScore {
+    if cards.len() <= 5 {
+        return score(cards);
+    }
+    combinations(cards, 5)
+        .iter()
+        .map(|combo| score(combo))
+        .max()
+        .unwrap_or_else(|| score(cards))
+}
+
+fn score_ace_to_five(cards: &[Card]) -> LowScore {
+    let mut rank_counts: std::collections::HashMap<u8, u8> = std::collections::HashMap::new();
+    for card in cards {
+        *rank_counts.entry(ace_low(card.rank)).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<(u8, u8)> = rank_counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+    let rank_groups: Vec<u8> = counts.iter().map(|(_, count)| *count).collect();
+
+    let rank = match rank_groups.as_slice() {
+        [4, ..] => HandRank::FourOfAKind,
+        [3, 2] => HandRank::FullHouse,
+        [3, ..] => HandRank::ThreeOfAKind,
+        [2, 2, ..] => HandRank::TwoPair,
+        [2, ..] => HandRank::OnePair,
+        _ => HandRank::HighCard,
+    };
+
+    LowScore {
+        rank,
+        ranks: counts.iter().map(|(rank, _)| *rank).collect(),
+    }
+}
+
+fn score_deuce_to_seven(cards: &[Card]) -> LowScore {
+    let score = evaluate_five_cards(cards, false);
+    let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank as u8).collect();
+    ranks.sort_by(|a, b| b.cmp(a));
+
+    // The ace only plays high, so A-2-3-4-5 is ace-high rather than a straight
+    match score.rank {
+        HandRank::Straight | HandRank::StraightFlush if ranks == [14, 5, 4, 3, 2] => LowScore {
+            rank: if score.rank == HandRank::StraightFlush {
+                HandRank::Flush
+            } else {
+                HandRank::HighCard
+            },
+            ranks,
+        },
+        HandRank::RoyalFlush => LowScore {
+            rank: HandRank::StraightFlush,
+            ranks: vec![14],
+        },
+        rank => LowScore {
+            rank,
+            ranks: score.tiebreakers,
+        },
+    }
+}
+
 fn combinations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
     if k == 0 {
         return vec![vec![]];
@@ -588,7 +1239,14 @@ fn combinations<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
     result
 }
 
-fn evaluate_five_cards(cards: &[Card]) -> HandScore {
+fn evaluate_five_cards(cards: &[Card], short_deck: bool) -> HandScore {
+    // The ace plays low below the lowest rank in the deck
+    let low_straight: Vec<u8> = if short_deck {
+        vec![14, 9, 8, 7, 6]
+    } else {
+        vec![14, 5, 4, 3, 2]
+    };
+
     let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank as u8).collect();
     ranks.sort_by(|a, b| b.cmp(a)); // Descending
 
@@ -600,8 +1258,13 @@ fn evaluate_five_cards(cards: &[Card]) -> HandScore {
     sorted_ranks.dedup();
     let is_straight = sorted_ranks.len() == 5
         && (sorted_ranks[0] - sorted_ranks[4] == 4 ||
-         // Ace-low straight (A-2-3-4-5)
-         (sorted_ranks == vec![14, 5, 4, 3, 2]));
+         // Ace-low straight (A-2-3-4-5, or A-6-7-8-9 in short deck)
+         (sorted_ranks == low_straight));
+    let straight_high = if ranks == low_straight {
+        low_straight[1]
+    } else {
+        ranks[0]
+    };
 
     // Count rank occurrences
     let mut rank_counts: std::collections::HashMap<u8, u8> = std::collections::HashMap::new();
@@ -618,7 +1281,7 @@ fn evaluate_five_cards(cards: &[Card]) -> HandScore {
     let (hand_rank, tiebreakers) = if is_flush && is_straight && ranks[0] == 14 && ranks[1] == 13 {
         (HandRank::RoyalFlush, vec![])
     } else if is_flush && is_straight {
-        (HandRank::StraightFlush, vec![ranks[0]])
+        (HandRank::StraightFlush, vec![straight_high])
     } else if rank_groups == vec![4, 1] {
         (HandRank::FourOfAKind, vec![counts[0].0, counts[1].0])
     } else if rank_groups == vec![3, 2] {
@@ -626,12 +1289,7 @@ fn evaluate_five_cards(cards: &[Card]) -> HandScore {
     } else if is_flush {
         (HandRank::Flush, ranks.clone())
     } else if is_straight {
-        // Handle ace-low straight
-        if ranks == vec![14, 5, 4, 3, 2] {
-            (HandRank::Straight, vec![5])
-        } else {
-            (HandRank::Straight, vec![ranks[0]])
-        }
+        (HandRank::Straight, vec![straight_high])
     } else if rank_groups == vec![3, 1, 1] {
         (
             HandRank::ThreeOfAKind,
@@ -654,55 +1312,1493 @@ fn evaluate_five_cards(cards: &[Card]) -> HandScore {
     HandScore {
         rank: hand_rank,
         tiebreakers,
+        short_deck,
     }
 }
 
 // ============================================================================
-// UTILITY: Generate deterministic "random" deck from seed
+// SIDE POTS
 // ============================================================================
 
-pub fn shuffle_deck(seed: &[u8]) -> Vec<Card> {
-    let mut cards: Vec<Card> = (0..52).filter_map(Card::from_index).collect();
+/// Main pot or side pot, with the seats that can win it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pot {
+    pub amount: Amount,
+    /// Seats still in the hand that covered this pot's level
+    pub eligible: Vec<Seat>,
+    /// Seats that won this pot (set at showdown)
+    pub winners: Vec<Seat>,
+}
 
-    // Fisher-Yates shuffle using seed
-    let mut hasher = Sha256::new();
-    hasher.update(seed);
-    let hash_bytes: [u8; 32] = hasher.finalize().into();
+/// Split the chips committed this hand into a main pot and side pots.
+///
+/// Each all-in level among players still in the hand closes a pot; a player
+/// is only eligible for pots up to their own total contribution. Chips folded
+/// players put in above the highest level go to the last pot.
+pub fn build_pots(players: &[PlayerInfo]) -> Vec<Pot> {
+    let mut levels: Vec<Amount> = players
+        .iter()
+        .filter(|p| !p.has_folded && p.total_contribution > Amount::ZERO)
+        .map(|p| p.total_contribution)
+        .collect();
+    levels.sort();
+    levels.dedup();
+
+    let mut pots: Vec<Pot> = Vec::new();
+    let mut previous = Amount::ZERO;
+    for level in levels {
+        let amount = players.iter().fold(Amount::ZERO, |sum, p| {
+            let slice = p
+                .total_contribution
+                .min(level)
+                .saturating_sub(p.total_contribution.min(previous));
+            sum.saturating_add(slice)
+        });
+        let eligible = players
+            .iter()
+            .filter(|p| !p.has_folded && p.total_contribution >= level)
+            .map(|p| p.seat)
+            .collect();
+        pots.push(Pot {
+            amount,
+            eligible,
+            winners: Vec::new(),
+        });
+        previous = level;
+    }
 
-    for i in (1..52).rev() {
-        let j = (hash_bytes[i % 32] as usize) % (i + 1);
-        cards.swap(i, j);
+    let leftover = players.iter().fold(Amount::ZERO, |sum, p| {
+        sum.saturating_add(p.total_contribution.saturating_sub(previous))
+    });
+    if leftover > Amount::ZERO {
+        match pots.last_mut() {
+            Some(pot) => pot.amount = pot.amount.saturating_add(leftover),
+            None => pots.push(Pot {
+                amount: leftover,
+                eligible: Vec::new(),
+                winners: Vec::new(),
+            }),
+        }
     }
 
-    cards
+    pots
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Chips one seat took from one pot at settlement
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PotAward {
+    /// Index into the pots (0 = main pot)
+    pub pot: usize,
+    pub seat: Seat,
+    pub amount: Amount,
+}
 
-    #[test]
-    fn test_card_index_roundtrip() {
-        for i in 0..52 {
-            let card = Card::from_index(i).unwrap();
-            assert_eq!(card.to_index(), i);
+/// Give back the part of the biggest contribution nobody matched.
+///
+/// Returns the seat and the amount moved from its contribution back to its
+/// stack, if the top contributor was not called in full.
+pub fn return_uncalled_bet(players: &mut [PlayerInfo]) -> Option<(Seat, Amount)> {
+    let mut contributions: Vec<Amount> = players.iter().map(|p| p.total_contribution).collect();
+    contributions.sort_by(|a, b| b.cmp(a));
+    let top = *contributions.first()?;
+    let called = contributions.get(1).copied().unwrap_or(Amount::ZERO);
+    if top == called {
+        return None;
+    }
+
+    let player = players.iter_mut().find(|p| p.total_contribution == top)?;
+    let refund = top.saturating_sub(called);
+    player.total_contribution = called;
+    player.current_bet = player.current_bet.saturating_sub(refund);
+    player.stack = player.stack.saturating_add(refund);
+    Some((player.seat, refund))
+}
+
+/// Seats among `eligible` holding the best of the scored hands (all of them on a tie).
+///
+/// Empty if none of the eligible seats has a score.
+pub fn best_hands<S: Ord>(scores: &[(Seat, S)], eligible: &[Seat]) -> Vec<Seat> {
+    let contenders: Vec<&(Seat, S)> = scores
+        .iter()
+        .filter(|(seat, _)| eligible.contains(seat))
+        .collect();
+    match contenders.iter().map(|(_, score)| score).max() {
+        Some(best) => contenders
+            .iter()
+            .filter(|(_, score)| score == best)
+            .map(|(seat, _)| *seat)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Split a pot evenly between its winners, in whole chips.
+///
+/// Odd chips that don't divide evenly go one at a time to the winners in
+/// clockwise order, starting with the first winner left of the button; that
+/// winner also takes any fraction of a chip the pot holds (after rake).
+pub fn split_pot(pot: &Pot, button: Option<Seat>) -> Vec<(Seat, Amount)> {
+    let count = pot.winners.len() as u128;
+    if count == 0 {
+        return Vec::new();
+    }
+
+    let chip = Amount::ONE.to_attos();
+    let chips = pot.amount.to_attos() / chip;
+    let share = Amount::from_attos(chips / count * chip);
+    let mut odd_chips = chips % count;
+    let fraction = Amount::from_attos(pot.amount.to_attos() % chip);
+    let mut split: Vec<(Seat, Amount)> = pot.winners.iter().map(|seat| (*seat, share)).collect();
+
+    let mut seat = button
+        .and_then(|b| b.next_in(&pot.winners))
+        .unwrap_or(pot.winners[0]);
+    if let Some((_, amount)) = split.iter_mut().find(|(s, _)| *s == seat) {
+        *amount = amount.saturating_add(fraction);
+    }
+    while odd_chips > 0 {
+        if let Some((_, amount)) = split.iter_mut().find(|(s, _)| *s == seat) {
+            *amount = amount.saturating_add(Amount::ONE);
         }
+        odd_chips -= 1;
+        seat = seat.next_in(&pot.winners).unwrap_or(seat);
     }
+    split
+}
 
-    #[test]
-    fn test_encrypted_card_verify() {
-        let card = Card::new(Suit::Hearts, Rank::Ace);
-        let secret = b"dealer_secret_key";
-        let nonce = [0u8; 16];
+// ============================================================================
+// RAKE
+// ============================================================================
 
-        let encrypted = EncryptedCard::new(card, secret, nonce);
-        assert!(encrypted.verify(card, secret));
+/// House fee taken from every pot of a hand and paid to the operator
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RakeConfig {
+    /// Percentage of each pot taken
+    pub percent: u8,
+    /// Most taken from one hand, in tokens (0 = no cap)
+    pub cap: u64,
+    /// No rake from hands that end on the first betting round ("no flop, no drop")
+    pub no_flop_no_drop: bool,
+    /// Chain the rake is paid to
+    pub fee_recipient: ChainId,
+    /// Token application on the table chain the rake is transferred through
+    /// (None = collected at the table only)
+    #[serde(default)]
+    pub token_app: Option<ApplicationId>,
+    /// Jackpot funded by a slice of the rake (None = no jackpot)
+    #[serde(default)]
+    pub jackpot: Option<JackpotConfig>,
+}
+
+/// Bad-beat and high-hand jackpot: part of every hand's rake goes into a
+/// pool paid out when a qualifying hand is shown down
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JackpotConfig {
+    /// Percentage of the rake dropped into the jackpot
+    pub percent: u8,
+    /// Bad beat: a hand this strong or better loses at showdown to a better
+    /// hand (None = no bad-beat jackpot)
+    pub bad_beat: Option<HandRank>,
+    /// Percentages of the pool paid on a bad beat to the losing hand, the
+    /// winning hand and everyone else dealt in (split evenly)
+    pub loser_share: u8,
+    pub winner_share: u8,
+    pub table_share: u8,
+    /// High hand: the winning hand is this strong or better (None = no
+    /// high-hand jackpot)
+    pub high_hand: Option<HandRank>,
+    /// Percentage of the pool paid to a high hand
+    pub high_hand_share: u8,
+}
+
+/// Jackpot won at showdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JackpotHit {
+    /// `loser`'s qualifying hand lost to `winner`'s better hand
+    BadBeat { loser: Seat, winner: Seat },
+    /// `winner` won with a qualifying hand
+    HighHand { winner: Seat },
+}
+
+/// Jackpot won by the high hands shown down, if any; a bad beat takes
+/// precedence over a high hand
+pub fn jackpot_hit(config: &JackpotConfig, highs: &[(Seat, HandScore)]) -> Option<JackpotHit> {
+    let best = highs.iter().map(|(_, score)| score).max()?;
+    let (winner, _) = highs.iter().find(|(_, score)| score == best)?;
+
+    // The best of the hands that lost
+    let beaten = highs
+        .iter()
+        .map(|(_, score)| score)
+        .filter(|score| *score < best)
+        .max();
+    if let (Some(rank), Some(beaten)) = (config.bad_beat, beaten) {
+        if beaten.at_least(rank) {
+            let (loser, _) = highs.iter().find(|(_, score)| score == beaten)?;
+            return Some(JackpotHit::BadBeat {
+                loser: *loser,
+                winner: *winner,
+            });
+        }
+    }
+
+    match config.high_hand {
+        Some(rank) if best.at_least(rank) => Some(JackpotHit::HighHand { winner: *winner }),
+        _ => None,
+    }
+}
+
+/// What each seat is paid out of a jackpot `pool` for `hit`.
+///
+/// The table share is split evenly between the seats `dealt_in` other than
+/// the loser and winner; with nobody else dealt in it stays in the pool, as
+/// does anything the shares leave over.
+pub fn jackpot_payouts(
+    config: &JackpotConfig,
+    hit: JackpotHit,
+    pool: Amount,
+    dealt_in: &[Seat],
+) -> Vec<(Seat, Amount)> {
+    let share = |percent: u8| Amount::from_attos(pool.to_attos() * u128::from(percent) / 100);
+    match hit {
+        JackpotHit::BadBeat { loser, winner } => {
+            let mut payouts = vec![
+                (loser, share(config.loser_share)),
+                (winner, share(config.winner_share)),
+            ];
+            let table: Vec<Seat> = dealt_in
+                .iter()
+                .filter(|seat| **seat != loser && **seat != winner)
+                .copied()
+                .collect();
+            if !table.is_empty() {
+                let each = share(config.table_share).saturating_div(table.len() as u128);
+                payouts.extend(table.into_iter().map(|seat| (seat, each)));
+            }
+            payouts
+        }
+        JackpotHit::HighHand { winner } => vec![(winner, share(config.high_hand_share))],
+    }
+}
+
+/// Take `percent` of every pot for the house, main pot first, until `cap`
+/// has been taken. Returns the rake taken out of the pots.
+pub fn take_rake(pots: &mut [Pot], percent: u8, cap: Option<Amount>) -> Amount {
+    let mut taken = Amount::ZERO;
+    for pot in pots.iter_mut() {
+        let mut rake = Amount::from_attos(pot.amount.to_attos() * u128::from(percent) / 100);
+        if let Some(cap) = cap {
+            rake = rake.min(cap.saturating_sub(taken));
+        }
+        pot.amount = pot.amount.saturating_sub(rake);
+        taken = taken.saturating_add(rake);
+    }
+    taken
+}
+
+// ============================================================================
+// TOURNAMENTS
+// ============================================================================
+
+/// Blinds and ante of one level of a tournament's blind schedule, in chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindLevel {
+    pub small_blind: u64,
+    pub big_blind: u64,
+    /// Ante per player dealt in (0 = no ante)
+    pub ante: u64,
+}
+
+/// How long each blind level lasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LevelDuration {
+    /// Levels go up after this many hands
+    Hands(u32),
+    /// Levels go up after this many blocks since the first hand was dealt
+    Blocks(u64),
+}
+
+/// Sit-and-go tournament: every player pays the same buy-in into a prize
+/// pool and plays for tournament chips until one player has them all
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentConfig {
+    /// Tokens each player pays into the prize pool to take a seat
+    pub buy_in: u64,
+    /// Tournament chips every player starts with (not tokens)
+    pub starting_chips: u64,
+    /// Blind schedule; the last level stays once reached
+    pub levels: Vec<BlindLevel>,
+    /// How long each level lasts
+    pub level_duration: LevelDuration,
+    /// Percent of the prize pool paid to each finishing position, first place first
+    pub payouts: Vec<u8>,
+    /// Token application on the table chain holding the prize pool; prizes
+    /// are transferred from it to the players' chains (None = results only)
+    #[serde(default)]
+    pub token_app: Option<ApplicationId>,
+    /// Multi-table tournament: chain of the coordinator that seats and moves
+    /// players, sets the blind level and pays the prizes (None = sit-and-go)
+    #[serde(default)]
+    pub coordinator: Option<ChainId>,
+}
+
+impl TournamentConfig {
+    /// Index of the blind level in play after `hands_played` hands, or
+    /// `blocks_elapsed` blocks since the first hand
+    pub fn level_index(&self, hands_played: u32, blocks_elapsed: u64) -> usize {
+        let level = match self.level_duration {
+            LevelDuration::Hands(hands) => (hands_played / hands.max(1)) as usize,
+            LevelDuration::Blocks(blocks) => (blocks_elapsed / blocks.max(1)) as usize,
+        };
+        level.min(self.levels.len().saturating_sub(1))
+    }
+}
+
+/// Where a player finished a tournament and the prize paid for it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentFinish {
+    pub chain_id: ChainId,
+    pub seat: Seat,
+    /// Finishing position (1 = winner)
+    pub place: u32,
+    pub prize: Amount,
+}
+
+/// Player moved off a multi-table tournament table, with the chips they left with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Departure {
+    pub chain_id: ChainId,
+    pub hand_app: Option<ApplicationId>,
+    pub chips: Amount,
+    /// Entropy commitment the player holds for their next hand
+    pub entropy_commitment: [u8; 32],
+}
+
+/// What a multi-table tournament table reports to its coordinator
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableReport {
+    /// Players seated at the table and their chips
+    pub stacks: Vec<(ChainId, Amount)>,
+    /// Every player knocked out at this table, the earliest (worst placed) first
+    pub knocked_out: Vec<ChainId>,
+    /// Players that left for another table since the last report
+    pub departures: Vec<Departure>,
+    /// Hands dealt at this table
+    pub hands_played: u32,
+    /// Index into the blind schedule of the level the table plays
+    pub blind_level: u32,
+}
+
+/// Prize for each paid finishing position, first place first.
+///
+/// Only positions among the `entrants` are paid. Lower places get their
+/// percentage of the pool in whole tokens; whatever they don't take,
+/// rounding included, goes to the winner.
+pub fn prize_payouts(prize_pool: Amount, payouts: &[u8], entrants: usize) -> Vec<Amount> {
+    let paid = &payouts[..payouts.len().min(entrants)];
+    if paid.is_empty() {
+        return Vec::new();
+    }
+
+    let pool_tokens = prize_pool.to_attos() / Amount::ONE.to_attos();
+    let mut prizes: Vec<Amount> = paid
+        .iter()
+        .map(|percent| Amount::from_tokens(pool_tokens * u128::from(*percent) / 100))
+        .collect();
+    let lower_places = prizes[1..]
+        .iter()
+        .fold(Amount::ZERO, |sum, prize| sum.saturating_add(*prize));
+    prizes[0] = prize_pool.saturating_sub(lower_places);
+    prizes
+}
+
+/// How the players left in a tournament split the prizes still to be paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DealKind {
+    /// Independent Chip Model: each player's expected winnings from their
+    /// chances of finishing in each paid place
+    Icm,
+    /// Chip chop: the prizes split in proportion to chips
+    ChipChop,
+}
+
+/// Attos in the billionth of a token deal figures are worked out in
+const DEAL_UNIT: u128 = 1_000_000_000;
+
+/// Fixed-point 1 for finishing probabilities
+const CERTAIN: u128 = 1_000_000_000_000_000_000;
+
+/// Independent Chip Model equity of each stack in the `prizes` still to be
+/// paid, first place first.
+///
+/// A player finishes first with probability stack / total chips, and each
+/// next place goes the same way among the players still in (Malmuth-Harville).
+/// Exact to a billionth of a token.
+pub fn icm_equity(stacks: &[Amount], prizes: &[Amount]) -> Vec<Amount> {
+    let chips: Vec<u128> = stacks.iter().map(|s| s.to_attos() / DEAL_UNIT).collect();
+    let prizes: Vec<u128> = prizes.iter().map(|p| p.to_attos() / DEAL_UNIT).collect();
+    let total: u128 = chips.iter().sum();
+    let mut equity = vec![0u128; chips.len()];
+
+    // Probability that the players in each set take the top places, in any order
+    let mut top_places = vec![0u128; 1 << chips.len()];
+    top_places[0] = CERTAIN;
+    for placed in 0..top_places.len() {
+        let place = placed.count_ones() as usize;
+        if top_places[placed] == 0 || place >= prizes.len() {
+            continue;
+        }
+        let placed_chips: u128 = (0..chips.len())
+            .filter(|i| placed & (1 << i) != 0)
+            .map(|i| chips[i])
+            .sum();
+        let remaining = total - placed_chips;
+        for (i, stack) in chips.iter().enumerate() {
+            if placed & (1 << i) != 0 || *stack == 0 {
+                continue;
+            }
+            let next = top_places[placed] * stack / remaining;
+            top_places[placed | (1 << i)] += next;
+            equity[i] += next * prizes[place];
+        }
+    }
+
+    equity
+        .iter()
+        .map(|e| Amount::from_attos(e / CERTAIN * DEAL_UNIT))
+        .collect()
+}
+
+/// Chip chop: each stack's share of the `prizes` still to be paid, in
+/// proportion to its chips. Exact to a billionth of a token.
+pub fn chip_chop(stacks: &[Amount], prizes: &[Amount]) -> Vec<Amount> {
+    let chips: Vec<u128> = stacks.iter().map(|s| s.to_attos() / DEAL_UNIT).collect();
+    let pool: u128 = prizes.iter().map(|p| p.to_attos() / DEAL_UNIT).sum();
+    let total: u128 = chips.iter().sum();
+    chips
+        .iter()
+        .map(|stack| match total {
+            0 => Amount::ZERO,
+            _ => Amount::from_attos(pool * stack / total * DEAL_UNIT),
+        })
+        .collect()
+}
+
+/// What each stack is paid under a deal on the `prizes` still to be paid.
+///
+/// Everyone but the chip leader gets their share in whole tokens; the chip
+/// leader takes whatever is left, rounding included.
+pub fn deal_payouts(kind: DealKind, stacks: &[Amount], prizes: &[Amount]) -> Vec<Amount> {
+    let shares = match kind {
+        DealKind::Icm => icm_equity(stacks, prizes),
+        DealKind::ChipChop => chip_chop(stacks, prizes),
+    };
+    let leader = match (0..stacks.len()).max_by_key(|i| (stacks[*i], std::cmp::Reverse(*i))) {
+        Some(leader) => leader,
+        None => return Vec::new(),
+    };
+
+    let mut payouts: Vec<Amount> = shares
+        .iter()
+        .map(|share| Amount::from_tokens(share.to_attos() / Amount::ONE.to_attos()))
+        .collect();
+    let pool = prizes
+        .iter()
+        .fold(Amount::ZERO, |sum, prize| sum.saturating_add(*prize));
+    let others = payouts
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != leader)
+        .fold(Amount::ZERO, |sum, (_, payout)| sum.saturating_add(*payout));
+    payouts[leader] = pool.saturating_sub(others);
+    payouts
+}
+
+// ============================================================================
+// DECK ENTROPY (player-contributed randomness)
+// ============================================================================
+
+/// Commitment to a player's deck entropy: the player sends this when they
+/// sit down and the entropy itself once the blinds are in, so nobody can
+/// pick their entropy after seeing anyone else's
+pub fn entropy_commitment(entropy: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"LINERA_POKER_ENTROPY");
+    hasher.update(entropy);
+    hasher.finalize().into()
+}
+
+/// Deck seed for a hand: every dealt-in player's revealed entropy, in seat
+/// order, bound to the table and the game ID. Anyone can recompute it from
+/// the reveals; no single player can predict it before the last reveal.
+pub fn deck_seed(table_chain: ChainId, game_id: u64, reveals: &[(Seat, [u8; 32])]) -> Vec<u8> {
+    let mut reveals = reveals.to_vec();
+    reveals.sort_by_key(|(seat, _)| seat.index());
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"LINERA_POKER_DECK");
+    hasher.update(game_id.to_le_bytes());
+    hasher.update(table_chain.to_string().as_bytes());
+    for (seat, entropy) in &reveals {
+        hasher.update([seat.index() as u8]);
+        hasher.update(entropy);
+    }
+    hasher.finalize().to_vec()
+}
+
+// ============================================================================
+// UTILITY: Generate deterministic "random" deck from seed
+// ============================================================================
+
+/// Cards in a short deck: the 2-5 of every suit removed
+pub const SHORT_DECK_SIZE: usize = 36;
+
+/// Shuffle the 52-card deck; the same seed always gives the same order
+pub fn shuffle_deck(seed: &[u8]) -> Vec<Card> {
+    shuffle_cards((0..52).filter_map(Card::from_index).collect(), seed)
+}
+
+/// Shuffle the 36-card short deck (sixes and up)
+pub fn shuffle_short_deck(seed: &[u8]) -> Vec<Card> {
+    let cards = (0..SHORT_DECK_SIZE as u8)
+        .filter_map(Card::from_short_deck_index)
+        .collect();
+    shuffle_cards(cards, seed)
+}
+
+/// Fisher-Yates shuffle driven by a ChaCha20 stream keyed from the seed,
+/// so every ordering of the deck is reachable and equally likely
+fn shuffle_cards(mut cards: Vec<Card>, seed: &[u8]) -> Vec<Card> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"LINERA_POKER_SHUFFLE");
+    hasher.update(seed);
+    let mut rng = ChaCha20Rng::from_seed(hasher.finalize().into());
+
+    for i in (1..cards.len()).rev() {
+        let j = uniform_below(&mut rng, i as u32 + 1) as usize;
+        cards.swap(i, j);
+    }
+
+    cards
+}
+
+/// Uniform draw from `0..bound`, rejecting the top of the range that
+/// would otherwise make the low values more likely
+pub(crate) fn uniform_below(rng: &mut ChaCha20Rng, bound: u32) -> u32 {
+    let zone = u32::MAX - u32::MAX % bound;
+    loop {
+        let value = rng.next_u32();
+        if value < zone {
+            return value % bound;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_index_roundtrip() {
+        for i in 0..52 {
+            let card = Card::from_index(i).unwrap();
+            assert_eq!(card.to_index(), i);
+        }
+    }
+
+    #[test]
+    fn test_encrypted_card_verify() {
+        let card = Card::new(Suit::Hearts, Rank::Ace);
+        let secret = b"dealer_secret_key";
+        let nonce = [0u8; 16];
+
+        let encrypted = EncryptedCard::new(card, secret, nonce);
+        assert!(encrypted.verify(card, secret));
 
         // Wrong card should fail
         let wrong_card = Card::new(Suit::Spades, Rank::King);
         assert!(!encrypted.verify(wrong_card, secret));
     }
 
+    #[test]
+    fn test_seat_next_in_wraps_clockwise() {
+        let seats = [Seat::Player2, Seat::Player5, Seat::Player9];
+        assert_eq!(Seat::Player2.next_in(&seats), Some(Seat::Player5));
+        assert_eq!(Seat::Player5.next_in(&seats), Some(Seat::Player9));
+        assert_eq!(Seat::Player9.next_in(&seats), Some(Seat::Player2));
+        // Seats that are not occupied still find the next occupied one
+        assert_eq!(Seat::Player1.next_in(&seats), Some(Seat::Player2));
+        assert_eq!(Seat::Player2.next_in(&[Seat::Player2]), None);
+    }
+
+    #[test]
+    fn test_seat_index_roundtrip() {
+        for (idx, seat) in Seat::ALL.iter().enumerate() {
+            assert_eq!(seat.index(), idx);
+            assert_eq!(Seat::from_index(idx), Some(*seat));
+        }
+        assert_eq!(Seat::from_index(Seat::MAX_SEATS), None);
+    }
+
+    fn contributor(seat: Seat, tokens: u128, has_folded: bool) -> PlayerInfo {
+        PlayerInfo {
+            seat,
+            chain_id: "0".repeat(64).parse().unwrap(),
+            owner: AccountOwner::CHAIN,
+            stake: Amount::from_tokens(tokens),
+            stack: Amount::ZERO,
+            hand_app: None,
+            has_folded,
+            current_bet: Amount::ZERO,
+            has_revealed: false,
+            has_acted: false,
+            total_contribution: Amount::from_tokens(tokens),
+            sitting_out: false,
+            pending_top_up: Amount::ZERO,
+            cashing_out: false,
+        }
+    }
+
+    #[test]
+    fn test_commit_caps_at_stack() {
+        let mut player = contributor(Seat::Player1, 0, false);
+        player.stack = Amount::from_tokens(30);
+
+        assert_eq!(player.commit(Amount::from_tokens(10)), Amount::from_tokens(10));
+        assert!(!player.is_all_in());
+        // Calling more than the stack puts the player all-in for the rest
+        assert_eq!(player.commit(Amount::from_tokens(50)), Amount::from_tokens(20));
+        assert!(player.is_all_in());
+        assert_eq!(player.current_bet, Amount::from_tokens(30));
+        assert_eq!(player.total_contribution, Amount::from_tokens(30));
+    }
+
+    #[test]
+    fn test_post_ante_is_dead_money() {
+        let mut player = contributor(Seat::Player1, 0, false);
+        player.stack = Amount::from_tokens(3);
+
+        assert_eq!(player.post_ante(Amount::from_tokens(5)), Amount::from_tokens(3));
+        assert_eq!(player.total_contribution, Amount::from_tokens(3));
+        // Antes don't count toward matching the street's bet
+        assert_eq!(player.current_bet, Amount::ZERO);
+        assert!(player.is_all_in());
+    }
+
+    #[test]
+    fn test_build_pots_single_pot_when_equal() {
+        let players = vec![
+            contributor(Seat::Player1, 50, false),
+            contributor(Seat::Player2, 50, false),
+        ];
+        let pots = build_pots(&players);
+        assert_eq!(pots.len(), 1);
+        assert_eq!(pots[0].amount, Amount::from_tokens(100));
+        assert_eq!(pots[0].eligible, vec![Seat::Player1, Seat::Player2]);
+    }
+
+    #[test]
+    fn test_build_pots_short_stack_all_in() {
+        let players = vec![
+            contributor(Seat::Player1, 100, false),
+            contributor(Seat::Player2, 100, false),
+            contributor(Seat::Player3, 30, false),
+        ];
+        let pots = build_pots(&players);
+        assert_eq!(pots.len(), 2);
+        // Short stack can only win what they covered from each opponent
+        assert_eq!(pots[0].amount, Amount::from_tokens(90));
+        assert_eq!(
+            pots[0].eligible,
+            vec![Seat::Player1, Seat::Player2, Seat::Player3]
+        );
+        assert_eq!(pots[1].amount, Amount::from_tokens(140));
+        assert_eq!(pots[1].eligible, vec![Seat::Player1, Seat::Player2]);
+    }
+
+    #[test]
+    fn test_build_pots_folded_chips_stay_in_pot() {
+        let players = vec![
+            contributor(Seat::Player1, 40, true),
+            contributor(Seat::Player2, 20, false),
+            contributor(Seat::Player3, 60, false),
+        ];
+        let pots = build_pots(&players);
+        let total = pots
+            .iter()
+            .fold(Amount::ZERO, |sum, pot| sum.saturating_add(pot.amount));
+        assert_eq!(total, Amount::from_tokens(120));
+        assert_eq!(pots[0].amount, Amount::from_tokens(60));
+        assert_eq!(pots[1].eligible, vec![Seat::Player3]);
+        assert!(pots.iter().all(|pot| !pot.eligible.contains(&Seat::Player1)));
+    }
+
+    #[test]
+    fn test_return_uncalled_bet() {
+        let mut players = vec![
+            contributor(Seat::Player1, 90, false),
+            contributor(Seat::Player2, 30, true),
+            contributor(Seat::Player3, 40, false),
+        ];
+        assert_eq!(
+            return_uncalled_bet(&mut players),
+            Some((Seat::Player1, Amount::from_tokens(50)))
+        );
+        assert_eq!(players[0].total_contribution, Amount::from_tokens(40));
+        assert_eq!(players[0].stack, Amount::from_tokens(50));
+        // Nothing left to return once the top bet is matched
+        assert_eq!(return_uncalled_bet(&mut players), None);
+    }
+
+    #[test]
+    fn test_split_pot_odd_chips_go_left_of_button() {
+        let pot = Pot {
+            amount: Amount::from_tokens(11),
+            eligible: vec![Seat::Player1, Seat::Player2, Seat::Player4],
+            winners: vec![Seat::Player1, Seat::Player2, Seat::Player4],
+        };
+        // Button on Player2: Player4 is first left of it, then Player1
+        let split = split_pot(&pot, Some(Seat::Player2));
+        assert_eq!(
+            split,
+            vec![
+                (Seat::Player1, Amount::from_tokens(4)),
+                (Seat::Player2, Amount::from_tokens(3)),
+                (Seat::Player4, Amount::from_tokens(4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_pot_in_whole_chips() {
+        let seats = vec![Seat::Player1, Seat::Player2, Seat::Player3];
+        let mut pot = Pot {
+            amount: Amount::from_tokens(10),
+            eligible: seats.clone(),
+            winners: seats,
+        };
+        // Button on Player1: the odd chip goes to Player2
+        assert_eq!(
+            split_pot(&pot, Some(Seat::Player1)),
+            vec![
+                (Seat::Player1, Amount::from_tokens(3)),
+                (Seat::Player2, Amount::from_tokens(4)),
+                (Seat::Player3, Amount::from_tokens(3)),
+            ]
+        );
+
+        // A raked pot's fraction of a chip goes with the first odd chip
+        pot.amount = Amount::from_millis(10_500);
+        let split = split_pot(&pot, Some(Seat::Player1));
+        assert_eq!(split[1], (Seat::Player2, Amount::from_millis(4_500)));
+        assert_eq!(split[0].1, Amount::from_tokens(3));
+        assert_eq!(split[2].1, Amount::from_tokens(3));
+    }
+
+    #[test]
+    fn test_pot_limit_raise_limits() {
+        // Blinds 5/10 posted, button to act with 5 in and 95 behind
+        let mut player = contributor(Seat::Player1, 5, false);
+        player.current_bet = Amount::from_tokens(5);
+        player.stack = Amount::from_tokens(95);
+        let street = StreetBetting {
+            current_bet: Amount::from_tokens(10),
+            min_raise: Amount::from_tokens(10),
+            pot: Amount::from_tokens(15),
+            raises: 1,
+        };
+        // Call 5 makes the pot 20, so the raise can be at most 20 more
+        assert_eq!(
+            BettingStructure::PotLimit.raise_limits(&player, &street),
+            Some((Amount::from_tokens(20), Amount::from_tokens(30)))
+        );
+        assert_eq!(
+            BettingStructure::NoLimit.raise_limits(&player, &street),
+            Some((Amount::from_tokens(20), Amount::from_tokens(100)))
+        );
+    }
+
+    #[test]
+    fn test_fixed_limit_raise_cap() {
+        let fixed = BettingStructure::FixedLimit {
+            small_bet: Amount::from_tokens(10),
+            big_bet: Amount::from_tokens(20),
+            raise_cap: 4,
+        };
+        assert_eq!(
+            fixed.opening_min_raise(GamePhase::Turn, Amount::from_tokens(10)),
+            Amount::from_tokens(20)
+        );
+
+        let mut player = contributor(Seat::Player2, 0, false);
+        player.stack = Amount::from_tokens(100);
+        let mut street = StreetBetting {
+            current_bet: Amount::from_tokens(30),
+            min_raise: Amount::from_tokens(10),
+            pot: Amount::from_tokens(60),
+            raises: 3,
+        };
+        // Raises come in one fixed size
+        assert_eq!(
+            fixed.raise_limits(&player, &street),
+            Some((Amount::from_tokens(40), Amount::from_tokens(40)))
+        );
+        // Capped: call or fold only
+        street.raises = 4;
+        assert_eq!(fixed.raise_limits(&player, &street), None);
+    }
+
+    #[test]
+    fn test_fixed_limit_completes_the_bring_in() {
+        let fixed = BettingStructure::FixedLimit {
+            small_bet: Amount::from_tokens(10),
+            big_bet: Amount::from_tokens(20),
+            raise_cap: 4,
+        };
+        let mut player = contributor(Seat::Player2, 0, false);
+        player.stack = Amount::from_tokens(100);
+        // Bring-in of 2 on third street
+        let street = StreetBetting {
+            current_bet: Amount::from_tokens(2),
+            min_raise: Amount::from_tokens(10),
+            pot: Amount::from_tokens(2),
+            raises: 0,
+        };
+        assert_eq!(fixed.completion(&street), Some(Amount::from_tokens(10)));
+        assert_eq!(
+            fixed.raise_limits(&player, &street),
+            Some((Amount::from_tokens(10), Amount::from_tokens(10)))
+        );
+        // No-limit raises the bring-in by a whole bet
+        assert_eq!(BettingStructure::NoLimit.completion(&street), None);
+    }
+
+    #[test]
+    fn test_omaha_uses_exactly_two_hole_cards() {
+        // Four hearts on board, one heart in hand: no flush in Omaha
+        let hole = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Diamonds, Rank::Two),
+        ];
+        let community = vec![
+            Card::new(Suit::Hearts, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Queen),
+        ];
+        assert_eq!(evaluate_hand(&hole, &community).rank, HandRank::Flush);
+        assert_eq!(evaluate_omaha(&hole, &community).rank, HandRank::HighCard);
+
+        // Quads in hand only count as a pair plus three board cards
+        let hole = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Ace),
+        ];
+        let score = PokerVariant::Omaha.rules().evaluate(&hole, &community);
+        assert_eq!(score.rank, HandRank::OnePair);
+        assert_eq!(PokerVariant::Omaha.rules().hole_cards(), 4);
+        assert_eq!(PokerVariant::HoldEm.rules().hole_cards(), 2);
+    }
+
+    #[test]
+    fn test_short_deck_rankings() {
+        let deck = shuffle_short_deck(b"seed");
+        assert_eq!(deck.len(), SHORT_DECK_SIZE);
+        assert!(deck.iter().all(|c| c.rank as u8 >= 6));
+        for i in 0..SHORT_DECK_SIZE as u8 {
+            let card = Card::from_short_deck_index(i).unwrap();
+            assert_eq!(card.to_short_deck_index(), Some(i));
+        }
+
+        // A-6-7-8-9 is the low straight, ranked below 6-7-8-9-T
+        let hole = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::Six),
+        ];
+        let community = vec![
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Queen),
+        ];
+        let wheel = evaluate_short_deck(&hole, &community);
+        assert_eq!(wheel.rank, HandRank::Straight);
+        assert_eq!(wheel.tiebreakers, vec![9]);
+        assert_eq!(evaluate_hand(&hole, &community).rank, HandRank::HighCard);
+
+        let ten_high = evaluate_short_deck(
+            &[
+                Card::new(Suit::Hearts, Rank::Ten),
+                Card::new(Suit::Clubs, Rank::Six),
+            ],
+            &community,
+        );
+        assert!(ten_high > wheel);
+
+        // Flush beats full house
+        let community = vec![
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Clubs, Rank::Queen),
+        ];
+        let flush = PokerVariant::ShortDeck.rules().evaluate(
+            &[
+                Card::new(Suit::Hearts, Rank::Ace),
+                Card::new(Suit::Hearts, Rank::Six),
+            ],
+            &community,
+        );
+        let full_house = PokerVariant::ShortDeck.rules().evaluate(
+            &[
+                Card::new(Suit::Diamonds, Rank::Eight),
+                Card::new(Suit::Clubs, Rank::Seven),
+            ],
+            &community,
+        );
+        assert_eq!(flush.rank, HandRank::Flush);
+        assert_eq!(full_house.rank, HandRank::FullHouse);
+        assert!(flush > full_house);
+    }
+
+    #[test]
+    fn test_stud_streets_and_bring_in() {
+        let stud = PokerVariant::SevenCardStud.rules();
+        let mut street = stud.first_street();
+        let mut dealt = (0, 0);
+        while street != GamePhase::Showdown {
+            assert!(street.is_betting_round());
+            let (down, up) = stud.street_cards(street);
+            dealt = (dealt.0 + down, dealt.1 + up);
+            street = stud.next_street(street).unwrap();
+        }
+        assert_eq!(dealt, (stud.hole_cards(), 4));
+        assert_eq!(PokerVariant::HoldEm.rules().next_street(GamePhase::River), Some(GamePhase::Showdown));
+        assert_eq!(stud.next_street(GamePhase::Flop), None);
+
+        // Lowest rank brings it in; clubs are the lowest suit
+        let up_cards = [
+            (Seat::Player1, Card::new(Suit::Spades, Rank::Three)),
+            (Seat::Player2, Card::new(Suit::Clubs, Rank::Three)),
+            (Seat::Player3, Card::new(Suit::Hearts, Rank::Ace)),
+        ];
+        assert_eq!(bring_in_seat(&up_cards), Some(Seat::Player2));
+
+        // A pair showing beats higher cards without one
+        let pair = evaluate_up_cards(&[
+            Card::new(Suit::Hearts, Rank::Four),
+            Card::new(Suit::Clubs, Rank::Four),
+        ]);
+        let ace_king = evaluate_up_cards(&[
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Clubs, Rank::King),
+        ]);
+        assert_eq!(pair.rank, HandRank::OnePair);
+        assert!(pair > ace_king);
+
+        // ...so the pair opens the betting on later streets
+        let showing = vec![
+            (
+                Seat::Player1,
+                vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Clubs, Rank::King)],
+            ),
+            (
+                Seat::Player2,
+                vec![Card::new(Suit::Hearts, Rank::Four), Card::new(Suit::Clubs, Rank::Four)],
+            ),
+        ];
+        let active = [Seat::Player1, Seat::Player2];
+        assert_eq!(stud.first_to_act(Seat::Player2, &active, &showing), Some(Seat::Player2));
+        assert_eq!(
+            PokerVariant::HoldEm.rules().first_to_act(Seat::Player2, &active, &showing),
+            Some(Seat::Player1)
+        );
+    }
+
+    #[test]
+    fn test_five_card_draw_streets() {
+        let draw = PokerVariant::FiveCardDraw.rules();
+        assert_eq!(draw.first_street(), GamePhase::PreDraw);
+        assert_eq!(draw.next_street(GamePhase::PreDraw), Some(GamePhase::Draw));
+        assert_eq!(draw.next_street(GamePhase::Draw), Some(GamePhase::PostDraw));
+        assert_eq!(draw.next_street(GamePhase::PostDraw), Some(GamePhase::Showdown));
+        assert!(!GamePhase::Draw.is_betting_round());
+        assert_eq!(draw.max_discards(), 3);
+        assert_eq!(PokerVariant::HoldEm.rules().max_discards(), 0);
+
+        // Five cards and no board make the whole hand
+        let hand = [
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Four),
+            Card::new(Suit::Diamonds, Rank::Four),
+        ];
+        assert_eq!(draw.evaluate(&hand, &[]).rank, HandRank::FullHouse);
+    }
+
+    #[test]
+    fn test_ace_to_five_and_deuce_to_seven_lows() {
+        let wheel = [
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Four),
+            Card::new(Suit::Hearts, Rank::Five),
+        ];
+        let seven_low = [
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Four),
+            Card::new(Suit::Diamonds, Rank::Three),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+        let nine_low = [
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Four),
+            Card::new(Suit::Diamonds, Rank::Three),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+
+        // Ace-to-five: the suited wheel is the nut low, and qualifies
+        let nuts = ace_to_five_low(&wheel);
+        assert_eq!(nuts.rank, HandRank::HighCard);
+        assert_eq!(nuts.ranks, vec![5, 4, 3, 2, 1]);
+        assert!(nuts > ace_to_five_low(&seven_low));
+        assert!(ace_to_five_low(&seven_low).qualifies_eight_or_better());
+        assert!(!ace_to_five_low(&nine_low).qualifies_eight_or_better());
+
+        // Best five of seven: the pair and the king are left out
+        let mut seven_cards = seven_low.to_vec();
+        seven_cards.push(Card::new(Suit::Hearts, Rank::Seven));
+        seven_cards.push(Card::new(Suit::Hearts, Rank::King));
+        assert_eq!(ace_to_five_low(&seven_cards), ace_to_five_low(&seven_low));
+
+        // A paired hand loses to any unpaired one
+        let mut paired = seven_low;
+        paired[0] = Card::new(Suit::Diamonds, Rank::Two);
+        assert_eq!(ace_to_five_low(&paired).rank, HandRank::OnePair);
+        assert!(ace_to_five_low(&nine_low) > ace_to_five_low(&paired));
+
+        // Deuce-to-seven: the ace is high and straights and flushes count
+        assert_eq!(deuce_to_seven_low(&wheel).rank, HandRank::Flush);
+        assert!(deuce_to_seven_low(&seven_low) > deuce_to_seven_low(&nine_low));
+        let mut straight = seven_low;
+        straight[0] = Card::new(Suit::Clubs, Rank::Six);
+        assert_eq!(deuce_to_seven_low(&straight).rank, HandRank::Straight);
+        assert!(deuce_to_seven_low(&nine_low) > deuce_to_seven_low(&straight));
+    }
+
+    #[test]
+    fn test_omaha_hi_lo_low_uses_two_hole_cards() {
+        let board = [
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Clubs, Rank::King),
+        ];
+        let hi_lo = PokerVariant::OmahaHiLo.rules();
+        assert_eq!(hi_lo.hole_cards(), 4);
+        assert!(hi_lo.plays_high());
+
+        // A-3 in the hand makes 7-5-3-2-A
+        let low_hand = [
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Diamonds, Rank::Queen),
+        ];
+        let low = hi_lo.evaluate_low(&low_hand, &board).unwrap();
+        assert_eq!(low.ranks, vec![7, 5, 3, 2, 1]);
+
+        // A single low card in the hand can't make a low with three board cards
+        let one_low_card = [
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Diamonds, Rank::Ten),
+        ];
+        assert_eq!(hi_lo.evaluate_low(&one_low_card, &board), None);
+        assert_eq!(PokerVariant::Omaha.rules().evaluate_low(&low_hand, &board), None);
+
+        // Best hands: ties share, seats outside the pot don't count
+        let seven_low = ace_to_five_low(&[board[0], board[1], board[2], low_hand[0], low_hand[1]]);
+        let lows = vec![
+            (Seat::Player1, seven_low.clone()),
+            (Seat::Player2, seven_low),
+            (Seat::Player3, ace_to_five_low(&board)),
+        ];
+        assert_eq!(
+            best_hands(&lows, &[Seat::Player1, Seat::Player2, Seat::Player3]),
+            vec![Seat::Player1, Seat::Player2]
+        );
+        assert_eq!(best_hands(&lows, &[Seat::Player3]), vec![Seat::Player3]);
+        assert!(best_hands(&lows, &[Seat::Player4]).is_empty());
+    }
+
+    #[test]
+    fn test_tournament_blind_levels_and_prizes() {
+        let level = |big_blind: u64| BlindLevel {
+            small_blind: big_blind / 2,
+            big_blind,
+            ante: 0,
+        };
+        let mut tournament = TournamentConfig {
+            buy_in: 10,
+            starting_chips: 1500,
+            levels: vec![level(20), level(40), level(80)],
+            level_duration: LevelDuration::Hands(10),
+            payouts: vec![50, 30, 20],
+            token_app: None,
+            coordinator: None,
+        };
+        assert_eq!(tournament.level_index(0, 500), 0);
+        assert_eq!(tournament.level_index(19, 0), 1);
+        // The last level stays in play
+        assert_eq!(tournament.level_index(100, 0), 2);
+        tournament.level_duration = LevelDuration::Blocks(60);
+        assert_eq!(tournament.level_index(100, 59), 0);
+        assert_eq!(tournament.level_index(0, 60), 1);
+
+        // Lower places are paid in whole tokens; the winner takes the rest
+        let pool = Amount::from_tokens(90);
+        assert_eq!(
+            prize_payouts(pool, &tournament.payouts, 9),
+            vec![
+                Amount::from_tokens(45),
+                Amount::from_tokens(27),
+                Amount::from_tokens(18)
+            ]
+        );
+        let pool = Amount::from_tokens(25);
+        assert_eq!(
+            prize_payouts(pool, &tournament.payouts, 9),
+            vec![
+                Amount::from_tokens(13),
+                Amount::from_tokens(7),
+                Amount::from_tokens(5)
+            ]
+        );
+        // Heads-up only two places are paid, and the winner gets third's share too
+        let pool = Amount::from_tokens(20);
+        assert_eq!(
+            prize_payouts(pool, &tournament.payouts, 2),
+            vec![Amount::from_tokens(14), Amount::from_tokens(6)]
+        );
+    }
+
+    #[test]
+    fn test_rake_percentage_and_cap() {
+        let pot = |tokens: u128, eligible: &[Seat]| Pot {
+            amount: Amount::from_tokens(tokens),
+            eligible: eligible.to_vec(),
+            winners: Vec::new(),
+        };
+        let all = [Seat::Player1, Seat::Player2, Seat::Player3];
+        let mut pots = vec![pot(300, &all), pot(100, &all[1..])];
+        assert_eq!(take_rake(&mut pots, 5, None), Amount::from_tokens(20));
+        assert_eq!(pots[0].amount, Amount::from_tokens(285));
+        assert_eq!(pots[1].amount, Amount::from_tokens(95));
+
+        // The cap is reached in the main pot; side pots go unraked
+        let mut pots = vec![pot(300, &all), pot(100, &all[1..])];
+        assert_eq!(
+            take_rake(&mut pots, 5, Some(Amount::from_tokens(3))),
+            Amount::from_tokens(3)
+        );
+        assert_eq!(pots[0].amount, Amount::from_tokens(297));
+        assert_eq!(pots[1].amount, Amount::from_tokens(100));
+    }
+
+    #[test]
+    fn test_deck_seed_from_revealed_entropy() {
+        let table: ChainId = "0".repeat(64).parse().unwrap();
+        let alice = [1u8; 32];
+        let bob = [2u8; 32];
+        assert_ne!(entropy_commitment(&alice), entropy_commitment(&bob));
+        assert_ne!(entropy_commitment(&alice), alice);
+
+        // Seat order, not reveal order, fixes the seed
+        let seed = deck_seed(table, 1, &[(Seat::Player1, alice), (Seat::Player2, bob)]);
+        assert_eq!(
+            seed,
+            deck_seed(table, 1, &[(Seat::Player2, bob), (Seat::Player1, alice)])
+        );
+
+        // Any one player's entropy changes the deck, as does the hand
+        let other = deck_seed(table, 1, &[(Seat::Player1, alice), (Seat::Player2, [3u8; 32])]);
+        assert_ne!(seed, other);
+        assert_ne!(shuffle_deck(&seed), shuffle_deck(&other));
+        assert_ne!(seed, deck_seed(table, 2, &[(Seat::Player1, alice), (Seat::Player2, bob)]));
+    }
+
+    #[test]
+    fn test_shuffle_is_deterministic_and_uniform() {
+        assert_eq!(shuffle_deck(b"seed"), shuffle_deck(b"seed"));
+        assert_ne!(shuffle_deck(b"seed"), shuffle_deck(b"seeds"));
+        let mut sorted: Vec<u8> = shuffle_deck(b"seed").iter().map(|c| c.to_index()).collect();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..52).collect::<Vec<u8>>());
+
+        // Every card lands in every position equally often: chi-squared over
+        // the 52x52 card/position table (2601 degrees of freedom, sd ~72)
+        let shuffles = 52 * 200;
+        let mut counts = vec![[0u32; 52]; 52];
+        for n in 0..shuffles as u32 {
+            for (position, card) in shuffle_deck(&n.to_le_bytes()).iter().enumerate() {
+                counts[card.to_index() as usize][position] += 1;
+            }
+        }
+        let expected = shuffles as f64 / 52.0;
+        let chi_squared: f64 = counts
+            .iter()
+            .flatten()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi_squared < 2601.0 + 5.0 * 72.0, "chi-squared {chi_squared}");
+
+        // Every ordering of a small deck is equally likely (23 degrees of freedom)
+        let cards: Vec<Card> = (0..4).filter_map(Card::from_index).collect();
+        let mut orderings = std::collections::HashMap::new();
+        for n in 0..24_000u32 {
+            *orderings
+                .entry(shuffle_cards(cards.clone(), &n.to_le_bytes()))
+                .or_insert(0u32) += 1;
+        }
+        assert_eq!(orderings.len(), 24);
+        let chi_squared: f64 = orderings
+            .values()
+            .map(|&count| (count as f64 - 1000.0).powi(2) / 1000.0)
+            .sum();
+        assert!(chi_squared < 60.0, "chi-squared {chi_squared}");
+    }
+
+    #[test]
+    fn test_dealerless_deal_opens_only_with_every_share() {
+        use mental::*;
+        let table: ChainId = "0".repeat(64).parse().unwrap();
+        let chains: Vec<ChainId> = ["1", "2"]
+            .iter()
+            .map(|c| c.repeat(64).parse().unwrap())
+            .collect();
+        let secrets = [[1u8; 32], [2u8; 32]];
+        let seats = [Seat::Player1, Seat::Player2];
+        let cards: Vec<Card> = (0..5).filter_map(Card::from_index).collect();
+        let context = mental_context(table, 1);
+        let mut deal = MentalDeal::new(context.clone(), cards.clone(), seats.to_vec(), 1);
+
+        // Keys: a proof made for another chain doesn't count
+        let respond = |request: &MentalRequest, player: usize| {
+            mental_response(request, &secrets[player], table, chains[player]).unwrap()
+        };
+        let key_request = MentalRequest::Key { game_id: 1 };
+        let MentalResponse::Key(first) = respond(&key_request, 0) else { panic!() };
+        assert!(!deal.add_key(Seat::Player1, chains[1], &first));
+        assert!(deal.add_key(Seat::Player1, chains[0], &first));
+        assert_eq!(deal.dealing_owed(), vec![Seat::Player2]);
+        let MentalResponse::Key(second) = respond(&key_request, 1) else { panic!() };
+        assert!(deal.add_key(Seat::Player2, chains[1], &second));
+
+        // Shuffles in seat order; a deck that isn't the proven one is refused
+        for (player, seat) in seats.iter().enumerate() {
+            assert_eq!(deal.next_shuffler(), Some(*seat));
+            let request = MentalRequest::Shuffle {
+                game_id: 1,
+                joint_key: deal.joint_key().unwrap(),
+                deck: deal.deck.clone(),
+            };
+            let MentalResponse::Shuffle { deck, proof } = respond(&request, player) else {
+                panic!()
+            };
+            let mut swapped = deck.clone();
+            swapped.swap(0, 1);
+            assert!(!deal.add_shuffle(*seat, swapped, &proof));
+            assert!(deal.add_shuffle(*seat, deck, &proof));
+        }
+        assert!(deal.is_shuffled());
+        assert_ne!(deal.deck, open_deck(&cards));
+
+        // Player 2 shares Player 1's hole card: only Player 1 can open it
+        let hole = deal.hole_positions(Seat::Player1);
+        assert_eq!(hole, vec![0]);
+        let request = MentalRequest::Shares { game_id: 1, cards: deal.cards_at(&hole) };
+        let MentalResponse::Shares(shares) = respond(&request, 1) else { panic!() };
+        assert!(!deal.add_shares(Seat::Player1, &shares));
+        assert!(deal.add_shares(Seat::Player2, &shares));
+        let masked = deal.partial_open(0, Seat::Player1).unwrap();
+        let hole_card = open_card(&secrets[0], &context, &masked).unwrap();
+        assert_ne!(open_card(&secrets[1], &context, &masked), Some(hole_card));
+        assert_eq!(deal.open(0), None);
+
+        // Board cards open once both players share them
+        let board = deal.board_positions(0, 3);
+        assert_eq!(board, vec![2, 3, 4]);
+        for player in 0..2 {
+            let request = MentalRequest::Shares { game_id: 1, cards: deal.cards_at(&board) };
+            let MentalResponse::Shares(shares) = respond(&request, player) else { panic!() };
+            assert!(deal.add_shares(seats[player], &shares));
+        }
+        let mut opened: Vec<Card> = board.iter().map(|p| deal.open(*p).unwrap()).collect();
+        opened.push(hole_card);
+        opened.sort_by_key(|c| c.to_index());
+        opened.dedup();
+        assert_eq!(opened.len(), 4);
+        assert!(opened.iter().all(|c| cards.contains(c)));
+    }
+
+    #[test]
+    fn test_bad_beat_and_high_hand_jackpots() {
+        let board = [
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Ten),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Diamonds, Rank::Nine),
+        ];
+        let straight_flush = [
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::King),
+        ];
+        let quads = [
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+        let full_house = [
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Ace),
+        ];
+        let config = JackpotConfig {
+            percent: 20,
+            bad_beat: Some(HandRank::FourOfAKind),
+            loser_share: 40,
+            winner_share: 40,
+            table_share: 20,
+            high_hand: Some(HandRank::StraightFlush),
+            high_hand_share: 10,
+        };
+
+        // Quads lose to a straight flush: a bad beat, not just a high hand
+        let highs = vec![
+            (Seat::Player1, evaluate_hand(&straight_flush, &board)),
+            (Seat::Player2, evaluate_hand(&quads, &board)),
+            (Seat::Player3, evaluate_hand(&full_house, &board)),
+        ];
+        let hit = jackpot_hit(&config, &highs);
+        assert_eq!(
+            hit,
+            Some(JackpotHit::BadBeat {
+                loser: Seat::Player2,
+                winner: Seat::Player1
+            })
+        );
+        let seats = [Seat::Player1, Seat::Player2, Seat::Player3, Seat::Player4];
+        assert_eq!(
+            jackpot_payouts(&config, hit.unwrap(), Amount::from_tokens(100), &seats),
+            vec![
+                (Seat::Player2, Amount::from_tokens(40)),
+                (Seat::Player1, Amount::from_tokens(40)),
+                (Seat::Player3, Amount::from_tokens(10)),
+                (Seat::Player4, Amount::from_tokens(10)),
+            ]
+        );
+
+        // A full house losing doesn't qualify; the straight flush is a high hand
+        let hit = jackpot_hit(&config, &[highs[0].clone(), highs[2].clone()]);
+        assert_eq!(hit, Some(JackpotHit::HighHand { winner: Seat::Player1 }));
+        assert_eq!(
+            jackpot_payouts(&config, hit.unwrap(), Amount::from_tokens(100), &seats),
+            vec![(Seat::Player1, Amount::from_tokens(10))]
+        );
+        assert_eq!(jackpot_hit(&config, &highs[1..]), None);
+    }
+
+    #[test]
+    fn test_icm_and_chip_chop_deals() {
+        let tokens = |amounts: &[u128]| -> Vec<Amount> {
+            amounts.iter().map(|a| Amount::from_tokens(*a)).collect()
+        };
+
+        // Heads-up the leader is a 3:1 favourite for first
+        let stacks = tokens(&[300, 100]);
+        let prizes = tokens(&[70, 30]);
+        assert_eq!(icm_equity(&stacks, &prizes), tokens(&[60, 40]));
+        assert_eq!(chip_chop(&stacks, &prizes), tokens(&[75, 25]));
+
+        // ICM takes equity from the chip leader compared to a chip chop
+        let stacks = tokens(&[50, 30, 20]);
+        let prizes = tokens(&[50, 30, 20]);
+        let equity = icm_equity(&stacks, &prizes);
+        assert!(equity[0] > Amount::from_tokens(38) && equity[0] < Amount::from_tokens(39));
+        assert_eq!(equity[1], Amount::from_attos(32_750_000_000_000_000_000));
+        assert!(equity[2] > Amount::from_tokens(28) && equity[2] < Amount::from_tokens(29));
+        assert_eq!(chip_chop(&stacks, &prizes), tokens(&[50, 30, 20]));
+
+        // Deals pay whole tokens; the chip leader takes the rounding
+        assert_eq!(
+            deal_payouts(DealKind::Icm, &stacks, &prizes),
+            tokens(&[40, 32, 28])
+        );
+        let stacks = tokens(&[20, 50, 30]);
+        assert_eq!(
+            deal_payouts(DealKind::Icm, &stacks, &prizes),
+            tokens(&[28, 40, 32])
+        );
+    }
+
+    #[test]
+    fn test_razz_bring_in_and_first_to_act() {
+        let razz = PokerVariant::Razz.rules();
+        assert!(!razz.plays_high());
+        assert!(!razz.uses_blinds());
+        assert_eq!(razz.first_street(), GamePhase::ThirdStreet);
+
+        // Highest door card brings in; aces are low, spades break ties
+        let door_cards = [
+            (Seat::Player1, Card::new(Suit::Hearts, Rank::King)),
+            (Seat::Player2, Card::new(Suit::Spades, Rank::King)),
+            (Seat::Player3, Card::new(Suit::Clubs, Rank::Ace)),
+        ];
+        assert_eq!(razz.bring_in(&door_cards), Some(Seat::Player2));
+        assert_eq!(
+            PokerVariant::SevenCardStud.rules().bring_in(&door_cards),
+            Some(Seat::Player1)
+        );
+
+        // The lowest hand showing opens later streets
+        let active = [Seat::Player1, Seat::Player2, Seat::Player3];
+        let up = |suit: Suit, rank: Rank| Card::new(suit, rank);
+        let showing = vec![
+            (Seat::Player1, vec![up(Suit::Hearts, Rank::King), up(Suit::Hearts, Rank::Two)]),
+            (Seat::Player2, vec![up(Suit::Spades, Rank::Four), up(Suit::Clubs, Rank::Four)]),
+            (Seat::Player3, vec![up(Suit::Clubs, Rank::Ace), up(Suit::Clubs, Rank::Eight)]),
+        ];
+        assert_eq!(razz.first_to_act(Seat::Player1, &active, &showing), Some(Seat::Player3));
+
+        // Every hand has a low, with no qualifier
+        let hand = [
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Diamonds, Rank::Queen),
+            Card::new(Suit::Clubs, Rank::Jack),
+            Card::new(Suit::Diamonds, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Nine),
+        ];
+        // Four ranks force a pair; the lowest pair plays
+        let low = razz.evaluate_low(&hand, &[]).unwrap();
+        assert_eq!(low.rank, HandRank::OnePair);
+        assert_eq!(low.ranks, vec![11, 13, 12, 9]);
+    }
+
     // FIX #10: MEDIUM - Comprehensive hand evaluation tests
 
     #[test]
@@ -0,0 +1,1055 @@
+//! Dealerless Mental Poker (Barnett–Smart)
+//!
+//! Lets the players deal a hand between themselves so that no chain - the
+//! table's included - ever sees a card before it is opened.
+//!
+//! # Protocol
+//!
+//! 1. **Keys**: every player dealt in publishes a key share `pk = x·G` on
+//!    BLS12-381 G1 with a Schnorr proof that they know `x`. The joint key is
+//!    the sum of the shares, so opening a card needs every player.
+//! 2. **Shuffle**: the deck starts open (card `i` is the point `(i+1)·G`).
+//!    Each player in seat order permutes it and re-encrypts every card under
+//!    the joint key with fresh ElGamal randomness, proving the new deck is a
+//!    shuffle of the old one without saying how (cut-and-choose, see
+//!    [`SHUFFLE_PROOF_ROUNDS`]).
+//! 3. **Opening**: a card is opened by partial decryptions `x·c1`, each with
+//!    a Chaum–Pedersen proof that it used the player's key. Hole cards get
+//!    every share but their owner's, so only the owner can finish opening
+//!    them; board cards get every share, street by street.
+//!
+//! Secrets never go on chain: the player's client computes key shares,
+//! shuffles and decryption shares locally (see [`mental_response`]) and
+//! submits only the public results.
+
+use crate::{uniform_below, Card, Seat};
+use ark_bls12_381::{Fr, G1Projective};
+use ark_ec::Group;
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use linera_sdk::linera_base_types::ChainId;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Size of a compressed G1 point
+pub const POINT_SIZE: usize = 48;
+
+/// Size of a serialized scalar
+pub const SCALAR_SIZE: usize = 32;
+
+/// Rounds of the cut-and-choose shuffle proof
+///
+/// A shuffle that isn't a permutation of the previous deck passes each round
+/// with probability 1/2. The challenge is a hash the shuffler can recompute
+/// offline, so a cheat costs about 2^rounds hashes of grinding: 128 rounds
+/// put that out of reach, where 40 would be an afternoon's work.
+///
+/// The price is paid on chain. For a full deck the proof is about 530 KB,
+/// within a 1 MB block. Checking it takes `2 · rounds · cards` G1
+/// multiplications (13,312, about 7 s natively), far beyond the testnet's
+/// 100M fuel per block. Dealerless tables only run on networks whose
+/// resource policy doesn't cap fuel that low, such as a local `linera net up`.
+pub const SHUFFLE_PROOF_ROUNDS: usize = 128;
+
+// ============================================================================
+// PROTOCOL TYPES
+// ============================================================================
+
+/// A card encrypted under the joint key: the ElGamal ciphertext
+/// `(c1, c2) = (r·G, M + r·K)` as compressed G1 points
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaskedCard {
+    pub c1: Vec<u8>,
+    pub c2: Vec<u8>,
+}
+
+/// A player's public key share with a Schnorr proof of knowledge of the
+/// secret behind it (`R || s`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyShare {
+    pub public_key: Vec<u8>,
+    pub proof: Vec<u8>,
+}
+
+/// A player's partial decryption `x·c1` of the card at `position`, with a
+/// Chaum–Pedersen proof that it used the same `x` as their key share
+/// (`A || B || s`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionShare {
+    pub position: u8,
+    pub share: Vec<u8>,
+    pub proof: Vec<u8>,
+}
+
+/// Proof that a deck is a permutation and re-encryption of the previous one
+///
+/// For every round the shuffler made a shadow shuffle of the input deck.
+/// The Fiat–Shamir challenge then asks, round by round, either how the
+/// shadow was made from the input, or how the output is made from the
+/// shadow; answering both for any round would give the shuffle away.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleProof {
+    pub rounds: Vec<ShuffleRound>,
+}
+
+/// One round of a [`ShuffleProof`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleRound {
+    /// The shadow deck, sent only when the round is opened toward the output
+    /// (empty when it is opened from the input)
+    pub shadow: Vec<MaskedCard>,
+    /// Position in the source deck of each card of the target deck
+    pub permutation: Vec<u8>,
+    /// Re-encryption scalar added to each card of the target deck
+    pub masks: Vec<Vec<u8>>,
+}
+
+/// What the table asks a player for during a dealerless hand
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MentalRequest {
+    /// Publish a key share for the hand
+    Key { game_id: u64 },
+    /// Shuffle and re-encrypt the deck under the joint key
+    Shuffle {
+        game_id: u64,
+        joint_key: Vec<u8>,
+        deck: Vec<MaskedCard>,
+    },
+    /// Partially decrypt the cards at these deck positions
+    Shares {
+        game_id: u64,
+        cards: Vec<(u8, MaskedCard)>,
+    },
+}
+
+impl MentalRequest {
+    pub fn game_id(&self) -> u64 {
+        match self {
+            MentalRequest::Key { game_id }
+            | MentalRequest::Shuffle { game_id, .. }
+            | MentalRequest::Shares { game_id, .. } => *game_id,
+        }
+    }
+}
+
+/// A player's answer to a [`MentalRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MentalResponse {
+    Key(KeyShare),
+    Shuffle {
+        deck: Vec<MaskedCard>,
+        proof: ShuffleProof,
+    },
+    Shares(Vec<DecryptionShare>),
+}
+
+// ============================================================================
+// CLIENT SIDE (run by the player's own node; uses the player's secret)
+// ============================================================================
+
+/// Bytes every proof of a hand is bound to
+pub fn mental_context(table_chain: ChainId, game_id: u64) -> Vec<u8> {
+    let mut context = b"LINERA_POKER_MENTAL".to_vec();
+    context.extend_from_slice(table_chain.to_string().as_bytes());
+    context.extend_from_slice(&game_id.to_le_bytes());
+    context
+}
+
+/// Answer a request from the table with the player's secret (32 random
+/// bytes the client keeps; the hand's key is derived from it)
+pub fn mental_response(
+    request: &MentalRequest,
+    secret: &[u8],
+    table_chain: ChainId,
+    player_chain: ChainId,
+) -> Option<MentalResponse> {
+    let context = mental_context(table_chain, request.game_id());
+    match request {
+        MentalRequest::Key { .. } => Some(MentalResponse::Key(key_share(
+            secret,
+            &context,
+            player_chain,
+        ))),
+        MentalRequest::Shuffle {
+            joint_key, deck, ..
+        } => {
+            let (deck, proof) = shuffle_masked(deck, joint_key, secret, &context)?;
+            Some(MentalResponse::Shuffle { deck, proof })
+        }
+        MentalRequest::Shares { cards, .. } => cards
+            .iter()
+            .map(|(position, card)| decryption_share(secret, &context, *position, card))
+            .collect::<Option<Vec<_>>>()
+            .map(MentalResponse::Shares),
+    }
+}
+
+/// Key share for the hand, bound to the player's chain
+pub fn key_share(secret: &[u8], context: &[u8], player_chain: ChainId) -> KeyShare {
+    let x = secret_key(secret, context);
+    let public_key = G1Projective::generator() * x;
+    let w = hash_to_scalar(b"LINERA_POKER_MENTAL_KEY_NONCE", &[secret, context]);
+    let r = G1Projective::generator() * w;
+    let e = key_challenge(context, player_chain, &public_key, &r);
+    let mut proof = to_bytes(&r);
+    proof.extend(to_bytes(&(w + e * x)));
+    KeyShare {
+        public_key: to_bytes(&public_key),
+        proof,
+    }
+}
+
+/// Shuffle and re-encrypt `deck` under `joint_key`, with the proof
+///
+/// Deterministic in the secret, the hand and the deck shuffled, so the
+/// client can recompute it; a deal restarted with a different joint key
+/// gets a fresh shuffle.
+pub fn shuffle_masked(
+    deck: &[MaskedCard],
+    joint_key: &[u8],
+    secret: &[u8],
+    context: &[u8],
+) -> Option<(Vec<MaskedCard>, ShuffleProof)> {
+    let input = parse_deck(deck)?;
+    let key = point(joint_key)?;
+    let seed: [u8; 32] = hash(
+        b"LINERA_POKER_MENTAL_SHUFFLE",
+        &[secret, context, joint_key, &deck_hash(deck)],
+    );
+    let mut rng = ChaCha20Rng::from_seed(seed);
+
+    let permutation = random_permutation(&mut rng, input.len());
+    let masks: Vec<Fr> = (0..input.len()).map(|_| Fr::rand(&mut rng)).collect();
+    let output = encode_deck(&remask(&input, &permutation, &masks, &key));
+
+    let mut shadows = Vec::with_capacity(SHUFFLE_PROOF_ROUNDS);
+    for _ in 0..SHUFFLE_PROOF_ROUNDS {
+        let shadow_permutation = random_permutation(&mut rng, input.len());
+        let shadow_masks: Vec<Fr> = (0..input.len()).map(|_| Fr::rand(&mut rng)).collect();
+        let shadow = encode_deck(&remask(&input, &shadow_permutation, &shadow_masks, &key));
+        shadows.push((shadow, shadow_permutation, shadow_masks));
+    }
+
+    let hashes: Vec<[u8; 32]> = shadows
+        .iter()
+        .map(|(shadow, _, _)| deck_hash(shadow))
+        .collect();
+    let challenge = shuffle_challenge(context, joint_key, deck, &output, &hashes);
+    let rounds = shadows
+        .into_iter()
+        .enumerate()
+        .map(|(round, (shadow, shadow_permutation, shadow_masks))| {
+            if !challenge_bit(&challenge, round) {
+                return ShuffleRound {
+                    shadow: Vec::new(),
+                    permutation: shadow_permutation,
+                    masks: shadow_masks.iter().map(to_bytes).collect(),
+                };
+            }
+            // Output card i is shadow card σ(i), re-encrypted by r_i - ρ_σ(i)
+            let mut inverse = vec![0u8; shadow_permutation.len()];
+            for (position, source) in shadow_permutation.iter().enumerate() {
+                inverse[*source as usize] = position as u8;
+            }
+            let sigma: Vec<u8> = permutation.iter().map(|p| inverse[*p as usize]).collect();
+            let masks = sigma
+                .iter()
+                .zip(&masks)
+                .map(|(s, r)| to_bytes(&(*r - shadow_masks[*s as usize])))
+                .collect();
+            ShuffleRound {
+                shadow,
+                permutation: sigma,
+                masks,
+            }
+        })
+        .collect();
+
+    Some((output, ShuffleProof { rounds }))
+}
+
+/// Partial decryption of `card` with the player's key for the hand
+pub fn decryption_share(
+    secret: &[u8],
+    context: &[u8],
+    position: u8,
+    card: &MaskedCard,
+) -> Option<DecryptionShare> {
+    let x = secret_key(secret, context);
+    let c1 = point(&card.c1)?;
+    let public_key = G1Projective::generator() * x;
+    let share = c1 * x;
+    let w = hash_to_scalar(
+        b"LINERA_POKER_MENTAL_SHARE_NONCE",
+        &[secret, context, &[position], &card.c1],
+    );
+    let a = G1Projective::generator() * w;
+    let b = c1 * w;
+    let e = share_challenge(context, position, &public_key, &c1, &share, &a, &b);
+    let mut proof = to_bytes(&a);
+    proof.extend(to_bytes(&b));
+    proof.extend(to_bytes(&(w + e * x)));
+    Some(DecryptionShare {
+        position,
+        share: to_bytes(&share),
+        proof,
+    })
+}
+
+/// Finish opening a card every other player has partially decrypted
+pub fn open_card(secret: &[u8], context: &[u8], card: &MaskedCard) -> Option<Card> {
+    let x = secret_key(secret, context);
+    let message = point(&card.c2)? - point(&card.c1)? * x;
+    decode(&message)
+}
+
+// ============================================================================
+// VERIFICATION (run by the table)
+// ============================================================================
+
+/// Deck of open cards: the starting point of the first shuffle
+pub fn open_deck(cards: &[Card]) -> Vec<MaskedCard> {
+    cards
+        .iter()
+        .map(|card| MaskedCard {
+            c1: to_bytes(&G1Projective::zero()),
+            c2: to_bytes(&card_point(*card)),
+        })
+        .collect()
+}
+
+/// Check a key share's proof of knowledge
+pub fn verify_key_share(share: &KeyShare, context: &[u8], player_chain: ChainId) -> bool {
+    let (public_key, r, s) = match (
+        point(&share.public_key),
+        share.proof.get(..POINT_SIZE).and_then(point),
+        share.proof.get(POINT_SIZE..).and_then(scalar),
+    ) {
+        (Some(public_key), Some(r), Some(s)) => (public_key, r, s),
+        _ => return false,
+    };
+    let e = key_challenge(context, player_chain, &public_key, &r);
+    !public_key.is_zero() && G1Projective::generator() * s == r + public_key * e
+}
+
+/// Joint key of the hand: the sum of every key share
+pub fn joint_key(keys: &[Vec<u8>]) -> Option<Vec<u8>> {
+    let mut sum = G1Projective::zero();
+    for key in keys {
+        sum += point(key)?;
+    }
+    Some(to_bytes(&sum))
+}
+
+/// Check that `output` is a shuffle and re-encryption of `input`
+pub fn verify_shuffle(
+    input: &[MaskedCard],
+    output: &[MaskedCard],
+    joint_key: &[u8],
+    proof: &ShuffleProof,
+    context: &[u8],
+) -> bool {
+    if output.len() != input.len() || proof.rounds.len() != SHUFFLE_PROOF_ROUNDS {
+        return false;
+    }
+    let (input_points, output_points, key) =
+        match (parse_deck(input), parse_deck(output), point(joint_key)) {
+            (Some(input), Some(output), Some(key)) => (input, output, key),
+            _ => return false,
+        };
+
+    let mut hashes = Vec::with_capacity(SHUFFLE_PROOF_ROUNDS);
+    let mut toward_output = Vec::with_capacity(SHUFFLE_PROOF_ROUNDS);
+    for round in &proof.rounds {
+        let (permutation, masks) = match parse_round(round, input.len()) {
+            Some(parsed) => parsed,
+            None => return false,
+        };
+        if round.shadow.is_empty() {
+            // The shadow is the input shuffled as claimed
+            let shadow = encode_deck(&remask(&input_points, &permutation, &masks, &key));
+            hashes.push(deck_hash(&shadow));
+            toward_output.push(false);
+        } else {
+            // The output is the shadow shuffled as claimed
+            let shadow = match parse_deck(&round.shadow) {
+                Some(shadow) if shadow.len() == input.len() => shadow,
+                _ => return false,
+            };
+            if remask(&shadow, &permutation, &masks, &key) != output_points {
+                return false;
+            }
+            hashes.push(deck_hash(&round.shadow));
+            toward_output.push(true);
+        }
+    }
+
+    let challenge = shuffle_challenge(context, joint_key, input, output, &hashes);
+    toward_output
+        .iter()
+        .enumerate()
+        .all(|(round, opened)| challenge_bit(&challenge, round) == *opened)
+}
+
+/// Check a decryption share against the player's key share and the card
+pub fn verify_decryption_share(
+    public_key: &[u8],
+    card: &MaskedCard,
+    share: &DecryptionShare,
+    context: &[u8],
+) -> bool {
+    let proof = &share.proof;
+    let parsed = (
+        point(public_key),
+        point(&card.c1),
+        point(&share.share),
+        proof.get(..POINT_SIZE).and_then(point),
+        proof.get(POINT_SIZE..2 * POINT_SIZE).and_then(point),
+        proof.get(2 * POINT_SIZE..).and_then(scalar),
+    );
+    let (public_key, c1, d, a, b, s) = match parsed {
+        (Some(pk), Some(c1), Some(d), Some(a), Some(b), Some(s)) => (pk, c1, d, a, b, s),
+        _ => return false,
+    };
+    let e = share_challenge(context, share.position, &public_key, &c1, &d, &a, &b);
+    G1Projective::generator() * s == a + public_key * e && c1 * s == b + d * e
+}
+
+/// Remove partial decryptions from a card
+pub fn remove_shares(card: &MaskedCard, shares: &[&[u8]]) -> Option<MaskedCard> {
+    let mut c2 = point(&card.c2)?;
+    for share in shares {
+        c2 -= point(share)?;
+    }
+    Some(MaskedCard {
+        c1: card.c1.clone(),
+        c2: to_bytes(&c2),
+    })
+}
+
+/// Card of a fully decrypted card
+pub fn unmasked_card(card: &MaskedCard) -> Option<Card> {
+    decode(&point(&card.c2)?)
+}
+
+// ============================================================================
+// DEALERLESS DEAL (table-side bookkeeping)
+// ============================================================================
+
+/// A hand being dealt by the players themselves
+///
+/// Hole cards take the first positions of the shuffled deck, `hole_cards`
+/// per seat in seat order; the board follows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MentalDeal {
+    /// Bytes every proof of the hand is bound to (see [`mental_context`])
+    pub context: Vec<u8>,
+    /// Cards of the deck, for opening
+    pub cards: Vec<Card>,
+    /// Seats dealing the hand, in seat order
+    pub seats: Vec<Seat>,
+    /// Hole cards per seat
+    pub hole_cards: u8,
+    /// Verified key shares
+    pub keys: Vec<(Seat, Vec<u8>)>,
+    /// Deck as last shuffled (open until the first shuffle)
+    pub deck: Vec<MaskedCard>,
+    /// Seats that have shuffled, in order
+    pub shuffled: Vec<Seat>,
+    /// Verified partial decryptions: (deck position, seat, share)
+    pub shares: Vec<(u8, Seat, Vec<u8>)>,
+    /// Seats that have been sent their hole cards
+    pub delivered: Vec<Seat>,
+}
+
+impl MentalDeal {
+    pub fn new(context: Vec<u8>, cards: Vec<Card>, seats: Vec<Seat>, hole_cards: usize) -> Self {
+        MentalDeal {
+            context,
+            deck: open_deck(&cards),
+            cards,
+            seats,
+            hole_cards: hole_cards as u8,
+            keys: Vec::new(),
+            shuffled: Vec::new(),
+            shares: Vec::new(),
+            delivered: Vec::new(),
+        }
+    }
+
+    /// Record a seat's key share if its proof holds
+    pub fn add_key(&mut self, seat: Seat, player_chain: ChainId, share: &KeyShare) -> bool {
+        if !self.seats.contains(&seat)
+            || self.keys.iter().any(|(s, _)| *s == seat)
+            || !verify_key_share(share, &self.context, player_chain)
+        {
+            return false;
+        }
+        self.keys.push((seat, share.public_key.clone()));
+        true
+    }
+
+    /// Joint key, once every seat has a key share in
+    pub fn joint_key(&self) -> Option<Vec<u8>> {
+        if self.keys.len() != self.seats.len() {
+            return None;
+        }
+        let keys: Vec<Vec<u8>> = self.keys.iter().map(|(_, key)| key.clone()).collect();
+        joint_key(&keys)
+    }
+
+    /// Seat that shuffles next, once the joint key is known
+    pub fn next_shuffler(&self) -> Option<Seat> {
+        if self.keys.len() != self.seats.len() {
+            return None;
+        }
+        self.seats
+            .iter()
+            .copied()
+            .find(|seat| !self.shuffled.contains(seat))
+    }
+
+    /// Whether every seat has shuffled the deck
+    pub fn is_shuffled(&self) -> bool {
+        !self.seats.is_empty()
+            && self.keys.len() == self.seats.len()
+            && self.shuffled.len() == self.seats.len()
+    }
+
+    /// Take a seat's shuffle of the deck if it is their turn and the proof holds
+    pub fn add_shuffle(&mut self, seat: Seat, deck: Vec<MaskedCard>, proof: &ShuffleProof) -> bool {
+        let joint_key = match self.joint_key() {
+            Some(key) if self.next_shuffler() == Some(seat) => key,
+            _ => return false,
+        };
+        if !verify_shuffle(&self.deck, &deck, &joint_key, proof, &self.context) {
+            return false;
+        }
+        self.deck = deck;
+        self.shuffled.push(seat);
+        true
+    }
+
+    /// Deck positions of a seat's hole cards
+    pub fn hole_positions(&self, seat: Seat) -> Vec<u8> {
+        let hole = self.hole_cards as usize;
+        match self.seats.iter().position(|s| *s == seat) {
+            Some(index) => (index * hole..(index + 1) * hole)
+                .map(|p| p as u8)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Deck positions of the board cards `from..to`
+    pub fn board_positions(&self, from: usize, to: usize) -> Vec<u8> {
+        let start = self.seats.len() * self.hole_cards as usize;
+        (start + from..start + to)
+            .filter(|p| *p < self.deck.len())
+            .map(|p| p as u8)
+            .collect()
+    }
+
+    /// The cards at these positions, for a request
+    pub fn cards_at(&self, positions: &[u8]) -> Vec<(u8, MaskedCard)> {
+        positions
+            .iter()
+            .filter_map(|p| self.deck.get(*p as usize).map(|card| (*p, card.clone())))
+            .collect()
+    }
+
+    /// Record a seat's decryption shares if every one of them holds
+    pub fn add_shares(&mut self, seat: Seat, shares: &[DecryptionShare]) -> bool {
+        let public_key = match self.keys.iter().find(|(s, _)| *s == seat) {
+            Some((_, key)) if self.is_shuffled() => key.clone(),
+            _ => return false,
+        };
+        let valid = shares.iter().all(|share| {
+            let card = match self.deck.get(share.position as usize) {
+                Some(card) => card,
+                None => return false,
+            };
+            verify_decryption_share(&public_key, card, share, &self.context)
+        });
+        if !valid || shares.is_empty() {
+            return false;
+        }
+        for share in shares {
+            if !self.has_share(share.position, seat) {
+                self.shares
+                    .push((share.position, seat, share.share.clone()));
+            }
+        }
+        true
+    }
+
+    pub fn has_share(&self, position: u8, seat: Seat) -> bool {
+        self.shares
+            .iter()
+            .any(|(p, s, _)| *p == position && *s == seat)
+    }
+
+    /// Seats among `from` still owing a share of any of `positions`
+    pub fn missing(&self, positions: &[u8], from: &[Seat]) -> Vec<Seat> {
+        from.iter()
+            .copied()
+            .filter(|seat| positions.iter().any(|p| !self.has_share(*p, *seat)))
+            .collect()
+    }
+
+    /// The card at `position` with every share but `owner`'s removed: still
+    /// encrypted under the owner's key alone
+    pub fn partial_open(&self, position: u8, owner: Seat) -> Option<MaskedCard> {
+        let others: Vec<Seat> = self.seats.iter().copied().filter(|s| *s != owner).collect();
+        if !self.missing(&[position], &others).is_empty() {
+            return None;
+        }
+        let shares: Vec<&[u8]> = self
+            .shares
+            .iter()
+            .filter(|(p, s, _)| *p == position && *s != owner)
+            .map(|(_, _, share)| share.as_slice())
+            .collect();
+        remove_shares(self.deck.get(position as usize)?, &shares)
+    }
+
+    /// The card at `position`, once every seat has shared it
+    pub fn open(&self, position: u8) -> Option<Card> {
+        if !self.missing(&[position], &self.seats).is_empty() {
+            return None;
+        }
+        let shares: Vec<&[u8]> = self
+            .shares
+            .iter()
+            .filter(|(p, _, _)| *p == position)
+            .map(|(_, _, share)| share.as_slice())
+            .collect();
+        let card = unmasked_card(&remove_shares(self.deck.get(position as usize)?, &shares)?)?;
+        self.cards.contains(&card).then_some(card)
+    }
+
+    /// Seats holding up the deal: missing key shares, the next shuffler, or
+    /// shares of hole cards not yet sent to their owner
+    pub fn dealing_owed(&self) -> Vec<Seat> {
+        if self.keys.len() != self.seats.len() {
+            return self
+                .seats
+                .iter()
+                .copied()
+                .filter(|seat| self.keys.iter().all(|(s, _)| s != seat))
+                .collect();
+        }
+        if let Some(seat) = self.next_shuffler() {
+            return vec![seat];
+        }
+        self.seats
+            .iter()
+            .copied()
+            .filter(|seat| {
+                self.seats
+                    .iter()
+                    .filter(|owner| *owner != seat && !self.delivered.contains(owner))
+                    .any(|owner| {
+                        !self
+                            .missing(&self.hole_positions(*owner), &[*seat])
+                            .is_empty()
+                    })
+            })
+            .collect()
+    }
+
+    /// Seats still owing a share of the board cards `from..to`
+    pub fn board_owed(&self, from: usize, to: usize) -> Vec<Seat> {
+        self.missing(&self.board_positions(from, to), &self.seats)
+    }
+
+    /// Start the deal over without a seat: the others keep their key shares
+    /// and shuffle a fresh open deck
+    pub fn drop_seat(&mut self, seat: Seat) {
+        self.seats.retain(|s| *s != seat);
+        self.keys.retain(|(s, _)| *s != seat);
+        self.deck = open_deck(&self.cards);
+        self.shuffled.clear();
+        self.shares.clear();
+        self.delivered.clear();
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Plaintext point of a card: `(index + 1)·G`
+fn card_point(card: Card) -> G1Projective {
+    G1Projective::generator() * Fr::from(card.to_index() as u64 + 1)
+}
+
+/// Card whose point this is
+fn decode(message: &G1Projective) -> Option<Card> {
+    let mut candidate = G1Projective::zero();
+    for index in 0..52u8 {
+        candidate += G1Projective::generator();
+        if candidate == *message {
+            return Card::from_index(index);
+        }
+    }
+    None
+}
+
+fn secret_key(secret: &[u8], context: &[u8]) -> Fr {
+    hash_to_scalar(b"LINERA_POKER_MENTAL_KEY", &[secret, context])
+}
+
+pub(crate) fn hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(tag);
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+pub(crate) fn hash_to_scalar(tag: &[u8], parts: &[&[u8]]) -> Fr {
+    Fr::from_le_bytes_mod_order(&hash(tag, parts))
+}
+
+fn key_challenge(
+    context: &[u8],
+    player_chain: ChainId,
+    public_key: &G1Projective,
+    r: &G1Projective,
+) -> Fr {
+    hash_to_scalar(
+        b"LINERA_POKER_MENTAL_KEY_PROOF",
+        &[
+            context,
+            player_chain.to_string().as_bytes(),
+            &to_bytes(public_key),
+            &to_bytes(r),
+        ],
+    )
+}
+
+fn share_challenge(
+    context: &[u8],
+    position: u8,
+    public_key: &G1Projective,
+    c1: &G1Projective,
+    share: &G1Projective,
+    a: &G1Projective,
+    b: &G1Projective,
+) -> Fr {
+    hash_to_scalar(
+        b"LINERA_POKER_MENTAL_SHARE_PROOF",
+        &[
+            context,
+            &[position],
+            &to_bytes(public_key),
+            &to_bytes(c1),
+            &to_bytes(share),
+            &to_bytes(a),
+            &to_bytes(b),
+        ],
+    )
+}
+
+fn shuffle_challenge(
+    context: &[u8],
+    joint_key: &[u8],
+    input: &[MaskedCard],
+    output: &[MaskedCard],
+    shadow_hashes: &[[u8; 32]],
+) -> [u8; 32] {
+    let mut parts: Vec<&[u8]> = Vec::with_capacity(shadow_hashes.len() + 4);
+    let (input_hash, output_hash) = (deck_hash(input), deck_hash(output));
+    parts.extend([context, joint_key, &input_hash[..], &output_hash[..]]);
+    parts.extend(shadow_hashes.iter().map(|h| &h[..]));
+    hash(b"LINERA_POKER_MENTAL_SHUFFLE_PROOF", &parts)
+}
+
+fn challenge_bit(challenge: &[u8; 32], round: usize) -> bool {
+    (challenge[round / 8] >> (round % 8)) & 1 == 1
+}
+
+fn deck_hash(deck: &[MaskedCard]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update((deck.len() as u64).to_le_bytes());
+    for card in deck {
+        hasher.update(&card.c1);
+        hasher.update(&card.c2);
+    }
+    hasher.finalize().into()
+}
+
+fn random_permutation(rng: &mut ChaCha20Rng, size: usize) -> Vec<u8> {
+    let mut permutation: Vec<u8> = (0..size as u8).collect();
+    for i in (1..size).rev() {
+        let j = uniform_below(rng, i as u32 + 1) as usize;
+        permutation.swap(i, j);
+    }
+    permutation
+}
+
+/// Target card `i` is source card `permutation[i]` re-encrypted by `masks[i]`
+fn remask(
+    deck: &[(G1Projective, G1Projective)],
+    permutation: &[u8],
+    masks: &[Fr],
+    key: &G1Projective,
+) -> Vec<(G1Projective, G1Projective)> {
+    permutation
+        .iter()
+        .zip(masks)
+        .map(|(source, mask)| {
+            let (c1, c2) = deck[*source as usize];
+            (c1 + G1Projective::generator() * mask, c2 + *key * mask)
+        })
+        .collect()
+}
+
+/// A round's permutation and masks, if the permutation is one of `size`
+/// cards and every mask is a scalar
+fn parse_round(round: &ShuffleRound, size: usize) -> Option<(Vec<u8>, Vec<Fr>)> {
+    if round.permutation.len() != size || round.masks.len() != size {
+        return None;
+    }
+    let mut seen = vec![false; size];
+    for source in &round.permutation {
+        let slot = seen.get_mut(*source as usize)?;
+        if *slot {
+            return None;
+        }
+        *slot = true;
+    }
+    let masks = round
+        .masks
+        .iter()
+        .map(|mask| scalar(mask))
+        .collect::<Option<Vec<_>>>()?;
+    Some((round.permutation.clone(), masks))
+}
+
+fn parse_deck(deck: &[MaskedCard]) -> Option<Vec<(G1Projective, G1Projective)>> {
+    deck.iter()
+        .map(|card| Some((point(&card.c1)?, point(&card.c2)?)))
+        .collect()
+}
+
+fn encode_deck(deck: &[(G1Projective, G1Projective)]) -> Vec<MaskedCard> {
+    deck.iter()
+        .map(|(c1, c2)| MaskedCard {
+            c1: to_bytes(c1),
+            c2: to_bytes(c2),
+        })
+        .collect()
+}
+
+pub(crate) fn to_bytes<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value
+        .serialize_compressed(&mut bytes)
+        .expect("Serializing to a vector can't fail");
+    bytes
+}
+
+/// A G1 point, checked to be on the curve and in the prime-order subgroup
+pub(crate) fn point(bytes: &[u8]) -> Option<G1Projective> {
+    if bytes.len() != POINT_SIZE {
+        return None;
+    }
+    G1Projective::deserialize_compressed(bytes).ok()
+}
+
+fn scalar(bytes: &[u8]) -> Option<Fr> {
+    if bytes.len() != SCALAR_SIZE {
+        return None;
+    }
+    Fr::deserialize_compressed(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(digit: &str) -> ChainId {
+        digit.repeat(64).parse().unwrap()
+    }
+
+    /// Two players' key shares, the joint key and a five-card deck they
+    /// encrypted under it
+    fn setup(context: &[u8]) -> (Vec<KeyShare>, Vec<u8>, Vec<MaskedCard>) {
+        let keys = vec![
+            key_share(&[1; 32], context, chain("1")),
+            key_share(&[2; 32], context, chain("2")),
+        ];
+        let public_keys: Vec<Vec<u8>> = keys.iter().map(|k| k.public_key.clone()).collect();
+        let joint = joint_key(&public_keys).unwrap();
+        let cards: Vec<Card> = (0..5).filter_map(Card::from_index).collect();
+        let (deck, _) = shuffle_masked(&open_deck(&cards), &joint, &[1; 32], context).unwrap();
+        (keys, joint, deck)
+    }
+
+    #[test]
+    fn test_forged_key_share_rejected() {
+        let context = mental_context(chain("0"), 1);
+        let share = key_share(&[1; 32], &context, chain("1"));
+        assert!(verify_key_share(&share, &context, chain("1")));
+
+        // Another player's key with this proof: nobody knows its secret
+        let other = key_share(&[2; 32], &context, chain("2"));
+        let forged = KeyShare {
+            public_key: other.public_key,
+            proof: share.proof.clone(),
+        };
+        assert!(!verify_key_share(&forged, &context, chain("1")));
+
+        // A tweaked response scalar
+        let mut tweaked = share.clone();
+        tweaked.proof[POINT_SIZE] ^= 1;
+        assert!(!verify_key_share(&tweaked, &context, chain("1")));
+
+        // The identity point as a key, which anyone can "prove"
+        let zero = KeyShare {
+            public_key: to_bytes(&G1Projective::zero()),
+            proof: share.proof.clone(),
+        };
+        assert!(!verify_key_share(&zero, &context, chain("1")));
+
+        // Copied by another chain
+        assert!(!verify_key_share(&share, &context, chain("2")));
+    }
+
+    #[test]
+    fn test_tampered_shuffle_rejected() {
+        let context = mental_context(chain("0"), 1);
+        let (_, joint, deck) = setup(&context);
+        let (output, proof) = shuffle_masked(&deck, &joint, &[2; 32], &context).unwrap();
+        assert!(verify_shuffle(&deck, &output, &joint, &proof, &context));
+
+        // Two cards of the output swapped after proving
+        let mut swapped = output.clone();
+        swapped.swap(0, 1);
+        assert!(!verify_shuffle(&deck, &swapped, &joint, &proof, &context));
+
+        // A card replaced by a copy of another: not a permutation
+        let mut duplicated = output.clone();
+        duplicated[1] = duplicated[0].clone();
+        assert!(!verify_shuffle(
+            &deck,
+            &duplicated,
+            &joint,
+            &proof,
+            &context
+        ));
+
+        // A round answered with another permutation
+        let mut forged = proof.clone();
+        forged.rounds[0].permutation.swap(0, 1);
+        assert!(!verify_shuffle(&deck, &output, &joint, &forged, &context));
+
+        // Rounds dropped to make grinding the challenge cheap
+        let mut short = proof.clone();
+        short.rounds.truncate(40);
+        assert!(!verify_shuffle(&deck, &output, &joint, &short, &context));
+
+        // Checked against a deck it didn't shuffle
+        let (other, _) = shuffle_masked(&deck, &joint, &[1; 32], &context).unwrap();
+        assert!(!verify_shuffle(&other, &output, &joint, &proof, &context));
+    }
+
+    #[test]
+    fn test_bad_decryption_share_rejected() {
+        let context = mental_context(chain("0"), 1);
+        let (keys, _, deck) = setup(&context);
+        let share = decryption_share(&[1; 32], &context, 0, &deck[0]).unwrap();
+        assert!(verify_decryption_share(
+            &keys[0].public_key,
+            &deck[0],
+            &share,
+            &context
+        ));
+
+        // Made with another player's key
+        let other = decryption_share(&[2; 32], &context, 0, &deck[0]).unwrap();
+        assert!(!verify_decryption_share(
+            &keys[0].public_key,
+            &deck[0],
+            &other,
+            &context
+        ));
+
+        // A wrong partial decryption with the honest proof
+        let mut wrong = share.clone();
+        wrong.share = other.share.clone();
+        assert!(!verify_decryption_share(
+            &keys[0].public_key,
+            &deck[0],
+            &wrong,
+            &context
+        ));
+
+        // Moved to another card
+        let mut moved = share.clone();
+        moved.position = 1;
+        assert!(!verify_decryption_share(
+            &keys[0].public_key,
+            &deck[1],
+            &moved,
+            &context
+        ));
+        assert!(!verify_decryption_share(
+            &keys[0].public_key,
+            &deck[1],
+            &share,
+            &context
+        ));
+    }
+
+    #[test]
+    fn test_full_deck_shuffle_fits_in_a_block() {
+        // Testnet `maximum_block_size`
+        const MAX_BLOCK_SIZE: usize = 1_000_000;
+
+        // Worst case: every round is opened toward the output and carries its shadow
+        let card = MaskedCard {
+            c1: vec![0; POINT_SIZE],
+            c2: vec![0; POINT_SIZE],
+        };
+        let round = ShuffleRound {
+            shadow: vec![card.clone(); 52],
+            permutation: (0..52).collect(),
+            masks: vec![vec![0; SCALAR_SIZE]; 52],
+        };
+        let response = MentalResponse::Shuffle {
+            deck: vec![card; 52],
+            proof: ShuffleProof {
+                rounds: vec![round; SHUFFLE_PROOF_ROUNDS],
+            },
+        };
+        let size = linera_sdk::bcs::to_bytes(&response).unwrap().len();
+        assert!(size < MAX_BLOCK_SIZE, "shuffle response of {size} bytes");
+    }
+
+    #[test]
+    fn test_proof_replayed_for_another_game_rejected() {
+        let context = mental_context(chain("0"), 1);
+        let next_game = mental_context(chain("0"), 2);
+        let other_table = mental_context(chain("3"), 1);
+        let (keys, joint, deck) = setup(&context);
+        let (output, proof) = shuffle_masked(&deck, &joint, &[2; 32], &context).unwrap();
+        let share = decryption_share(&[1; 32], &context, 0, &deck[0]).unwrap();
+
+        for replayed in [&next_game, &other_table] {
+            assert!(!verify_key_share(&keys[0], replayed, chain("1")));
+            assert!(!verify_shuffle(&deck, &output, &joint, &proof, replayed));
+            assert!(!verify_decryption_share(
+                &keys[0].public_key,
+                &deck[0],
+                &share,
+                replayed
+            ));
+        }
+    }
+}
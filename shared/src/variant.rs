@@ -0,0 +1,362 @@
+//! Game variants
+//!
+//! Everything that differs between the games a table can deal - deck, deal
+//! sizes, street progression, who opens the betting and how hands are
+//! evaluated - lives behind the [`GameVariant`] trait. The table state machine
+//! only asks the variant selected for the current hand.
+
+use crate::{
+    ace_to_five_low, bring_in_seat, evaluate_hand, evaluate_omaha, evaluate_short_deck,
+    evaluate_up_cards, omaha_eight_or_better_low, razz_bring_in_seat, shuffle_deck,
+    shuffle_short_deck, Card, GamePhase, HandScore, LowScore, Seat, SHORT_DECK_SIZE,
+};
+
+/// Rules of one poker game, as used by the table state machine
+///
+/// Defaults describe a flop game with blinds (Texas Hold'em).
+pub trait GameVariant {
+    /// Face-down cards each player holds by showdown (the cards revealed)
+    fn hole_cards(&self) -> usize;
+
+    /// Shared board cards dealt for the hand (flop, turn and river)
+    fn board_cards(&self) -> usize {
+        5
+    }
+
+    /// Board cards showing once `street` opens
+    fn board_dealt(&self, street: GamePhase) -> usize {
+        let dealt = match street {
+            GamePhase::Flop => 3,
+            GamePhase::Turn => 4,
+            GamePhase::River | GamePhase::Showdown => 5,
+            _ => 0,
+        };
+        dealt.min(self.board_cards())
+    }
+
+    /// Most players the deck can be dealt to
+    fn max_players(&self) -> usize {
+        Seat::MAX_SEATS
+    }
+
+    /// Cards of the deck this variant is dealt from, unshuffled
+    fn deck(&self) -> Vec<Card> {
+        (0..52).filter_map(Card::from_index).collect()
+    }
+
+    /// Shuffled deck this variant is dealt from
+    fn shuffle(&self, seed: &[u8]) -> Vec<Card> {
+        shuffle_deck(seed)
+    }
+
+    /// Whether the hand opens with blinds; otherwise the lowest up card brings it in
+    fn uses_blinds(&self) -> bool {
+        true
+    }
+
+    /// First betting round of a hand
+    fn first_street(&self) -> GamePhase {
+        GamePhase::PreFlop
+    }
+
+    /// Street after `phase`: the next betting round (or the draw), or
+    /// `Showdown` after the last one. `None` outside betting.
+    fn next_street(&self, phase: GamePhase) -> Option<GamePhase> {
+        match phase {
+            GamePhase::PreFlop => Some(GamePhase::Flop),
+            GamePhase::Flop => Some(GamePhase::Turn),
+            GamePhase::Turn => Some(GamePhase::River),
+            GamePhase::River => Some(GamePhase::Showdown),
+            _ => None,
+        }
+    }
+
+    /// Cards each player is dealt (face down, face up) as `street` opens,
+    /// for games dealt street by street
+    fn street_cards(&self, _street: GamePhase) -> (usize, usize) {
+        (0, 0)
+    }
+
+    /// Most cards a player may discard and replace on the draw (0 = no draw)
+    fn max_discards(&self) -> usize {
+        0
+    }
+
+    /// Seat that opens the betting on every street after the first
+    ///
+    /// Flop games start with the first active seat left of the button
+    /// (heads-up this is the big blind).
+    fn first_to_act(
+        &self,
+        button: Seat,
+        active: &[Seat],
+        _up_cards: &[(Seat, Vec<Card>)],
+    ) -> Option<Seat> {
+        button.next_in(active).or_else(|| active.first().copied())
+    }
+
+    /// Stud: seat that must bring in, from each seat's door card
+    fn bring_in(&self, door_cards: &[(Seat, Card)]) -> Option<Seat> {
+        bring_in_seat(door_cards)
+    }
+
+    /// Best hand a player can make under this variant's rules
+    ///
+    /// In stud `hole_cards` holds the down and up cards and `community` is empty.
+    fn evaluate(&self, hole_cards: &[Card], community: &[Card]) -> HandScore {
+        evaluate_hand(hole_cards, community)
+    }
+
+    /// Whether the best high hand wins the pot (or half of it, in hi/lo)
+    fn plays_high(&self) -> bool {
+        true
+    }
+
+    /// Best low a player can make, if this variant awards the pot (or half of
+    /// it) to a low and the hand qualifies
+    fn evaluate_low(&self, _hole_cards: &[Card], _community: &[Card]) -> Option<LowScore> {
+        None
+    }
+}
+
+/// Stud: the seat showing the best score from `score` acts first; ties go to
+/// the first such seat clockwise from the button
+fn best_showing<S: Ord>(
+    button: Seat,
+    active: &[Seat],
+    up_cards: &[(Seat, Vec<Card>)],
+    score: impl Fn(&[Card]) -> S,
+) -> Option<Seat> {
+    let mut best: Option<(Seat, S)> = None;
+    let mut seat = button;
+    for _ in 0..active.len() {
+        seat = match seat.next_in(active) {
+            Some(next) => next,
+            None => break,
+        };
+        let showing = up_cards
+            .iter()
+            .find(|(s, _)| *s == seat)
+            .map(|(_, cards)| cards.as_slice())
+            .unwrap_or_default();
+        let score = score(showing);
+        if best.as_ref().is_none_or(|(_, top)| score > *top) {
+            best = Some((seat, score));
+        }
+    }
+    best.map(|(seat, _)| seat).or_else(|| active.first().copied())
+}
+
+/// Texas Hold'em: two hole cards; hands use any five of the seven cards
+pub struct HoldEm;
+
+impl GameVariant for HoldEm {
+    fn hole_cards(&self) -> usize {
+        2
+    }
+}
+
+/// Omaha: four hole cards; hands use exactly two of them and three from the board
+pub struct Omaha;
+
+impl GameVariant for Omaha {
+    fn hole_cards(&self) -> usize {
+        4
+    }
+
+    fn evaluate(&self, hole_cards: &[Card], community: &[Card]) -> HandScore {
+        evaluate_omaha(hole_cards, community)
+    }
+}
+
+/// Omaha hi/lo (eight or better): half the pot to the best high, half to the
+/// best low of five cards no higher than an eight; the high scoops if nobody
+/// qualifies
+pub struct OmahaHiLo;
+
+impl GameVariant for OmahaHiLo {
+    fn hole_cards(&self) -> usize {
+        4
+    }
+
+    fn evaluate(&self, hole_cards: &[Card], community: &[Card]) -> HandScore {
+        evaluate_omaha(hole_cards, community)
+    }
+
+    fn evaluate_low(&self, hole_cards: &[Card], community: &[Card]) -> Option<LowScore> {
+        omaha_eight_or_better_low(hole_cards, community)
+    }
+}
+
+/// Short-deck Hold'em: the 2-5 removed (36 cards); A-6-7-8-9 is the low
+/// straight and a flush beats a full house
+pub struct ShortDeck;
+
+impl GameVariant for ShortDeck {
+    fn hole_cards(&self) -> usize {
+        2
+    }
+
+    fn deck(&self) -> Vec<Card> {
+        (0..SHORT_DECK_SIZE as u8)
+            .filter_map(Card::from_short_deck_index)
+            .collect()
+    }
+
+    fn shuffle(&self, seed: &[u8]) -> Vec<Card> {
+        shuffle_short_deck(seed)
+    }
+
+    fn evaluate(&self, hole_cards: &[Card], community: &[Card]) -> HandScore {
+        evaluate_short_deck(hole_cards, community)
+    }
+}
+
+/// Seven-card stud: no board; each player gets three down cards and four up
+/// cards over five streets, with a bring-in instead of blinds
+pub struct SevenCardStud;
+
+impl GameVariant for SevenCardStud {
+    fn hole_cards(&self) -> usize {
+        3
+    }
+
+    fn board_cards(&self) -> usize {
+        0
+    }
+
+    fn max_players(&self) -> usize {
+        // Seven cards each must come out of 52
+        7
+    }
+
+    fn uses_blinds(&self) -> bool {
+        false
+    }
+
+    fn first_street(&self) -> GamePhase {
+        GamePhase::ThirdStreet
+    }
+
+    fn next_street(&self, phase: GamePhase) -> Option<GamePhase> {
+        match phase {
+            GamePhase::ThirdStreet => Some(GamePhase::FourthStreet),
+            GamePhase::FourthStreet => Some(GamePhase::FifthStreet),
+            GamePhase::FifthStreet => Some(GamePhase::SixthStreet),
+            GamePhase::SixthStreet => Some(GamePhase::SeventhStreet),
+            GamePhase::SeventhStreet => Some(GamePhase::Showdown),
+            _ => None,
+        }
+    }
+
+    fn street_cards(&self, street: GamePhase) -> (usize, usize) {
+        match street {
+            GamePhase::ThirdStreet => (2, 1),
+            GamePhase::FourthStreet | GamePhase::FifthStreet | GamePhase::SixthStreet => (0, 1),
+            GamePhase::SeventhStreet => (1, 0),
+            _ => (0, 0),
+        }
+    }
+
+    /// The best hand showing acts first; ties go to the first such seat
+    /// clockwise from the button
+    fn first_to_act(
+        &self,
+        button: Seat,
+        active: &[Seat],
+        up_cards: &[(Seat, Vec<Card>)],
+    ) -> Option<Seat> {
+        best_showing(button, active, up_cards, evaluate_up_cards)
+    }
+}
+
+/// Five-card draw: five down cards and no board; after the first betting
+/// round each player may swap up to three cards for new ones from the deck
+pub struct FiveCardDraw;
+
+impl GameVariant for FiveCardDraw {
+    fn hole_cards(&self) -> usize {
+        5
+    }
+
+    fn board_cards(&self) -> usize {
+        0
+    }
+
+    fn max_players(&self) -> usize {
+        // Five cards each plus up to three replacements must come out of 52
+        6
+    }
+
+    fn first_street(&self) -> GamePhase {
+        GamePhase::PreDraw
+    }
+
+    fn next_street(&self, phase: GamePhase) -> Option<GamePhase> {
+        match phase {
+            GamePhase::PreDraw => Some(GamePhase::Draw),
+            GamePhase::Draw => Some(GamePhase::PostDraw),
+            GamePhase::PostDraw => Some(GamePhase::Showdown),
+            _ => None,
+        }
+    }
+
+    fn max_discards(&self) -> usize {
+        3
+    }
+}
+
+/// Razz: seven-card stud played for the ace-to-five low only; the highest
+/// door card brings it in and the lowest hand showing acts first
+pub struct Razz;
+
+impl GameVariant for Razz {
+    fn hole_cards(&self) -> usize {
+        SevenCardStud.hole_cards()
+    }
+
+    fn board_cards(&self) -> usize {
+        SevenCardStud.board_cards()
+    }
+
+    fn max_players(&self) -> usize {
+        SevenCardStud.max_players()
+    }
+
+    fn uses_blinds(&self) -> bool {
+        false
+    }
+
+    fn first_street(&self) -> GamePhase {
+        SevenCardStud.first_street()
+    }
+
+    fn next_street(&self, phase: GamePhase) -> Option<GamePhase> {
+        SevenCardStud.next_street(phase)
+    }
+
+    fn street_cards(&self, street: GamePhase) -> (usize, usize) {
+        SevenCardStud.street_cards(street)
+    }
+
+    fn first_to_act(
+        &self,
+        button: Seat,
+        active: &[Seat],
+        up_cards: &[(Seat, Vec<Card>)],
+    ) -> Option<Seat> {
+        best_showing(button, active, up_cards, ace_to_five_low)
+    }
+
+    fn bring_in(&self, door_cards: &[(Seat, Card)]) -> Option<Seat> {
+        razz_bring_in_seat(door_cards)
+    }
+
+    fn plays_high(&self) -> bool {
+        false
+    }
+
+    fn evaluate_low(&self, hole_cards: &[Card], _community: &[Card]) -> Option<LowScore> {
+        Some(ace_to_five_low(hole_cards))
+    }
+}
@@ -34,8 +34,13 @@
 //!
 //! The type signatures will remain unchanged, ensuring seamless migration.
 
+use crate::mental::{hash, hash_to_scalar, point, to_bytes};
 use crate::Card;
+use ark_bls12_381::{Fr, G1Projective};
+use ark_ec::Group;
+use ark_ff::Zero;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 // ============================================================================
 // CARD COMMITMENT (Pedersen Commitment)
@@ -89,6 +94,9 @@ impl CardCommitment {
     /// Size of the nonce in bytes.
     pub const NONCE_SIZE: usize = 16;
 
+    /// Size of a blinding factor in bytes.
+    pub const BLINDING_SIZE: usize = 32;
+
     /// Create a new CardCommitment with the given commitment and nonce.
     ///
     /// # Arguments
@@ -120,6 +128,38 @@ impl CardCommitment {
     pub fn is_valid(&self) -> bool {
         self.commitment.len() == Self::COMMITMENT_SIZE
     }
+
+    /// Whether `card` and `blinding` open this commitment.
+    pub fn opens(&self, card: Card, blinding: &[u8]) -> bool {
+        *self == commit_card(card, blinding, self.nonce)
+    }
+}
+
+/// Commit to `card` with the `blinding` factor and `nonce`.
+///
+/// In Phase 3 this is `SHA-256(card_index || blinding || nonce)`, padded to
+/// the size of a G1 point for Phase 2 compatibility. Phase 4 will upgrade to
+/// true BLS12-381 Pedersen commitments.
+pub fn commit_card(card: Card, blinding: &[u8], nonce: [u8; 16]) -> CardCommitment {
+    let mut hasher = Sha256::new();
+    hasher.update([card.to_index()]);
+    hasher.update(blinding);
+    hasher.update(nonce);
+    let mut commitment = hasher.finalize().to_vec();
+    commitment.resize(CardCommitment::COMMITMENT_SIZE, 0);
+    CardCommitment::new(commitment, nonce)
+}
+
+/// Blinding factor of the card at `index` among a player's cards in game
+/// `game_id`, derived from the entropy the player revealed for the hand.
+///
+/// The player can recompute it without opening the seal. It is no secret
+/// from the chain: the entropy travels in the table's inputs, so anyone
+/// replaying them can open the commitment. Commitments only bind the table
+/// to the cards it dealt; dealerless tables keep cards from chain observers.
+pub fn card_blinding(entropy: &[u8; 32], game_id: u64, index: u8) -> Vec<u8> {
+    let parts: [&[u8]; 3] = [entropy, &game_id.to_le_bytes(), &[index]];
+    hash(b"LINERA_POKER_BLINDING", &parts).to_vec()
 }
 
 // ============================================================================
@@ -161,7 +201,7 @@ impl CardCommitment {
 /// - **Non-malleability**: The proof cannot be modified or reused for different cards
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DealingProof {
-    /// Groth16 zero-knowledge proof (192 bytes).
+    /// Groth16 zero-knowledge proof (192 bytes per pair of dealt cards).
     ///
     /// Structure: π = (A, B, C) where:
     /// - A: G1 point (48 bytes compressed)
@@ -173,15 +213,26 @@ pub struct DealingProof {
 
     /// Pedersen commitments to the dealt cards.
     ///
-    /// For Texas Hold'em, this is always exactly 2 cards (hole cards).
+    /// 2 hole cards for Texas Hold'em, 4 for Omaha; in seven-card stud the
+    /// down cards dealt on that street (2 on third street, 1 on seventh).
     /// Each commitment binds the dealer to a specific card value.
-    pub card_commitments: [CardCommitment; 2],
+    pub card_commitments: Vec<CardCommitment>,
 
     /// Merkle root of the shuffled 52-card deck.
     ///
     /// This root commits the dealer to the entire deck ordering.
     /// The ZK proof verifies that dealt cards exist as leaves in this Merkle tree.
     pub deck_root: [u8; 32],
+
+    /// Each card's index and blinding factor, sealed to the card key the
+    /// player registered when they sat down, in the order of
+    /// `card_commitments`.
+    ///
+    /// The player opens them with their card secret, which gives them their
+    /// cards and the openings of their commitments for the reveal (the seal
+    /// randomness isn't secret from the chain, see [`seal_opening`]). Empty
+    /// in mock proofs.
+    pub sealed_openings: Vec<SealedOpening>,
 }
 
 impl DealingProof {
@@ -197,6 +248,15 @@ impl DealingProof {
     /// Number of cards dealt in Texas Hold'em hole cards.
     pub const DEALT_CARDS_COUNT: usize = 2;
 
+    /// Number of cards dealt in Omaha hole cards.
+    pub const OMAHA_DEALT_CARDS_COUNT: usize = 4;
+
+    /// Number of down cards a seven-card stud player holds by showdown.
+    pub const STUD_DOWN_CARDS_COUNT: usize = 3;
+
+    /// Number of cards dealt in five-card draw.
+    pub const DRAW_DEALT_CARDS_COUNT: usize = 5;
+
     /// Size of Merkle root in bytes (SHA-256 hash).
     pub const DECK_ROOT_SIZE: usize = 32;
 
@@ -204,8 +264,8 @@ impl DealingProof {
     ///
     /// # Arguments
     ///
-    /// * `proof` - The Groth16 proof bytes (192 bytes expected)
-    /// * `card_commitments` - Array of exactly 2 card commitments
+    /// * `proof` - One 192-byte Groth16 proof per pair of dealt cards
+    /// * `card_commitments` - 2 (Hold'em) or 4 (Omaha) card commitments
     /// * `deck_root` - 32-byte Merkle root of the shuffled deck
     ///
     /// # Example
@@ -218,17 +278,18 @@ impl DealingProof {
     /// let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
     /// let deck_root = [0u8; 32];
     ///
-    /// let dealing_proof = DealingProof::new(proof, [commitment1, commitment2], deck_root);
+    /// let dealing_proof = DealingProof::new(proof, vec![commitment1, commitment2], deck_root);
     /// ```
     pub fn new(
         proof: Vec<u8>,
-        card_commitments: [CardCommitment; 2],
+        card_commitments: Vec<CardCommitment>,
         deck_root: [u8; 32],
     ) -> Self {
         Self {
             proof,
             card_commitments,
             deck_root,
+            sealed_openings: Vec::new(),
         }
     }
 
@@ -241,9 +302,296 @@ impl DealingProof {
     ///
     /// `true` if the proof structure is valid, `false` otherwise.
     pub fn is_structurally_valid(&self) -> bool {
-        self.proof.len() == Self::PROOF_SIZE
+        // Seventh street deals a stud player a single down card
+        (self.card_commitments.len() == 1 || is_supported_card_count(self.card_commitments.len()))
+            && self.proof.len() == proof_size_for(self.card_commitments.len())
+            && self.card_commitments.iter().all(|c| c.is_valid())
+    }
+}
+
+/// The dealing and reveal circuits each cover one pair of cards, so a hand of
+/// `n` hole cards carries `n / 2` Groth16 proofs concatenated in order (an odd
+/// last card gets a proof of its own).
+pub const fn proof_size_for(card_count: usize) -> usize {
+    DealingProof::PROOF_SIZE * card_count.div_ceil(2)
+}
+
+/// Hold'em (2), stud (3 down cards), Omaha (4) and draw (5) hole card counts.
+fn is_supported_card_count(card_count: usize) -> bool {
+    (DealingProof::DEALT_CARDS_COUNT..=DealingProof::DRAW_DEALT_CARDS_COUNT).contains(&card_count)
+}
+
+// ============================================================================
+// DRAW PROOF (Draw Phase ZK-SNARK)
+// ============================================================================
+
+/// Zero-knowledge proof that replacement cards on a draw come from the
+/// undealt part of the committed deck.
+///
+/// Generated by the dealer when a player discards in five-card draw.
+/// It proves in zero-knowledge that:
+///
+/// 1. Each replacement card is the leaf at its deck position under `deck_root`
+/// 2. Every position is at or past `first_undealt`, so it was never dealt before
+/// 3. No position is used twice
+///
+/// # Circuit Public Inputs
+///
+/// - Merkle root of the deck commitment (the one from the dealing proof)
+/// - Pedersen commitments to the replacement cards
+/// - The deck positions and the first undealt position
+///
+/// # Circuit Private Inputs (Witness)
+///
+/// - The replacement card indices
+/// - Merkle proof paths for each position
+/// - Pedersen randomness for each card commitment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawProof {
+    /// Groth16 zero-knowledge proof (192 bytes per pair of replacement cards).
+    ///
+    /// In Phase 3, this is a placeholder byte vector.
+    pub proof: Vec<u8>,
+
+    /// Positions in the player's hand of the discarded cards, in the order
+    /// their replacements are listed.
+    pub discarded: Vec<u8>,
+
+    /// Pedersen commitments to the replacement cards, one per discarded card.
+    pub card_commitments: Vec<CardCommitment>,
+
+    /// Deck positions the replacements were taken from.
+    pub deck_positions: Vec<u8>,
+
+    /// First deck position not dealt before this draw.
+    pub first_undealt: u8,
+
+    /// Merkle root of the shuffled deck, unchanged since dealing.
+    pub deck_root: [u8; 32],
+
+    /// Each replacement's index and blinding factor, sealed to the player's
+    /// card key, in the order of `card_commitments`. Empty in mock proofs.
+    pub sealed_openings: Vec<SealedOpening>,
+}
+
+impl DrawProof {
+    /// Validate the structural correctness of the proof for a hand of
+    /// `hand_size` cards.
+    ///
+    /// Checks one commitment and one fresh deck position per discarded card,
+    /// distinct hand positions, and the proof size.
+    pub fn is_structurally_valid(&self, hand_size: usize) -> bool {
+        let count = self.discarded.len();
+        let mut discarded = self.discarded.clone();
+        discarded.sort_unstable();
+        discarded.dedup();
+        let mut positions = self.deck_positions.clone();
+        positions.sort_unstable();
+        positions.dedup();
+
+        count > 0
+            && count <= hand_size
+            && discarded.len() == count
+            && discarded.iter().all(|&i| (i as usize) < hand_size)
+            && self.card_commitments.len() == count
             && self.card_commitments.iter().all(|c| c.is_valid())
+            && positions.len() == count
+            && positions.iter().all(|&p| p >= self.first_undealt)
+            && self.proof.len() == proof_size_for(count)
+    }
+}
+
+// ============================================================================
+// SEALED OPENINGS (Hole Cards Encrypted to the Player)
+// ============================================================================
+
+/// A dealt card's index and blinding factor, encrypted to the card key the
+/// player registered when they sat down.
+///
+/// Hashed ElGamal on BLS12-381 G1: the dealer picks `r`, sends `R = r·G` and
+/// XORs the opening with a key stream derived from `r·K`, where `K = k·G` is
+/// the player's card key. Without `r`, only the player can recompute
+/// `k·R = r·K`, so the cards aren't readable in the player chain's state until
+/// the player's client opens them with `k` (see [`open_sealed`]). The table
+/// derives `r` from public inputs, so this is no protection from anyone
+/// replaying the table chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SealedOpening {
+    /// `R = r·G` (48-byte compressed G1 point).
+    pub ephemeral: Vec<u8>,
+
+    /// Card index and blinding factor, XORed with the key stream.
+    pub ciphertext: Vec<u8>,
+}
+
+impl SealedOpening {
+    /// Size of an opening: the card index and its blinding factor.
+    pub const OPENING_SIZE: usize = 1 + CardCommitment::BLINDING_SIZE;
+}
+
+/// Public card key for a player's secret (32 random bytes the client keeps).
+///
+/// Registered with the table when the player sits down; the table seals
+/// every card it deals the player to it.
+pub fn card_key(secret: &[u8]) -> Vec<u8> {
+    to_bytes(&(G1Projective::generator() * card_secret_key(secret)))
+}
+
+/// Whether `key` can be sealed to: a G1 point other than the identity.
+pub fn is_card_key(key: &[u8]) -> bool {
+    point(key).is_some_and(|key| !key.is_zero())
+}
+
+/// Seal `card` and its `blinding` factor to `card_key`.
+///
+/// `seed` must be unique to the card dealt; the encryption randomness is
+/// derived from it and the key, so anyone who can compute the seed can open
+/// the seal too. Returns `None` for an unusable key or a blinding factor of
+/// the wrong size.
+pub fn seal_opening(
+    card_key: &[u8],
+    card: Card,
+    blinding: &[u8],
+    seed: &[u8],
+) -> Option<SealedOpening> {
+    let key = point(card_key)?;
+    if key.is_zero() || blinding.len() != CardCommitment::BLINDING_SIZE {
+        return None;
+    }
+    let r = hash_to_scalar(b"LINERA_POKER_SEAL", &[card_key, seed]);
+    let mut ciphertext = vec![card.to_index()];
+    ciphertext.extend_from_slice(blinding);
+    apply_key_stream(&mut ciphertext, &(key * r));
+    Some(SealedOpening {
+        ephemeral: to_bytes(&(G1Projective::generator() * r)),
+        ciphertext,
+    })
+}
+
+/// Open a sealed card with the player's secret: the card and the blinding
+/// factor that opens its commitment.
+///
+/// Run by the player's own client; a wrong secret yields garbage, which the
+/// caller detects by checking the opening against the commitment.
+pub fn open_sealed(secret: &[u8], sealed: &SealedOpening) -> Option<(Card, Vec<u8>)> {
+    let ephemeral = point(&sealed.ephemeral)?;
+    if sealed.ciphertext.len() != SealedOpening::OPENING_SIZE {
+        return None;
+    }
+    let mut opening = sealed.ciphertext.clone();
+    apply_key_stream(&mut opening, &(ephemeral * card_secret_key(secret)));
+    let card = Card::from_index(opening[0])?;
+    Some((card, opening.split_off(1)))
+}
+
+fn card_secret_key(secret: &[u8]) -> Fr {
+    hash_to_scalar(b"LINERA_POKER_CARD_KEY", &[secret])
+}
+
+/// XOR `bytes` with a SHA-256 counter-mode stream keyed by the shared point
+fn apply_key_stream(bytes: &mut [u8], shared: &G1Projective) {
+    let shared = to_bytes(shared);
+    for (block, chunk) in bytes.chunks_mut(32).enumerate() {
+        let pad = hash(
+            b"LINERA_POKER_SEAL_STREAM",
+            &[&shared, &(block as u64).to_le_bytes()],
+        );
+        for (byte, key) in chunk.iter_mut().zip(pad) {
+            *byte ^= key;
+        }
+    }
+}
+
+// ============================================================================
+// BOARD OPENINGS (Community Cards Against the Deck Root)
+// ============================================================================
+
+/// A community card opened at the deck position the table committed to for
+/// it, with the Merkle path showing it is the leaf there under the deck root.
+///
+/// Leaves are `H(position || card || salt)`: the position binds the card to
+/// its place in the deck, and the salt keeps the sibling leaves on a path
+/// from giving away the cards next to it (there are only 52 to try).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardCardProof {
+    /// Deck position of the card.
+    pub position: u8,
+
+    /// The card opened.
+    pub card: Card,
+
+    /// Salt hashed into the card's leaf.
+    pub salt: [u8; 32],
+
+    /// Sibling hashes from the leaf up to the root.
+    pub path: Vec<[u8; 32]>,
+}
+
+/// Leaf of the deck Merkle tree for `card` at `position`.
+pub fn deck_leaf(position: u8, card: Card, salt: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([position, card.to_index()]);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Merkle root over the deck's leaves, in deck order.
+///
+/// Pairs are hashed left to right; an odd node at the end of a level is
+/// paired with itself.
+pub fn deck_merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| merkle_parent(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+    }
+    level.first().copied().unwrap_or([0u8; 32])
+}
+
+/// Sibling hashes from the leaf at `position` up to the root (empty for a
+/// position past the end of the deck).
+pub fn deck_merkle_path(leaves: &[[u8; 32]], position: usize) -> Vec<[u8; 32]> {
+    let mut path = Vec::new();
+    if position >= leaves.len() {
+        return path;
+    }
+    let mut level = leaves.to_vec();
+    let mut index = position;
+    while level.len() > 1 {
+        // The odd node at the end of a level is its own sibling
+        path.push(*level.get(index ^ 1).unwrap_or(&level[index]));
+        level = level
+            .chunks(2)
+            .map(|pair| merkle_parent(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+        index /= 2;
     }
+    path
+}
+
+/// Verify that a board card is the leaf at its position under `deck_root`.
+pub fn verify_board_card(proof: &BoardCardProof, deck_root: &[u8; 32]) -> bool {
+    let mut node = deck_leaf(proof.position, proof.card, &proof.salt);
+    let mut index = proof.position as usize;
+    for sibling in &proof.path {
+        node = if index % 2 == 0 {
+            merkle_parent(&node, sibling)
+        } else {
+            merkle_parent(sibling, &node)
+        };
+        index /= 2;
+    }
+    // A path too short for the position would climb from the wrong leaf
+    index == 0 && node == *deck_root
+}
+
+fn merkle_parent(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
 }
 
 // ============================================================================
@@ -282,7 +630,7 @@ impl DealingProof {
 /// - **Binding**: The commitment scheme ensures cards cannot be changed after dealing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RevealProof {
-    /// Groth16 zero-knowledge proof (192 bytes).
+    /// Groth16 zero-knowledge proof (192 bytes per pair of revealed cards).
     ///
     /// Proves knowledge of valid openings (randomness) for the card commitments
     /// without revealing the randomness before showdown.
@@ -292,7 +640,7 @@ pub struct RevealProof {
 
     /// The actual card values being revealed.
     ///
-    /// For Texas Hold'em, this is always exactly 2 cards.
+    /// 2 cards for Texas Hold'em, 4 for Omaha, 3 down cards in seven-card stud.
     /// These are the player's hole cards that were previously committed.
     pub cards: Vec<Card>,
 
@@ -321,7 +669,7 @@ impl RevealProof {
     /// # Arguments
     ///
     /// * `proof` - The Groth16 proof bytes (192 bytes expected)
-    /// * `cards` - The revealed cards (exactly 2 for Texas Hold'em)
+    /// * `cards` - The revealed cards (2 for Texas Hold'em, 4 for Omaha)
     /// * `randomness` - The Pedersen randomness for each card (32 bytes each)
     ///
     /// # Example
@@ -359,9 +707,9 @@ impl RevealProof {
     ///
     /// `true` if the proof structure is valid, `false` otherwise.
     pub fn is_structurally_valid(&self) -> bool {
-        self.proof.len() == Self::PROOF_SIZE
-            && self.cards.len() == Self::REVEALED_CARDS_COUNT
-            && self.randomness.len() == Self::REVEALED_CARDS_COUNT
+        is_supported_card_count(self.cards.len())
+            && self.proof.len() == proof_size_for(self.cards.len())
+            && self.randomness.len() == self.cards.len()
             && self
                 .randomness
                 .iter()
@@ -515,7 +863,7 @@ impl PokerProofParams {
 /// let commitment1 = CardCommitment::new(vec![0u8; 48], [1u8; 16]);
 /// let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
 /// let deck_root = [0u8; 32];
-/// let dealing_proof = DealingProof::new(proof, [commitment1, commitment2], deck_root);
+/// let dealing_proof = DealingProof::new(proof, vec![commitment1, commitment2], deck_root);
 ///
 /// let params = PokerProofParams::new(vec![0u8; 300], vec![0u8; 300]);
 ///
@@ -594,7 +942,7 @@ pub fn verify_dealing_proof(proof: &DealingProof, params: &PokerProofParams) ->
 ///
 /// let commitment1 = CardCommitment::new(vec![0u8; 48], [1u8; 16]);
 /// let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
-/// let stored_commitments = [commitment1, commitment2];
+/// let stored_commitments = vec![commitment1, commitment2];
 ///
 /// let params = PokerProofParams::new(vec![0u8; 300], vec![0u8; 300]);
 ///
@@ -603,7 +951,7 @@ pub fn verify_dealing_proof(proof: &DealingProof, params: &PokerProofParams) ->
 /// ```
 pub fn verify_reveal_proof(
     proof: &RevealProof,
-    stored_commitments: &[CardCommitment; 2],
+    stored_commitments: &[CardCommitment],
     params: &PokerProofParams,
 ) -> bool {
     // Phase 1 MOCK: Only basic structural validation
@@ -620,7 +968,9 @@ pub fn verify_reveal_proof(
         return false;
     }
 
-    if !stored_commitments.iter().all(|c| c.is_valid()) {
+    if stored_commitments.len() != proof.cards.len()
+        || !stored_commitments.iter().all(|c| c.is_valid())
+    {
         return false;
     }
 
@@ -633,6 +983,31 @@ pub fn verify_reveal_proof(
     true
 }
 
+/// Verify a draw proof for a hand of `hand_size` cards dealt under `deck_root`.
+///
+/// # Phase 3 Implementation (MOCK VERSION)
+///
+/// **WARNING**: Only checks the structure and that the proof refers to the
+/// deck the hand was dealt from. Real verification will check the Groth16
+/// proof of Merkle membership at each position.
+///
+/// # Example
+///
+/// ```
+/// use linera_poker_shared::zk::{create_mock_draw_proof, verify_draw_proof};
+/// use linera_poker_shared::{Card, Suit, Rank};
+///
+/// let replacements = [Card::new(Suit::Clubs, Rank::Nine)];
+/// let draw_proof = create_mock_draw_proof(&[3], &replacements, 10, [7u8; 32]);
+///
+/// assert!(verify_draw_proof(&draw_proof, 5, &[7u8; 32]));
+/// assert!(!verify_draw_proof(&draw_proof, 5, &[0u8; 32]));
+/// ```
+pub fn verify_draw_proof(proof: &DrawProof, hand_size: usize, deck_root: &[u8; 32]) -> bool {
+    // Phase 3 MOCK: structural validation only
+    proof.is_structurally_valid(hand_size) && proof.deck_root == *deck_root
+}
+
 // ============================================================================
 // HELPER FUNCTIONS (For Testing)
 // ============================================================================
@@ -665,26 +1040,29 @@ pub fn verify_reveal_proof(
 /// let proof = create_mock_dealing_proof(&cards);
 /// assert!(proof.is_structurally_valid());
 /// ```
-pub fn create_mock_dealing_proof(cards: &[Card; 2]) -> DealingProof {
+pub fn create_mock_dealing_proof(cards: &[Card]) -> DealingProof {
     // Create mock commitments based on card indices
-    let commitment1 = CardCommitment::new(
-        vec![cards[0].to_index(); CardCommitment::COMMITMENT_SIZE],
-        [1u8; CardCommitment::NONCE_SIZE],
-    );
-    let commitment2 = CardCommitment::new(
-        vec![cards[1].to_index(); CardCommitment::COMMITMENT_SIZE],
-        [2u8; CardCommitment::NONCE_SIZE],
-    );
-
-    // Create mock proof (all zeros)
-    let proof = vec![0u8; DealingProof::PROOF_SIZE];
+    let commitments = cards
+        .iter()
+        .enumerate()
+        .map(|(i, card)| {
+            CardCommitment::new(
+                vec![card.to_index(); CardCommitment::COMMITMENT_SIZE],
+                [i as u8 + 1; CardCommitment::NONCE_SIZE],
+            )
+        })
+        .collect();
+
+    // Create mock proof (all zeros, one per pair of cards)
+    let proof = vec![0u8; proof_size_for(cards.len())];
 
     // Create mock deck root (deterministic based on cards for testing)
     let mut deck_root = [0u8; DealingProof::DECK_ROOT_SIZE];
-    deck_root[0] = cards[0].to_index();
-    deck_root[1] = cards[1].to_index();
+    for (byte, card) in deck_root.iter_mut().zip(cards) {
+        *byte = card.to_index();
+    }
 
-    DealingProof::new(proof, [commitment1, commitment2], deck_root)
+    DealingProof::new(proof, commitments, deck_root)
 }
 
 /// Create a mock reveal proof for testing.
@@ -720,21 +1098,46 @@ pub fn create_mock_dealing_proof(cards: &[Card; 2]) -> DealingProof {
 /// assert_eq!(reveal_proof.cards.len(), 2);
 /// ```
 pub fn create_mock_reveal_proof(
-    cards: &[Card; 2],
-    commitments: &[CardCommitment; 2],
+    cards: &[Card],
+    commitments: &[CardCommitment],
 ) -> RevealProof {
-    // Create mock proof (all zeros)
-    let proof = vec![0u8; RevealProof::PROOF_SIZE];
+    // Create mock proof (all zeros, one per pair of cards)
+    let proof = vec![0u8; proof_size_for(cards.len())];
 
     // Create mock randomness (derived from commitment nonces for consistency)
-    let randomness = vec![
-        commitments[0].nonce.repeat(2), // 16 * 2 = 32 bytes
-        commitments[1].nonce.repeat(2),
-    ];
+    let randomness = commitments
+        .iter()
+        .map(|c| c.nonce.repeat(2)) // 16 * 2 = 32 bytes
+        .collect();
 
     RevealProof::new(proof, cards.to_vec(), randomness)
 }
 
+/// Create a mock draw proof for testing.
+///
+/// Replaces the cards at hand positions `discarded` with `replacements`,
+/// taken from consecutive deck positions starting at `first_undealt`.
+pub fn create_mock_draw_proof(
+    discarded: &[u8],
+    replacements: &[Card],
+    first_undealt: u8,
+    deck_root: [u8; 32],
+) -> DrawProof {
+    let dealing_proof = create_mock_dealing_proof(replacements);
+
+    DrawProof {
+        proof: vec![0u8; proof_size_for(replacements.len())],
+        discarded: discarded.to_vec(),
+        card_commitments: dealing_proof.card_commitments,
+        deck_positions: (0..replacements.len() as u8)
+            .map(|offset| first_undealt + offset)
+            .collect(),
+        first_undealt,
+        deck_root,
+        sealed_openings: Vec::new(),
+    }
+}
+
 /// Create mock poker proof parameters for testing.
 ///
 /// Generates placeholder verification keys that will pass structural validation.
@@ -794,7 +1197,7 @@ mod tests {
         let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
         let deck_root = [0u8; 32];
 
-        let dealing_proof = DealingProof::new(proof, [commitment1, commitment2], deck_root);
+        let dealing_proof = DealingProof::new(proof, vec![commitment1, commitment2], deck_root);
         assert!(dealing_proof.is_structurally_valid());
     }
 
@@ -805,7 +1208,7 @@ mod tests {
         let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
         let deck_root = [0u8; 32];
 
-        let dealing_proof = DealingProof::new(proof, [commitment1, commitment2], deck_root);
+        let dealing_proof = DealingProof::new(proof, vec![commitment1, commitment2], deck_root);
         assert!(!dealing_proof.is_structurally_valid());
     }
 
@@ -816,7 +1219,7 @@ mod tests {
         let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
         let deck_root = [0u8; 32];
 
-        let dealing_proof = DealingProof::new(proof, [commitment1, commitment2], deck_root);
+        let dealing_proof = DealingProof::new(proof, vec![commitment1, commitment2], deck_root);
         assert!(!dealing_proof.is_structurally_valid());
     }
 
@@ -914,7 +1317,7 @@ mod tests {
         let commitment1 = CardCommitment::new(vec![0u8; 48], [1u8; 16]);
         let commitment2 = CardCommitment::new(vec![0u8; 48], [2u8; 16]);
         let deck_root = [0u8; 32];
-        let dealing_proof = DealingProof::new(proof, [commitment1, commitment2], deck_root);
+        let dealing_proof = DealingProof::new(proof, vec![commitment1, commitment2], deck_root);
         let params = create_mock_params();
 
         assert!(!verify_dealing_proof(&dealing_proof, &params));
@@ -982,6 +1385,92 @@ mod tests {
         assert_eq!(reveal_proof.randomness.len(), 2);
     }
 
+    #[test]
+    fn test_omaha_proofs_carry_one_proof_per_pair() {
+        let cards = [
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Diamonds, Rank::Jack),
+        ];
+        let dealing_proof = create_mock_dealing_proof(&cards);
+        assert!(dealing_proof.is_structurally_valid());
+        assert_eq!(dealing_proof.card_commitments.len(), DealingProof::OMAHA_DEALT_CARDS_COUNT);
+        assert_eq!(dealing_proof.proof.len(), 2 * DealingProof::PROOF_SIZE);
+
+        let reveal_proof = create_mock_reveal_proof(&cards, &dealing_proof.card_commitments);
+        let params = create_mock_params();
+        assert!(verify_reveal_proof(&reveal_proof, &dealing_proof.card_commitments, &params));
+
+        // A Hold'em-sized proof cannot open four commitments
+        let short = RevealProof::new(
+            vec![0u8; RevealProof::PROOF_SIZE],
+            cards.to_vec(),
+            reveal_proof.randomness.clone(),
+        );
+        assert!(!short.is_structurally_valid());
+        // Nor can a four-card reveal be checked against two commitments
+        assert!(!verify_reveal_proof(
+            &reveal_proof,
+            &dealing_proof.card_commitments[..2],
+            &params
+        ));
+    }
+
+    #[test]
+    fn test_stud_down_cards_get_a_proof_for_the_odd_card() {
+        let cards = [
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Clubs, Rank::Queen),
+        ];
+        assert_eq!(proof_size_for(1), DealingProof::PROOF_SIZE);
+        assert_eq!(
+            proof_size_for(DealingProof::STUD_DOWN_CARDS_COUNT),
+            2 * DealingProof::PROOF_SIZE
+        );
+
+        // Seventh street deals a single down card
+        assert!(create_mock_dealing_proof(&cards[2..]).is_structurally_valid());
+
+        let dealing_proof = create_mock_dealing_proof(&cards);
+        let reveal_proof = create_mock_reveal_proof(&cards, &dealing_proof.card_commitments);
+        assert!(reveal_proof.is_structurally_valid());
+        assert!(verify_reveal_proof(
+            &reveal_proof,
+            &dealing_proof.card_commitments,
+            &create_mock_params()
+        ));
+    }
+
+    #[test]
+    fn test_draw_proof_takes_replacements_from_undealt_positions() {
+        let deck_root = [9u8; 32];
+        let replacements = [
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Jack),
+        ];
+        let hand_size = DealingProof::DRAW_DEALT_CARDS_COUNT;
+        let draw_proof = create_mock_draw_proof(&[0, 4], &replacements, 10, deck_root);
+        assert_eq!(draw_proof.deck_positions, vec![10, 11]);
+        assert!(verify_draw_proof(&draw_proof, hand_size, &deck_root));
+
+        // A position dealt before the draw
+        let mut reused = draw_proof.clone();
+        reused.deck_positions[1] = 9;
+        assert!(!verify_draw_proof(&reused, hand_size, &deck_root));
+
+        // The same hand card discarded twice
+        let mut twice = draw_proof.clone();
+        twice.discarded = vec![4, 4];
+        assert!(!verify_draw_proof(&twice, hand_size, &deck_root));
+
+        // A card outside the hand
+        let mut outside = draw_proof;
+        outside.discarded = vec![0, 5];
+        assert!(!verify_draw_proof(&outside, hand_size, &deck_root));
+    }
+
     #[test]
     fn test_card_commitment_equality() {
         let commitment1 = CardCommitment::new(vec![42u8; 48], [1u8; 16]);
@@ -1020,6 +1509,77 @@ mod tests {
         assert_eq!(reveal_proof.cards, cloned.cards);
         assert_eq!(reveal_proof.randomness, cloned.randomness);
     }
+
+    #[test]
+    fn test_sealed_opening_opens_only_with_the_players_secret() {
+        let card = Card::new(Suit::Diamonds, Rank::Jack);
+        let blinding = [7u8; CardCommitment::BLINDING_SIZE];
+        let commitment = commit_card(card, &blinding, [3u8; CardCommitment::NONCE_SIZE]);
+        assert!(commitment.is_valid());
+        assert!(commitment.opens(card, &blinding));
+        assert!(!commitment.opens(Card::new(Suit::Diamonds, Rank::Queen), &blinding));
+
+        let key = card_key(b"alice");
+        assert!(is_card_key(&key));
+        assert!(!is_card_key(&[0u8; 48]));
+        let sealed = seal_opening(&key, card, &blinding, b"game 1, card 0").unwrap();
+        assert_eq!(sealed.ciphertext.len(), SealedOpening::OPENING_SIZE);
+        assert_ne!(sealed.ciphertext[1..], blinding);
+
+        let (opened, opening) = open_sealed(b"alice", &sealed).unwrap();
+        assert_eq!(opened, card);
+        assert!(commitment.opens(opened, &opening));
+
+        // Anyone else reads garbage that doesn't open the commitment
+        let wrong = open_sealed(b"bob", &sealed);
+        assert!(!wrong.is_some_and(|(card, opening)| commitment.opens(card, &opening)));
+
+        // Every card gets its own randomness
+        let other = seal_opening(&key, card, &blinding, b"game 1, card 1").unwrap();
+        assert_ne!(sealed, other);
+    }
+
+    #[test]
+    fn test_card_blinding_is_unique_to_the_players_entropy() {
+        let blinding = card_blinding(&[1u8; 32], 7, 0);
+        assert_eq!(blinding.len(), CardCommitment::BLINDING_SIZE);
+        assert_eq!(blinding, card_blinding(&[1u8; 32], 7, 0));
+        assert_ne!(blinding, card_blinding(&[2u8; 32], 7, 0));
+        assert_ne!(blinding, card_blinding(&[1u8; 32], 8, 0));
+        assert_ne!(blinding, card_blinding(&[1u8; 32], 7, 1));
+    }
+
+    #[test]
+    fn test_board_card_opens_against_the_deck_root() {
+        let deck: Vec<Card> = (0..52).filter_map(Card::from_index).collect();
+        let salt = |position: u8| [position; 32];
+        let leaves: Vec<[u8; 32]> = deck
+            .iter()
+            .enumerate()
+            .map(|(position, card)| deck_leaf(position as u8, *card, &salt(position as u8)))
+            .collect();
+        let root = deck_merkle_root(&leaves);
+
+        // Every position opens, including the odd one out at the end of a level
+        for position in [0u8, 4, 7, 51] {
+            let proof = BoardCardProof {
+                position,
+                card: deck[position as usize],
+                salt: salt(position),
+                path: deck_merkle_path(&leaves, position as usize),
+            };
+            assert!(verify_board_card(&proof, &root));
+            assert!(!verify_board_card(&proof, &[0u8; 32]));
+
+            // Nor can the dealer swap in another card or claim another position
+            let swapped = BoardCardProof { card: deck[0], ..proof.clone() };
+            assert!(position == 0 || !verify_board_card(&swapped, &root));
+            let moved = BoardCardProof { position: position ^ 1, ..proof };
+            assert!(!verify_board_card(&moved, &root));
+        }
+
+        assert!(deck_merkle_path(&leaves, 52).is_empty());
+    }
 }
 
 // ============================================================================
@@ -1300,6 +1860,7 @@ pub fn generate_dealing_proof(
     proving_key: &ProvingKey<Bls12_381>,
 ) -> Result<DealingProof, ProofError> {
     use ark_groth16::Groth16;
+    use ark_snark::SNARK;
     use ark_serialize::CanonicalSerialize;
     use ark_std::rand::SeedableRng;
 
@@ -1334,11 +1895,7 @@ pub fn generate_dealing_proof(
     proof.serialize_compressed(&mut proof_bytes)
         .map_err(|e| ProofError::SerializationError(format!("{:?}", e)))?;
 
-    Ok(DealingProof::new(
-        proof_bytes,
-        [commitments[0].clone(), commitments[1].clone()],
-        *deck_root,
-    ))
+    Ok(DealingProof::new(proof_bytes, commitments, *deck_root))
 }
 
 /// Generate a reveal proof (native only, not for WASM)
@@ -1364,6 +1921,7 @@ pub fn generate_reveal_proof(
     proving_key: &ProvingKey<Bls12_381>,
 ) -> Result<RevealProof, ProofError> {
     use ark_groth16::Groth16;
+    use ark_snark::SNARK;
     use ark_serialize::CanonicalSerialize;
     use ark_std::rand::SeedableRng;
 
@@ -1497,35 +2055,36 @@ pub fn verify_dealing_proof_real(
         Err(_) => return false,
     };
 
-    // Deserialize the proof
-    let groth16_proof = match Proof::<Bls12_381>::deserialize_compressed(&proof.proof[..]) {
-        Ok(p) => p,
-        Err(_) => return false,
-    };
-
-    // Construct public inputs
-    // Public inputs for dealing circuit:
-    // 1. Deck root (as field elements)
-    // 2. Card commitments (as field elements)
-    let mut public_inputs: Vec<Fr> = Vec::new();
-
-    // Add deck root bytes as field elements
-    for byte in proof.deck_root.iter() {
-        public_inputs.push(Fr::from(*byte as u64));
-    }
-
-    // Add commitment bytes as field elements
-    for commitment in proof.card_commitments.iter() {
-        for byte in commitment.commitment.iter() {
+    // The circuit covers one pair of cards; Omaha hands carry one proof per pair
+    let pairs = proof.card_commitments.chunks(2);
+    proof.proof.chunks(DealingProof::PROOF_SIZE).zip(pairs).all(|(proof_bytes, pair)| {
+        // Deserialize the proof
+        let groth16_proof = match Proof::<Bls12_381>::deserialize_compressed(proof_bytes) {
+            Ok(p) => p,
+            Err(_) => return false,
+        };
+
+        // Construct public inputs
+        // Public inputs for dealing circuit:
+        // 1. Deck root (as field elements)
+        // 2. Card commitments (as field elements)
+        let mut public_inputs: Vec<Fr> = Vec::new();
+
+        // Add deck root bytes as field elements
+        for byte in proof.deck_root.iter() {
             public_inputs.push(Fr::from(*byte as u64));
         }
-    }
 
-    // Verify the proof
-    match Groth16::<Bls12_381>::verify(&vk, &public_inputs, &groth16_proof) {
-        Ok(result) => result,
-        Err(_) => false,
-    }
+        // Add commitment bytes as field elements
+        for commitment in pair {
+            for byte in commitment.commitment.iter() {
+                public_inputs.push(Fr::from(*byte as u64));
+            }
+        }
+
+        // Verify the proof
+        Groth16::<Bls12_381>::verify(&vk, &public_inputs, &groth16_proof).unwrap_or(false)
+    })
 }
 
 /// Verify a reveal proof using real Groth16 verification
@@ -1544,7 +2103,7 @@ pub fn verify_dealing_proof_real(
 /// `true` if the proof is cryptographically valid and cards match, `false` otherwise.
 pub fn verify_reveal_proof_real(
     proof: &RevealProof,
-    stored_commitments: &[CardCommitment; 2],
+    stored_commitments: &[CardCommitment],
     verifying_key_bytes: &[u8],
 ) -> bool {
     use ark_bls12_381::{Bls12_381, Fr};
@@ -1557,7 +2116,9 @@ pub fn verify_reveal_proof_real(
         return false;
     }
 
-    if !stored_commitments.iter().all(|c| c.is_valid()) {
+    if stored_commitments.len() != proof.cards.len()
+        || !stored_commitments.iter().all(|c| c.is_valid())
+    {
         return false;
     }
 
@@ -1567,35 +2128,37 @@ pub fn verify_reveal_proof_real(
         Err(_) => return false,
     };
 
-    // Deserialize the proof
-    let groth16_proof = match Proof::<Bls12_381>::deserialize_compressed(&proof.proof[..]) {
-        Ok(p) => p,
-        Err(_) => return false,
-    };
-
-    // Construct public inputs
-    // Public inputs for reveal circuit:
-    // 1. Card commitments (as field elements)
-    // 2. Revealed card values
-    let mut public_inputs: Vec<Fr> = Vec::new();
-
-    // Add commitment bytes as field elements
-    for commitment in stored_commitments.iter() {
-        for byte in commitment.commitment.iter() {
-            public_inputs.push(Fr::from(*byte as u64));
+    // The circuit covers one pair of cards; Omaha hands carry one proof per pair
+    let pairs = stored_commitments.chunks(2).zip(proof.cards.chunks(2));
+    let mut checks = proof.proof.chunks(RevealProof::PROOF_SIZE).zip(pairs);
+    checks.all(|(proof_bytes, (commitments, cards))| {
+        // Deserialize the proof
+        let groth16_proof = match Proof::<Bls12_381>::deserialize_compressed(proof_bytes) {
+            Ok(p) => p,
+            Err(_) => return false,
+        };
+
+        // Construct public inputs
+        // Public inputs for reveal circuit:
+        // 1. Card commitments (as field elements)
+        // 2. Revealed card values
+        let mut public_inputs: Vec<Fr> = Vec::new();
+
+        // Add commitment bytes as field elements
+        for commitment in commitments {
+            for byte in commitment.commitment.iter() {
+                public_inputs.push(Fr::from(*byte as u64));
+            }
         }
-    }
 
-    // Add revealed card values
-    for card in proof.cards.iter() {
-        public_inputs.push(Fr::from(card.to_index() as u64));
-    }
+        // Add revealed card values
+        for card in cards {
+            public_inputs.push(Fr::from(card.to_index() as u64));
+        }
 
-    // Verify the proof
-    match Groth16::<Bls12_381>::verify(&vk, &public_inputs, &groth16_proof) {
-        Ok(result) => result,
-        Err(_) => false,
-    }
+        // Verify the proof
+        Groth16::<Bls12_381>::verify(&vk, &public_inputs, &groth16_proof).unwrap_or(false)
+    })
 }
 
 // ============================================================================
@@ -1632,7 +2195,7 @@ pub fn verify_dealing_proof_embedded(proof: &DealingProof) -> bool {
 /// This is the recommended function for WASM contracts.
 pub fn verify_reveal_proof_embedded(
     proof: &RevealProof,
-    stored_commitments: &[CardCommitment; 2],
+    stored_commitments: &[CardCommitment],
 ) -> bool {
     verify_reveal_proof_real(proof, stored_commitments, REVEAL_VK_BYTES)
 }
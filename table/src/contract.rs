@@ -3,13 +3,25 @@
 mod state;
 
 use self::state::TableState;
-use linera_poker_shared::{evaluate_hand, shuffle_deck};
+use linera_poker_shared::{
+    best_hands, build_pots, deal_payouts, deck_seed, entropy_commitment, jackpot_hit,
+    jackpot_payouts, prize_payouts, return_uncalled_bet, split_pot, take_rake, DealKind,
+    HandScore, LowScore, Departure, MentalDeal, MentalRequest, MentalResponse, Pot, PotAward,
+    BettingStructure, StreetBetting, TableReport, TournamentFinish,
+};
 use linera_poker_table::{
     BetAction, Card, CardReveal, GamePhase, InstantiationArgument, Message, PlayerInfo, Seat,
-    TableAbi, TableOperation, TableResult,
+    TableAbi, TableError, TableOperation, TableResult,
+};
+use linera_poker_shared::{
+    BoardCardProof, CardCommitment, DealingProof, DrawProof, RevealProof, SealedOpening,
 };
-use linera_poker_shared::{CardCommitment, DealingProof, RevealProof};
-use linera_poker_shared::zk::verify_reveal_proof_embedded;
+use linera_poker_shared::mental::mental_context;
+use linera_poker_shared::zk::{
+    card_blinding, commit_card, deck_leaf, deck_merkle_path, deck_merkle_root, is_card_key,
+    proof_size_for, seal_opening, verify_reveal_proof_embedded,
+};
+use linera_poker_token::{TokenAbi, TokenOperation, TokenResult};
 use linera_sdk::{
     linera_base_types::{AccountOwner, Amount, ApplicationId, ChainId, WithContractAbi},
     views::{RootView, View},
@@ -42,23 +54,60 @@ impl Contract for TableContract {
     }
 
     async fn instantiate(&mut self, arg: InstantiationArgument) {
+        let creator = self.runtime.authenticated_signer();
+        self.state.creator.set(creator);
         self.state.game_id.set(1);
         self.state.phase.set(GamePhase::WaitingForPlayers);
         self.state.players.set(Vec::new());
         self.state.pot.set(Amount::ZERO);
+        self.state.total_buy_ins.set(Amount::ZERO);
         self.state.current_bet.set(Amount::ZERO);
         self.state
             .min_raise
             .set(Amount::from_tokens(arg.big_blind.into())); // Min raise = big blind
         self.state.community_cards.set(Vec::new());
+        self.state.board_positions.set(Vec::new());
+        self.state.up_cards.set(Vec::new());
+        self.state.draws.set(Vec::new());
+        self.state.deck_position.set(0);
         self.state.turn_seat.set(None);
-        self.state.winner.set(None);
+        self.state.winners.set(Vec::new());
+        self.state.pots.set(Vec::new());
+        self.state.awards.set(Vec::new());
+        self.state.uncalled_bet.set(None);
         self.state
             .min_stake
             .set(Amount::from_tokens(arg.min_stake.into()));
         self.state
             .max_stake
             .set(Amount::from_tokens(arg.max_stake.into()));
+        // A mixed game seats no more players than its most restrictive variant
+        let variants = if arg.mixed_games.is_empty() {
+            vec![arg.variant]
+        } else {
+            arg.mixed_games.clone()
+        };
+        let seats = variants
+            .iter()
+            .map(|variant| variant.rules().max_players())
+            .min()
+            .unwrap_or(Seat::MAX_SEATS);
+        self.state
+            .max_players
+            .set(arg.max_players.clamp(2, seats as u8));
+        // A tournament plays hands back to back, but stacks are tournament chips
+        self.state.cash_game.set(arg.cash_game && arg.tournament.is_none());
+        self.state.betting.set(arg.betting);
+        self.state.variant.set(variants[0]);
+        self.state.mixed_games.set(arg.mixed_games);
+        self.state.mixed_game_index.set(0);
+        self.state.orbit_hands.set(0);
+        self.state.raises_this_street.set(0);
+        self.state.ante.set(Amount::from_tokens(arg.ante.into()));
+        self.state.big_blind_ante.set(arg.big_blind_ante);
+        self.state
+            .straddle
+            .set(Amount::from_tokens(arg.straddle.into()));
         self.state.revealed_cards.set(Vec::new());
         // Initialize blinds
         self.state
@@ -68,16 +117,51 @@ impl Contract for TableContract {
             .big_blind
             .set(Amount::from_tokens(arg.big_blind.into()));
         self.state.dealer_button.set(None);
+        // Tournament blinds come from the first level of the schedule
+        self.state.tournament.set(arg.tournament);
+        self.state.prize_pool.set(Amount::ZERO);
+        self.state.hands_played.set(0);
+        self.state.tournament_start_block.set(None);
+        self.state.finishes.set(Vec::new());
+        self.state.departures.set(Vec::new());
+        self.state.reserved_seats.set(Vec::new());
+        self.state.deal_votes.set(Vec::new());
+        self.set_blind_level(0);
+        // Tournament chips aren't raked
+        let raked = self.state.tournament.get().is_none();
+        self.state.rake.set(arg.rake.filter(|_| raked));
+        self.state.hand_rake.set(Amount::ZERO);
+        self.state.rake_collected.set(Amount::ZERO);
+        self.state.rake_owed.set(Amount::ZERO);
+        self.state.jackpot.set(Amount::ZERO);
+        self.state.jackpot_hit.set(None);
+        self.state.jackpot_awards.set(Vec::new());
+        self.state.entropy_commitments.set(Vec::new());
+        self.state.entropy_reveals.set(Vec::new());
+        self.state.entropy_start_block.set(None);
+        self.state.dealt_in.set(Vec::new());
+        // Only flop games are dealt by the players: every card comes off the
+        // one deck they shuffle before the hand
+        let flop_games = variants.iter().all(|variant| variant.rules().board_cards() > 0);
+        self.state.dealerless.set(arg.dealerless && flop_games);
+        self.state.mental_deal.set(None);
+        self.state.mental_start_block.set(None);
     }
 
     async fn execute_operation(&mut self, operation: TableOperation) -> TableResult {
         match operation {
-            TableOperation::StartNewGame => {
-                self.start_new_game();
+            TableOperation::StartNewGame => match self.start_new_game() {
+                Ok(()) => TableResult::Success,
+                Err(error) => TableResult::Error(error),
+            },
+            TableOperation::ForceAdvance => {
+                self.advance_phase().await;
                 TableResult::Success
             }
-            TableOperation::ForceAdvance => {
-                self.advance_phase();
+            TableOperation::StartHand => {
+                if *self.state.phase.get() == GamePhase::WaitingForPlayers {
+                    self.deal_cards().await;
+                }
                 TableResult::Success
             }
 
@@ -86,8 +170,11 @@ impl Contract for TableContract {
                 player_chain,
                 stake,
                 hand_app_id,
+                entropy_commitment,
+                card_key,
             } => {
-                self.handle_join(player_chain, stake, hand_app_id).await;
+                self.handle_join(player_chain, stake, hand_app_id, entropy_commitment, card_key)
+                    .await;
                 TableResult::Success
             }
             TableOperation::RelayBetAction {
@@ -101,6 +188,17 @@ impl Contract for TableContract {
                     .await;
                 TableResult::Success
             }
+            TableOperation::RelayDraw {
+                player_chain,
+                game_id: _,
+                discarded,
+            } => {
+                // Use current game_id from state instead of passed value
+                let current_game_id = *self.state.game_id.get();
+                self.handle_draw(player_chain, current_game_id, discarded)
+                    .await;
+                TableResult::Success
+            }
             TableOperation::RelayRevealCards {
                 player_chain,
                 game_id: _,
@@ -124,12 +222,91 @@ impl Contract for TableContract {
                 // Acknowledgment only
                 TableResult::Success
             }
+            TableOperation::RelayRevealEntropy {
+                player_chain,
+                game_id,
+                entropy,
+                next_commitment,
+            } => {
+                self.handle_reveal_entropy(player_chain, game_id, entropy, next_commitment)
+                    .await;
+                TableResult::Success
+            }
+            TableOperation::RelayMental {
+                player_chain,
+                game_id,
+                response,
+            } => {
+                self.handle_mental(player_chain, game_id, response).await;
+                TableResult::Success
+            }
 
             // Timeout & Liveness operations (Phase 3)
             TableOperation::TriggerTimeoutCheck { game_id } => {
                 self.handle_timeout_check(game_id).await;
                 TableResult::Success
             }
+
+            // Cash-game session operations
+            TableOperation::RelaySitOut { player_chain } => {
+                self.handle_sit_out(player_chain);
+                TableResult::Success
+            }
+            TableOperation::RelaySitIn { player_chain } => {
+                self.handle_sit_in(player_chain).await;
+                TableResult::Success
+            }
+            TableOperation::RelayTopUp {
+                player_chain,
+                amount,
+            } => {
+                self.handle_top_up(player_chain, amount);
+                TableResult::Success
+            }
+            TableOperation::RelayCashOut { player_chain } => {
+                self.handle_cash_out(player_chain);
+                TableResult::Success
+            }
+            TableOperation::RelayAcceptDeal { player_chain, kind } => {
+                self.handle_accept_deal(player_chain, kind);
+                TableResult::Success
+            }
+
+            // Multi-table tournament operations
+            TableOperation::RelayReserveSeat {
+                player_chain,
+                chips,
+            } => {
+                if !self.handle_reserve_seat(player_chain, chips) {
+                    return TableResult::Error(TableError::NotCoordinator);
+                }
+                TableResult::Success
+            }
+            TableOperation::RelaySeatPlayer {
+                player_chain,
+                hand_app_id,
+                entropy_commitment,
+                card_key,
+            } => {
+                self.handle_seat_player(player_chain, hand_app_id, entropy_commitment, card_key)
+                    .await;
+                TableResult::Success
+            }
+            TableOperation::RelayMovePlayer { player_chain } => {
+                if self.from_coordinator() {
+                    self.handle_move_player(player_chain);
+                }
+                TableResult::Success
+            }
+            TableOperation::RelaySetBlindLevel { level } => {
+                if self.from_coordinator() {
+                    self.state.blind_level.set(level);
+                }
+                TableResult::Success
+            }
+            TableOperation::ReportTournament => {
+                TableResult::TournamentReport(self.tournament_report())
+            }
         }
     }
 
@@ -141,8 +318,14 @@ impl Contract for TableContract {
 
         match message {
             // INCOMING messages from Hand chains
-            Message::JoinTable { stake, hand_app_id } => {
-                self.handle_join(source_chain, stake, hand_app_id).await;
+            Message::JoinTable {
+                stake,
+                hand_app_id,
+                entropy_commitment,
+                card_key,
+            } => {
+                self.handle_join(source_chain, stake, hand_app_id, entropy_commitment, card_key)
+                    .await;
             }
             Message::CardsReceived { game_id: _ } => {
                 // Acknowledgment only
@@ -150,6 +333,9 @@ impl Contract for TableContract {
             Message::BetAction { game_id, action } => {
                 self.handle_bet_action(source_chain, game_id, action).await;
             }
+            Message::Draw { game_id, discarded } => {
+                self.handle_draw(source_chain, game_id, discarded).await;
+            }
             Message::RevealCards {
                 game_id,
                 cards,
@@ -176,43 +362,174 @@ impl Contract for TableContract {
                 self.handle_timeout_check(game_id).await;
             }
 
+            // Cash-game session messages
+            Message::SitOut => {
+                self.handle_sit_out(source_chain);
+            }
+            Message::SitIn => {
+                self.handle_sit_in(source_chain).await;
+            }
+            Message::TopUp { amount } => {
+                self.handle_top_up(source_chain, amount);
+            }
+            Message::CashOut => {
+                self.handle_cash_out(source_chain);
+            }
+
+            // Tournament messages
+            Message::AcceptDeal { kind } => {
+                self.handle_accept_deal(source_chain, kind);
+            }
+
+            // Deck entropy
+            Message::RevealEntropy {
+                game_id,
+                entropy,
+                next_commitment,
+            } => {
+                self.handle_reveal_entropy(source_chain, game_id, entropy, next_commitment)
+                    .await;
+            }
+
+            // Dealerless shuffle
+            Message::MentalResponse { game_id, response } => {
+                self.handle_mental(source_chain, game_id, response).await;
+            }
+
             // OUTGOING messages (shouldn't be received)
             _ => {}
         }
     }
 
     async fn store(mut self) {
+        // Never persist a state where chips were created or lost
+        assert!(
+            self.ledger_balanced(),
+            "Chip ledger out of balance: stacks plus pot must equal total buy-ins"
+        );
         self.state.save().await.expect("Failed to save state");
     }
 }
 
 impl TableContract {
     /// Handle player joining
-    async fn handle_join(&mut self, player_chain: ChainId, stake: Amount, hand_app: ApplicationId) {
-        let phase = self.state.phase.get();
-        if *phase != GamePhase::WaitingForPlayers {
-            return;
+    ///
+    /// A tournament with a token app only seats players whose buy-in was paid
+    /// to this chain through it, and refunds a paid buy-in it turns down.
+    async fn handle_join(
+        &mut self,
+        player_chain: ChainId,
+        stake: Amount,
+        hand_app: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    ) {
+        if !self.claim_tournament_tokens(player_chain, stake) {
+            return; // Nothing was paid into the prize pool's account
         }
 
-        let mut players = self.state.players.get().clone();
-        if players.iter().any(|p| p.chain_id == player_chain) {
+        let cash_game = *self.state.cash_game.get();
+        let in_hand = *self.state.phase.get() != GamePhase::WaitingForPlayers;
+        if !self.seat_joining_player(player_chain, stake, hand_app, entropy_commitment, card_key) {
+            self.pay_tournament_tokens(player_chain, stake);
             return;
         }
-
-        let min_stake = *self.state.min_stake.get();
-        let max_stake = *self.state.max_stake.get();
-        if stake < min_stake || stake > max_stake {
+        if in_hand {
             return;
         }
 
-        if players.len() >= 2 {
-            return;
+        // Once every seat is taken, start dealing
+        // (cash games start as soon as two players can be dealt in)
+        let players = self.state.players.get();
+        let max_players = *self.state.max_players.get() as usize;
+        if players.len() == max_players || (cash_game && Self::dealt_in_count(players) >= 2) {
+            self.deal_cards().await;
         }
+    }
 
-        let seat = if players.is_empty() {
-            Seat::Player1
-        } else {
-            Seat::Player2
+    /// Seat a joining player for their stake; returns false if they are turned away
+    fn seat_joining_player(
+        &mut self,
+        player_chain: ChainId,
+        stake: Amount,
+        hand_app: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    ) -> bool {
+        // Cash games seat new players mid-hand; they are dealt in from the next hand
+        let cash_game = *self.state.cash_game.get();
+        let in_hand = *self.state.phase.get() != GamePhase::WaitingForPlayers;
+        if in_hand && !cash_game {
+            return false;
+        }
+
+        // Tournaments seat players for the exact buy-in, until the first hand is dealt
+        // (multi-table tournament players are seated by the coordinator)
+        let tournament = self.state.tournament.get().clone();
+        let chips = match &tournament {
+            Some(tournament) => {
+                if tournament.coordinator.is_some()
+                    || self.state.tournament_start_block.get().is_some()
+                    || stake != Amount::from_tokens(tournament.buy_in.into())
+                {
+                    return false;
+                }
+                Amount::from_tokens(tournament.starting_chips.into())
+            }
+            None => {
+                let min_stake = *self.state.min_stake.get();
+                let max_stake = *self.state.max_stake.get();
+                if stake < min_stake || stake > max_stake {
+                    return false;
+                }
+                stake
+            }
+        };
+
+        // Buy-in stays in the player's stack until it is bet
+        // (a tournament buy-in goes to the prize pool and the stack is chips)
+        if !self.take_seat(player_chain, hand_app, chips, entropy_commitment, card_key) {
+            return false;
+        }
+        if tournament.is_some() {
+            let prize_pool = self.state.prize_pool.get().saturating_add(stake);
+            self.state.prize_pool.set(prize_pool);
+        }
+        true
+    }
+
+    /// Seat a player in the lowest free seat with `chips` in their stack,
+    /// holding `entropy_commitment` for the first hand they are dealt into
+    /// and `card_key` to seal their cards to
+    ///
+    /// Players seated mid-hand are dealt in from the next hand. Returns false
+    /// if the player is already seated, the table is full or the card key
+    /// isn't a usable public key.
+    fn take_seat(
+        &mut self,
+        player_chain: ChainId,
+        hand_app: ApplicationId,
+        chips: Amount,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    ) -> bool {
+        let mut players = self.state.players.get().clone();
+        if players.iter().any(|p| p.chain_id == player_chain) || !is_card_key(&card_key) {
+            return false;
+        }
+
+        let max_players = *self.state.max_players.get() as usize;
+        if players.len() >= max_players {
+            return false;
+        }
+
+        // Take the lowest free seat
+        let seat = match Seat::ALL[..max_players]
+            .iter()
+            .find(|seat| players.iter().all(|p| p.seat != **seat))
+        {
+            Some(seat) => *seat,
+            None => return false,
         };
 
         let owner = self
@@ -220,26 +537,39 @@ impl TableContract {
             .authenticated_signer()
             .unwrap_or(AccountOwner::CHAIN);
 
+        let in_hand = *self.state.phase.get() != GamePhase::WaitingForPlayers;
         players.push(PlayerInfo {
             seat,
             chain_id: player_chain,
             owner,
-            stake,
+            stake: chips,
+            stack: chips,
             hand_app: Some(hand_app),
-            has_folded: false,
+            has_folded: in_hand,
             current_bet: Amount::ZERO,
             has_revealed: false,
+            has_acted: false,
+            total_contribution: Amount::ZERO,
+            sitting_out: false,
+            pending_top_up: Amount::ZERO,
+            cashing_out: false,
         });
 
-        let mut pot = *self.state.pot.get();
-        pot = pot.saturating_add(stake);
-        self.state.pot.set(pot);
-        self.state.players.set(players.clone());
+        let total_buy_ins = self.state.total_buy_ins.get().saturating_add(chips);
+        self.state.total_buy_ins.set(total_buy_ins);
+        self.state.players.set(players);
 
-        // If two players joined, start dealing
-        if players.len() == 2 {
-            self.deal_cards().await;
-        }
+        let mut commitments = self.state.entropy_commitments.get().clone();
+        commitments.retain(|(chain_id, _)| *chain_id != player_chain);
+        commitments.push((player_chain, entropy_commitment));
+        self.state.entropy_commitments.set(commitments);
+        let _ = self.state.card_keys.insert(&player_chain, card_key);
+        true
+    }
+
+    /// Number of seated players that will be dealt into the next hand
+    fn dealt_in_count(players: &[PlayerInfo]) -> usize {
+        players.iter().filter(|p| !p.sitting_out).count()
     }
 
     /// Deal cards to all players using ZK proofs
@@ -254,41 +584,224 @@ impl TableContract {
     async fn deal_cards(&mut self) {
         let mut players = self.state.players.get().clone();
 
-        // FIX #5: Need exactly 2 players to deal
-        if players.len() != 2 {
-            return; // Cannot deal without exactly 2 players
+        // FIX #5: Need at least 2 players to deal
+        if Self::dealt_in_count(&players) < 2 {
+            return;
         }
 
         self.state.phase.set(GamePhase::Dealing);
+        if self.state.tournament.get().is_some() {
+            self.raise_blinds();
+        }
 
-        // === STANDARD POKER: Assign dealer button ===
-        // Button alternates based on game_id (first game: Player1, second: Player2, etc.)
+        // Deal in clockwise seat order; sitting-out players keep their seat
+        // but sit this hand out as if they had folded
+        players.sort_by_key(|p| p.seat.index());
+        for player in &mut players {
+            player.has_folded = player.sitting_out;
+        }
+        let seats: Vec<Seat> = players
+            .iter()
+            .filter(|p| !p.sitting_out)
+            .map(|p| p.seat)
+            .collect();
+
+        // === STANDARD POKER: Move dealer button one seat clockwise ===
+        // First hand: button starts at the lowest occupied seat
         let game_id = *self.state.game_id.get();
-        let button = if game_id % 2 == 1 {
-            Seat::Player1
-        } else {
-            Seat::Player2
+        let button = match *self.state.dealer_button.get() {
+            Some(previous) => previous.next_in(&seats).unwrap_or(seats[0]),
+            None => seats[0],
         };
         self.state.dealer_button.set(Some(button));
+        self.rotate_variant(seats.len());
+        let rules = self.state.variant.get().rules();
+        let blinds = rules.uses_blinds();
 
         // === STANDARD POKER: Post blinds ===
+        // (stud has no blinds: the lowest up card brings it in once dealt)
+        // Heads-up: button posts SB and the other player posts BB.
+        // Otherwise SB and BB are the two seats clockwise from the button.
         let small_blind = *self.state.small_blind.get();
         let big_blind = *self.state.big_blind.get();
-        let bb_seat = button.other(); // Big blind is non-button player
+        let sb_seat = if seats.len() == 2 {
+            button
+        } else {
+            button.next_in(&seats).unwrap_or(button)
+        };
+        let bb_seat = sb_seat.next_in(&seats).unwrap_or(sb_seat);
 
-        // Find player indices
-        let button_idx = players.iter().position(|p| p.seat == button).unwrap();
-        let bb_idx = players.iter().position(|p| p.seat == bb_seat).unwrap();
+        // Optional straddle: the seat left of the big blind, three-handed or more
+        let straddle = *self.state.straddle.get();
+        let straddle_seat = if blinds && seats.len() >= 3 && straddle > big_blind {
+            bb_seat.next_in(&seats)
+        } else {
+            None
+        };
 
-        // Button posts small blind (from their stake)
-        players[button_idx].current_bet = small_blind;
-        // Non-button posts big blind (from their stake)
-        players[bb_idx].current_bet = big_blind;
+        // Antes: from every player, or once from the big blind (blind games only)
+        let ante = *self.state.ante.get();
+        let big_blind_ante = *self.state.big_blind_ante.get() && blinds;
+
+        let mut pot = Amount::ZERO;
+        for player in &mut players {
+            player.stake = player.stack;
+            player.has_acted = false;
+            player.current_bet = Amount::ZERO;
+            player.total_contribution = Amount::ZERO;
+            if player.sitting_out {
+                continue;
+            }
+            if !big_blind_ante || player.seat == bb_seat {
+                pot = pot.saturating_add(player.post_ante(ante));
+            }
+            let blind = if !blinds {
+                Amount::ZERO
+            } else if player.seat == sb_seat {
+                small_blind
+            } else if player.seat == bb_seat {
+                big_blind
+            } else if Some(player.seat) == straddle_seat {
+                straddle
+            } else {
+                Amount::ZERO
+            };
+            // Blinds move from the stack into the pot (a short stack posts what it has)
+            pot = pot.saturating_add(player.commit(blind));
+        }
 
-        // Set current bet to BB (pot already has stakes from handle_join)
-        // In heads-up, blinds are posted from stake, so pot remains unchanged
-        self.state.current_bet.set(big_blind);
+        // Set current bet to BB (or the straddle); the big blind counts as the
+        // street's first bet and a straddle as a raise
+        let betting = *self.state.betting.get();
+        let mut current_bet = big_blind;
+        let mut min_raise = betting.opening_min_raise(rules.first_street(), big_blind);
+        let mut raises = 1;
+        if straddle_seat.is_some() {
+            current_bet = straddle;
+            raises = 2;
+            if !matches!(betting, BettingStructure::FixedLimit { .. }) {
+                min_raise = straddle;
+            }
+        }
+        self.state.pot.set(pot);
+        self.state.current_bet.set(current_bet);
+        self.state.min_raise.set(min_raise);
+        self.state.raises_this_street.set(raises);
         self.state.players.set(players.clone());
+        self.state.dealt_in.set(seats.clone());
+
+        // === STANDARD POKER: Pre-flop, seat after the BB acts first ===
+        // (heads-up this is the button, who posted the SB).
+        // With a straddle the seat after the straddler starts, and the
+        // straddler acts last.
+        if blinds {
+            let last_forced = straddle_seat.unwrap_or(bb_seat);
+            self.state
+                .turn_seat
+                .set(Some(last_forced.next_in(&seats).unwrap_or(button)));
+        }
+
+        // Dealerless: the players shuffle the deck themselves instead
+        if *self.state.dealerless.get() {
+            self.start_mental_deal(game_id, &seats);
+            return;
+        }
+
+        // The forced bets are in: ask every player dealt in for their entropy.
+        // The deck is shuffled once they have all revealed.
+        self.state.entropy_reveals.set(Vec::new());
+        let current_block = self.runtime.block_height().0;
+        self.state.entropy_start_block.set(Some(current_block));
+        let mut commitments = self.state.entropy_commitments.get().clone();
+        commitments.retain(|(chain_id, _)| players.iter().any(|p| p.chain_id == *chain_id));
+        self.state.entropy_commitments.set(commitments);
+        for player in players.iter().filter(|p| !p.sitting_out) {
+            if player.hand_app.is_some() {
+                self.runtime
+                    .prepare_message(Message::RequestEntropy { game_id })
+                    .with_authentication()
+                    .send_to(player.chain_id);
+            }
+        }
+    }
+
+    /// Shuffle and deal once every player still in the hand has revealed the
+    /// entropy they committed to
+    async fn deal_if_revealed(&mut self) {
+        if *self.state.phase.get() != GamePhase::Dealing {
+            return;
+        }
+        let dealt_in = self.state.dealt_in.get();
+        let reveals = self.state.entropy_reveals.get();
+        let waiting = self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| !p.has_folded && dealt_in.contains(&p.seat))
+            .any(|p| reveals.iter().all(|(seat, _)| *seat != p.seat));
+        if !waiting {
+            self.shuffle_and_deal().await;
+        }
+    }
+
+    /// Player reveals the entropy they committed to for this hand, and
+    /// commits to the entropy for their next one
+    async fn handle_reveal_entropy(
+        &mut self,
+        player_chain: ChainId,
+        game_id: u64,
+        entropy: [u8; 32],
+        next_commitment: [u8; 32],
+    ) {
+        if game_id != *self.state.game_id.get()
+            || *self.state.phase.get() != GamePhase::Dealing
+            || self.state.mental_deal.get().is_some()
+        {
+            return;
+        }
+
+        let seat = match self
+            .state
+            .players
+            .get()
+            .iter()
+            .find(|p| p.chain_id == player_chain && !p.has_folded)
+        {
+            Some(player) => player.seat,
+            None => return,
+        };
+        let mut reveals = self.state.entropy_reveals.get().clone();
+        if !self.state.dealt_in.get().contains(&seat) || reveals.iter().any(|(s, _)| *s == seat) {
+            return;
+        }
+
+        // Only the entropy behind the commitment counts
+        let mut commitments = self.state.entropy_commitments.get().clone();
+        let commitment = match commitments.iter_mut().find(|(c, _)| *c == player_chain) {
+            Some((_, commitment)) => commitment,
+            None => return,
+        };
+        if *commitment != entropy_commitment(&entropy) {
+            return;
+        }
+        *commitment = next_commitment;
+        reveals.push((seat, entropy));
+        self.state.entropy_commitments.set(commitments);
+        self.state.entropy_reveals.set(reveals);
+
+        self.deal_if_revealed().await;
+    }
+
+    /// Shuffle the deck with the players' entropy and deal the hand
+    async fn shuffle_and_deal(&mut self) {
+        let mut players = self.state.players.get().clone();
+        players.sort_by_key(|p| p.seat.index());
+        let seats = self.state.dealt_in.get().clone();
+        let game_id = *self.state.game_id.get();
+        let rules = self.state.variant.get().rules();
+        let blinds = rules.uses_blinds();
+        self.state.entropy_start_block.set(None);
 
         // =====================================================================
         // PHASE 3: ZK-SNARK CARD DEALING
@@ -296,21 +809,36 @@ impl TableContract {
 
         // 1. Generate and shuffle the deck
         let seed = self.generate_deck_seed();
-        let deck = shuffle_deck(&seed);
+        let deck = rules.shuffle(&seed);
         self.state.deck_seed.set(seed);
 
         // 2. Build Merkle tree root of the shuffled deck
-        let deck_root = Self::build_merkle_root(&deck);
+        let deck_root = deck_merkle_root(&self.deck_leaves(&deck));
         self.state.deck_root.set(deck_root);
 
+        // Stud deals street by street and opens with the bring-in
+        if !blinds {
+            let street = rules.first_street();
+            self.state.deck_position.set(0);
+            self.deal_street(street).await;
+            self.post_bring_in(street, &seats);
+            self.state.phase.set(street);
+
+            let current_block = self.runtime.block_height().0;
+            self.state.turn_start_block.set(current_block);
+            self.notify_turn().await;
+            return;
+        }
+
         // 3. For each player, create ZK dealing proof and send cards
-        for (idx, player) in players.iter().enumerate() {
-            let card1 = deck[idx * 2];
-            let card2 = deck[idx * 2 + 1];
-            let cards = [card1, card2];
+        let hole_count = rules.hole_cards();
+        for (idx, player) in players.iter().filter(|p| seats.contains(&p.seat)).enumerate() {
+            let cards = &deck[idx * hole_count..(idx + 1) * hole_count];
 
-            // Generate Pedersen commitments for the cards
-            let (commitments, _blinding_factors) = self.commit_cards(&cards, game_id);
+            // Generate Pedersen commitments for the cards, with their
+            // openings sealed to the player's card key
+            let (commitments, sealed_openings) =
+                self.commit_cards(player.chain_id, cards, 0, game_id).await;
 
             // Store commitments for later verification during reveal
             let _ = self.state.player_commitments
@@ -319,9 +847,10 @@ impl TableContract {
             // Create ZK dealing proof
             // Phase 3: Mock proof - Phase 4 will use real Groth16
             let dealing_proof = DealingProof {
-                proof: vec![0u8; DealingProof::PROOF_SIZE],  // Mock 192-byte proof
-                card_commitments: [commitments[0].clone(), commitments[1].clone()],
+                proof: vec![0u8; proof_size_for(hole_count)],  // Mock 192 bytes per card pair
+                card_commitments: commitments,
                 deck_root,
+                sealed_openings,
             };
 
             // Send ZK message to player's hand contract
@@ -336,17 +865,40 @@ impl TableContract {
             }
         }
 
-        // Store community cards (flop, turn, river)
-        self.state.community_cards.set(deck[4..9].to_vec());
-
-        // Move to pre-flop
-        self.state.phase.set(GamePhase::PreFlop);
+        // Commit to the board (flop, turn, river) right after the hole cards;
+        // its cards are opened street by street
+        let board_start = seats.len() * hole_count;
+        let board_end = board_start + rules.board_cards();
+        self.state
+            .board_positions
+            .set((board_start as u8..board_end as u8).collect());
+        // A draw deals replacements from the rest of the deck
+        self.state.deck_position.set(board_end as u8);
 
-        // === STANDARD POKER: Pre-flop, BUTTON acts first (SB) ===
-        self.state.turn_seat.set(Some(button));
+        self.start_first_street().await;
+    }
 
-        // Initialize action counter for first betting round
-        self.state.actions_this_round.set(0);
+    /// Open the first betting round once the hole cards are out
+    async fn start_first_street(&mut self) {
+        // Move to pre-flop
+        let rules = self.state.variant.get().rules();
+        self.state.phase.set(rules.first_street());
+
+        // A player who forfeited while the table waited on the deal is skipped
+        let seats = self.state.dealt_in.get();
+        let in_hand: Vec<Seat> = self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| !p.has_folded && seats.contains(&p.seat))
+            .map(|p| p.seat)
+            .collect();
+        if let Some(seat) = *self.state.turn_seat.get() {
+            if !in_hand.contains(&seat) {
+                self.state.turn_seat.set(seat.next_in(&in_hand));
+            }
+        }
 
         // Record turn start for timeout tracking
         let current_block = self.runtime.block_height().0;
@@ -355,118 +907,579 @@ impl TableContract {
         self.notify_turn().await;
     }
 
-    /// Handle betting action
-    async fn handle_bet_action(&mut self, player_chain: ChainId, game_id: u64, action: BetAction) {
+    // ========================================================================
+    // DEALERLESS SHUFFLE (Mental Poker)
+    // ========================================================================
+
+    /// Dealerless: have the players dealt in deal the hand among themselves,
+    /// starting with a key share from each
+    fn start_mental_deal(&mut self, game_id: u64, seats: &[Seat]) {
+        let rules = self.state.variant.get().rules();
+        let deal = MentalDeal::new(
+            mental_context(self.runtime.chain_id(), game_id),
+            rules.deck(),
+            seats.to_vec(),
+            rules.hole_cards(),
+        );
+        self.state.mental_deal.set(Some(deal));
+        let current_block = self.runtime.block_height().0;
+        self.state.mental_start_block.set(Some(current_block));
+        self.send_mental_request(seats, MentalRequest::Key { game_id });
+    }
+
+    /// Send a dealerless request to the players in these seats
+    fn send_mental_request(&mut self, seats: &[Seat], request: MentalRequest) {
+        for player in self.state.players.get().iter() {
+            if seats.contains(&player.seat) && player.hand_app.is_some() {
+                self.runtime
+                    .prepare_message(Message::MentalRequest {
+                        request: request.clone(),
+                    })
+                    .with_authentication()
+                    .send_to(player.chain_id);
+            }
+        }
+    }
+
+    /// Dealerless: take a player's key share, shuffle or decryption shares
+    /// and move the deal on
+    ///
+    /// Anything whose proof doesn't hold is ignored; a player who never sends
+    /// a valid one is forfeited once the step times out.
+    async fn handle_mental(
+        &mut self,
+        player_chain: ChainId,
+        game_id: u64,
+        response: MentalResponse,
+    ) {
         if game_id != *self.state.game_id.get() {
             return;
         }
-
-        let mut players = self.state.players.get().clone();
-        let player_idx = match players.iter().position(|p| p.chain_id == player_chain) {
-            Some(idx) => idx,
+        let mut deal = match self.state.mental_deal.get().clone() {
+            Some(deal) => deal,
+            None => return,
+        };
+        let seat = match self
+            .state
+            .players
+            .get()
+            .iter()
+            .find(|p| p.chain_id == player_chain)
+        {
+            Some(player) => player.seat,
             None => return,
         };
 
-        let player_seat = players[player_idx].seat;
-        if self.state.turn_seat.get() != &Some(player_seat) {
+        // Key shares and shuffles only while dealing; shares until the hand ends
+        let dealing = *self.state.phase.get() == GamePhase::Dealing;
+        let step = matches!(response, MentalResponse::Key(_) | MentalResponse::Shuffle { .. });
+        let accepted = match response {
+            MentalResponse::Key(share) => dealing && deal.add_key(seat, player_chain, &share),
+            MentalResponse::Shuffle { deck, proof } => {
+                dealing && deal.add_shuffle(seat, deck, &proof)
+            }
+            MentalResponse::Shares(shares) => deal.add_shares(seat, &shares),
+        };
+        if !accepted {
             return;
         }
-
-        let phase = *self.state.phase.get();
-        match phase {
-            GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn | GamePhase::River => {}
-            _ => return,
+        self.state.mental_deal.set(Some(deal));
+        if self.state.mental_start_block.get().is_some() {
+            let current_block = self.runtime.block_height().0;
+            self.state.mental_start_block.set(Some(current_block));
         }
 
-        let mut pot = *self.state.pot.get();
-        let mut current_bet = *self.state.current_bet.get();
-        let min_raise = *self.state.min_raise.get();
+        if step {
+            self.request_mental_step();
+        } else {
+            self.open_mental_cards().await;
+        }
+    }
 
-        match action {
-            BetAction::Check => {
-                if current_bet > players[player_idx].current_bet {
-                    return;
-                }
+    /// Dealerless: ask for the next shuffle, or once the deck is shuffled,
+    /// for every player's shares of the other players' hole cards
+    fn request_mental_step(&mut self) {
+        let deal = match self.state.mental_deal.get().clone() {
+            Some(deal) => deal,
+            None => return,
+        };
+        let game_id = *self.state.game_id.get();
+        if let (Some(seat), Some(joint_key)) = (deal.next_shuffler(), deal.joint_key()) {
+            let request = MentalRequest::Shuffle {
+                game_id,
+                joint_key,
+                deck: deal.deck.clone(),
+            };
+            self.send_mental_request(&[seat], request);
+        } else if deal.is_shuffled() {
+            for seat in &deal.seats {
+                let positions: Vec<u8> = deal
+                    .seats
+                    .iter()
+                    .filter(|owner| *owner != seat)
+                    .flat_map(|owner| deal.hole_positions(*owner))
+                    .collect();
+                let cards = deal.cards_at(&positions);
+                self.send_mental_request(&[*seat], MentalRequest::Shares { game_id, cards });
             }
-            BetAction::Call => {
-                let to_call = current_bet.saturating_sub(players[player_idx].current_bet);
-                players[player_idx].current_bet = current_bet;
-                pot = pot.saturating_add(to_call);
+        }
+    }
+
+    /// Dealerless: open whatever the shares in so far allow, and play on
+    ///
+    /// Hole cards go to their owner with every other share removed, and the
+    /// first betting round starts once every player has theirs. A street's
+    /// board cards are opened for everyone before its betting starts. At
+    /// showdown, each player's own shares open their hole cards for the
+    /// table.
+    async fn open_mental_cards(&mut self) {
+        let mut deal = match self.state.mental_deal.get().clone() {
+            Some(deal) => deal,
+            None => return,
+        };
+        let game_id = *self.state.game_id.get();
+        let phase = *self.state.phase.get();
+
+        if phase == GamePhase::Dealing {
+            for owner in deal.seats.clone() {
+                if deal.delivered.contains(&owner) {
+                    continue;
+                }
+                let cards: Option<Vec<_>> = deal
+                    .hole_positions(owner)
+                    .iter()
+                    .map(|position| deal.partial_open(*position, owner))
+                    .collect();
+                let cards = match cards {
+                    Some(cards) => cards,
+                    None => continue,
+                };
+                let chain_id = self
+                    .state
+                    .players
+                    .get()
+                    .iter()
+                    .find(|p| p.seat == owner && p.hand_app.is_some())
+                    .map(|p| p.chain_id);
+                if let Some(chain_id) = chain_id {
+                    self.runtime
+                        .prepare_message(Message::MentalHoleCards { game_id, cards })
+                        .with_authentication()
+                        .send_to(chain_id);
+                }
+                deal.delivered.push(owner);
             }
-            BetAction::Raise(amount) => {
-                if amount < min_raise {
-                    return;
+            let delivered = deal.delivered.len() == deal.seats.len();
+            self.state.mental_deal.set(Some(deal));
+            if delivered {
+                self.state.mental_start_block.set(None);
+                self.start_first_street().await;
+            }
+        } else if phase == GamePhase::Showdown {
+            let mut players = self.state.players.get().clone();
+            let mut revealed = self.state.revealed_cards.get().clone();
+            for player in players.iter_mut().filter(|p| !p.has_folded && !p.has_revealed) {
+                let cards: Option<Vec<Card>> = deal
+                    .hole_positions(player.seat)
+                    .iter()
+                    .map(|position| deal.open(*position))
+                    .collect();
+                if let Some(cards) = cards {
+                    player.has_revealed = true;
+                    revealed.push((player.seat, cards));
                 }
+            }
+            self.state.revealed_cards.set(revealed);
+            self.state.players.set(players.clone());
+            if players.iter().all(|p| p.has_folded || p.has_revealed) {
+                self.determine_winner();
+                self.settle_game(false).await;
+            }
+        } else if self.board_pending() {
+            let (from, to) = self.board_to_open();
+            let board: Option<Vec<Card>> = deal
+                .board_positions(from, to)
+                .iter()
+                .map(|position| deal.open(*position))
+                .collect();
+            let board = match board {
+                Some(board) => board,
+                None => return,
+            };
+            let mut community = self.state.community_cards.get().clone();
+            community.extend(board);
+            self.state.community_cards.set(community);
+            self.state.mental_start_block.set(None);
+
+            self.set_first_to_act();
+            if self.betting_closed() {
+                self.close_street().await;
+            } else {
+                self.notify_turn().await;
+            }
+        }
+    }
+
+    /// Dealerless: board cards showing so far, and how many the street needs
+    fn board_to_open(&self) -> (usize, usize) {
+        let rules = self.state.variant.get().rules();
+        (
+            self.state.community_cards.get().len(),
+            rules.board_dealt(*self.state.phase.get()),
+        )
+    }
+
+    /// Dealerless: the street's board cards aren't open yet
+    fn board_pending(&self) -> bool {
+        let (from, to) = self.board_to_open();
+        self.state.mental_deal.get().is_some()
+            && self.state.phase.get().is_betting_round()
+            && from < to
+    }
+
+    /// Dealerless: ask every player that hasn't shared the street's board
+    /// cards for their shares
+    fn request_board_shares(&mut self) {
+        let deal = match self.state.mental_deal.get().clone() {
+            Some(deal) => deal,
+            None => return,
+        };
+        let (from, to) = self.board_to_open();
+        let positions = deal.board_positions(from, to);
+        let cards = deal.cards_at(&positions);
+        let game_id = *self.state.game_id.get();
+        let owing = deal.board_owed(from, to);
+        self.send_mental_request(&owing, MentalRequest::Shares { game_id, cards });
+        let current_block = self.runtime.block_height().0;
+        self.state.mental_start_block.set(Some(current_block));
+    }
+
+    /// Dealerless: ask a player who folded for their shares of every board
+    /// card not yet open
+    fn request_folded_shares(&mut self, seat: Seat) {
+        let deal = match self.state.mental_deal.get().clone() {
+            Some(deal) => deal,
+            None => return,
+        };
+        let rules = self.state.variant.get().rules();
+        let from = self.state.community_cards.get().len();
+        let positions = deal.board_positions(from, rules.board_cards());
+        if positions.is_empty() {
+            return;
+        }
+        let request = MentalRequest::Shares {
+            game_id: *self.state.game_id.get(),
+            cards: deal.cards_at(&positions),
+        };
+        self.send_mental_request(&[seat], request);
+    }
 
-                // FIX #6: HIGH - Validate bet against player's available stack
-                let player_remaining = players[player_idx]
-                    .stake
-                    .saturating_sub(players[player_idx].current_bet);
-                let new_bet = current_bet.saturating_add(amount);
-                let required = new_bet.saturating_sub(players[player_idx].current_bet);
+    /// Deal every player still in the hand the down and up cards of `street`,
+    /// in games dealt street by street (stud)
+    ///
+    /// Cards come off the shuffled deck one round at a time, clockwise from the
+    /// lowest seat. Up cards are public at the table; down cards are committed
+    /// and sent to the player's hand with a ZK dealing proof, like hole cards.
+    async fn deal_street(&mut self, street: GamePhase) {
+        let rules = self.state.variant.get().rules();
+        let (down, up) = rules.street_cards(street);
+        if down + up == 0 {
+            return;
+        }
 
-                if required > player_remaining {
-                    return; // Cannot bet more than available stack
+        let deck = rules.shuffle(self.state.deck_seed.get());
+        let deck_root = *self.state.deck_root.get();
+        let game_id = *self.state.game_id.get();
+        let mut players: Vec<PlayerInfo> = self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| !p.has_folded)
+            .cloned()
+            .collect();
+        players.sort_by_key(|p| p.seat.index());
+
+        // At most seven players, so seven cards each never run past the deck
+        let mut position = *self.state.deck_position.get() as usize;
+        let mut down_cards: Vec<Vec<Card>> = vec![Vec::new(); players.len()];
+        let mut up_cards = self.state.up_cards.get().clone();
+        for round in 0..down + up {
+            for (idx, player) in players.iter().enumerate() {
+                let card = deck[position];
+                position += 1;
+                if round < down {
+                    down_cards[idx].push(card);
+                } else if let Some((_, cards)) =
+                    up_cards.iter_mut().find(|(seat, _)| *seat == player.seat)
+                {
+                    cards.push(card);
+                } else {
+                    up_cards.push((player.seat, vec![card]));
                 }
+            }
+        }
+        self.state.deck_position.set(position as u8);
+        self.state.up_cards.set(up_cards);
+
+        for (player, cards) in players.iter().zip(down_cards) {
+            if cards.is_empty() {
+                continue;
+            }
+
+            // Later streets add to the commitments made on third street
+            let mut held = if street == rules.first_street() {
+                Vec::new()
+            } else {
+                self.state
+                    .player_commitments
+                    .get(&player.chain_id)
+                    .await
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default()
+            };
+            let (commitments, sealed_openings) = self
+                .commit_cards(player.chain_id, &cards, held.len(), game_id)
+                .await;
+            held.extend(commitments.iter().cloned());
+            let _ = self.state.player_commitments.insert(&player.chain_id, held);
+
+            // Phase 3: Mock proof - Phase 4 will use real Groth16
+            let dealing_proof = DealingProof {
+                proof: vec![0u8; proof_size_for(cards.len())],
+                card_commitments: commitments,
+                deck_root,
+                sealed_openings,
+            };
 
-                let addition = new_bet.saturating_sub(players[player_idx].current_bet);
-                players[player_idx].current_bet = new_bet;
-                current_bet = new_bet;
-                pot = pot.saturating_add(addition);
+            if player.hand_app.is_some() {
+                self.runtime
+                    .prepare_message(Message::DealCardsZK {
+                        game_id,
+                        dealing_proof,
+                    })
+                    .with_authentication()
+                    .send_to(player.chain_id);
+            }
+        }
+    }
+
+    /// Stud: the lowest up card (the highest in razz) posts the bring-in
+    /// (the small blind amount) and the seat on its left acts first
+    fn post_bring_in(&mut self, street: GamePhase, seats: &[Seat]) {
+        let door_cards: Vec<(Seat, Card)> = self
+            .state
+            .up_cards
+            .get()
+            .iter()
+            .filter_map(|(seat, cards)| cards.first().map(|card| (*seat, *card)))
+            .collect();
+        let seat = match self.state.variant.get().rules().bring_in(&door_cards) {
+            Some(seat) => seat,
+            None => return,
+        };
+
+        let bring_in = *self.state.small_blind.get();
+        let mut players = self.state.players.get().clone();
+        let mut pot = *self.state.pot.get();
+        if let Some(player) = players.iter_mut().find(|p| p.seat == seat) {
+            pot = pot.saturating_add(player.commit(bring_in));
+            // If everyone just calls, the street ends without the bring-in acting again
+            player.has_acted = true;
+        }
+
+        let big_blind = *self.state.big_blind.get();
+        let min_raise = self
+            .state
+            .betting
+            .get()
+            .opening_min_raise(street, big_blind);
+        self.state.pot.set(pot);
+        self.state.players.set(players);
+        self.state.current_bet.set(bring_in);
+        self.state.min_raise.set(min_raise);
+        self.state.raises_this_street.set(0);
+        self.state
+            .turn_seat
+            .set(Some(seat.next_in(seats).unwrap_or(seat)));
+    }
+
+    /// Mixed game: move on to the next variant once every player dealt in
+    /// has had the button, then count this hand toward the orbit
+    fn rotate_variant(&mut self, players: usize) {
+        let rotation = self.state.mixed_games.get().clone();
+        if rotation.is_empty() {
+            return;
+        }
+        let mut hands = *self.state.orbit_hands.get();
+        if hands as usize >= players {
+            let index = (*self.state.mixed_game_index.get() as usize + 1) % rotation.len();
+            self.state.mixed_game_index.set(index as u8);
+            self.state.variant.set(rotation[index]);
+            hands = 0;
+        }
+        self.state.orbit_hands.set(hands + 1);
+    }
+
+    /// Handle betting action
+    async fn handle_bet_action(&mut self, player_chain: ChainId, game_id: u64, action: BetAction) {
+        if game_id != *self.state.game_id.get() {
+            return;
+        }
+
+        let mut players = self.state.players.get().clone();
+        let player_idx = match players.iter().position(|p| p.chain_id == player_chain) {
+            Some(idx) => idx,
+            None => return,
+        };
+
+        let player_seat = players[player_idx].seat;
+        if self.state.turn_seat.get() != &Some(player_seat) {
+            return;
+        }
+
+        let phase = *self.state.phase.get();
+        if !phase.is_betting_round() {
+            return;
+        }
+
+        let betting = *self.state.betting.get();
+        let mut street = StreetBetting {
+            current_bet: *self.state.current_bet.get(),
+            min_raise: *self.state.min_raise.get(),
+            pot: *self.state.pot.get(),
+            raises: *self.state.raises_this_street.get(),
+        };
+
+        match action {
+            BetAction::Check => {
+                if street.current_bet > players[player_idx].current_bet {
+                    return;
+                }
+            }
+            BetAction::Call => {
+                // A short stack calls for whatever it has left
+                let to_call = street.current_bet.saturating_sub(players[player_idx].current_bet);
+                street.pot = street.pot.saturating_add(players[player_idx].commit(to_call));
+            }
+            BetAction::Bet(amount) => {
+                // Opening bet only; facing a bet the player must raise
+                if street.current_bet > Amount::ZERO {
+                    return;
+                }
+                if !Self::apply_raise(&mut players, player_idx, amount, &betting, &mut street) {
+                    return;
+                }
+            }
+            BetAction::RaiseTo(amount) => {
+                if street.current_bet == Amount::ZERO {
+                    return;
+                }
+                if !Self::apply_raise(&mut players, player_idx, amount, &betting, &mut street) {
+                    return;
+                }
             }
             BetAction::AllIn => {
-                let remaining = players[player_idx]
-                    .stake
-                    .saturating_sub(players[player_idx].current_bet);
-                let new_bet = players[player_idx].current_bet.saturating_add(remaining);
-                if new_bet > current_bet {
-                    current_bet = new_bet;
+                // Whatever is left of the stack after earlier streets
+                let all_in_to = players[player_idx]
+                    .current_bet
+                    .saturating_add(players[player_idx].stack);
+                if all_in_to > street.current_bet {
+                    if !Self::apply_raise(
+                        &mut players,
+                        player_idx,
+                        all_in_to,
+                        &betting,
+                        &mut street,
+                    ) {
+                        return;
+                    }
+                } else {
+                    // All-in for no more than the call
+                    let remaining = players[player_idx].stack;
+                    street.pot = street.pot.saturating_add(players[player_idx].commit(remaining));
                 }
-                players[player_idx].current_bet = new_bet;
-                pot = pot.saturating_add(remaining);
             }
             BetAction::Fold => {
-                // FIX #8: MEDIUM - Check if opponent already folded (edge case)
-                let opponent_seat = player_seat.other();
-                let opponent_folded = players
-                    .iter()
-                    .find(|p| p.seat == opponent_seat)
-                    .map(|p| p.has_folded)
-                    .unwrap_or(false);
-
-                if opponent_folded {
-                    // Both folded - current player wins by default since they folded second
-                    self.state.winner.set(Some(player_seat));
-                    self.state.phase.set(GamePhase::Settlement);
+                players[player_idx].has_folded = true;
+
+                // FIX #8: MEDIUM - Last player still in the hand wins the pot
+                let remaining = players.iter().filter(|p| !p.has_folded).count();
+                if remaining <= 1 {
                     self.state.players.set(players);
-                    self.state.pot.set(pot);
-                    self.settle_game().await;
+                    self.state.pot.set(street.pot);
+                    self.determine_winner();
+                    self.settle_game(false).await;
                     return;
                 }
-
-                // Normal fold - opponent wins
-                players[player_idx].has_folded = true;
-                let winner_seat = player_seat.other();
-                self.state.winner.set(Some(winner_seat));
-                self.state.phase.set(GamePhase::Settlement);
-                self.state.players.set(players);
-                self.state.pot.set(pot);
-                self.settle_game().await;
-                return;
+                // Dealerless: the board can't be opened without the folded
+                // player's shares, so ask for the rest of it now
+                self.request_folded_shares(player_seat);
             }
         }
 
-        self.state.pot.set(pot);
-        self.state.current_bet.set(current_bet);
-        self.state.players.set(players);
+        players[player_idx].has_acted = true;
 
-        // Increment action counter to track betting round completion
-        let actions = self.state.actions_this_round.get().saturating_add(1);
-        self.state.actions_this_round.set(actions);
+        self.state.pot.set(street.pot);
+        self.state.current_bet.set(street.current_bet);
+        self.state.min_raise.set(street.min_raise);
+        self.state.raises_this_street.set(street.raises);
+        self.state.players.set(players);
 
         self.advance_turn().await;
     }
 
+    /// Bet or raise of a seat to `raise_to` for this street, within the
+    /// limits the betting structure allows.
+    /// Returns false if the action is illegal.
+    ///
+    /// A full raise (at least the last raise size, or completing a fixed-limit
+    /// bring-in to a full bet) reopens action, and any larger than the last
+    /// becomes the new minimum. A short all-in raise does not: players who
+    /// already acted may only call or fold.
+    fn apply_raise(
+        players: &mut [PlayerInfo],
+        idx: usize,
+        raise_to: Amount,
+        betting: &BettingStructure,
+        street: &mut StreetBetting,
+    ) -> bool {
+        // Also rejects players who already acted and weren't reopened
+        let (min, max) = match betting.raise_limits(&players[idx], street) {
+            Some(limits) => limits,
+            None => return false,
+        };
+        if raise_to < min || raise_to > max {
+            return false;
+        }
+
+        // FIX #6: HIGH - Validate bet against player's available stack
+        let required = raise_to.saturating_sub(players[idx].current_bet);
+        if required > players[idx].stack {
+            return false;
+        }
+
+        let raise_size = raise_to.saturating_sub(street.current_bet);
+        let full_raise = raise_size >= street.min_raise
+            || betting.completion(street).is_some_and(|full_bet| raise_to >= full_bet);
+        street.pot = street.pot.saturating_add(players[idx].commit(required));
+        street.current_bet = raise_to;
+        if full_raise {
+            street.min_raise = street.min_raise.max(raise_size);
+            street.raises = street.raises.saturating_add(1);
+            Self::reopen_action(players);
+        }
+        true
+    }
+
+    /// After a bet or raise, everyone else still in the hand must act again
+    fn reopen_action(players: &mut [PlayerInfo]) {
+        for player in players.iter_mut() {
+            player.has_acted = false;
+        }
+    }
+
     /// Advance to next player or phase
     /// FIX #4: HIGH - Replace unwrap() with safe error handling
     async fn advance_turn(&mut self) {
@@ -474,72 +1487,299 @@ impl TableContract {
             Some(s) => *s,
             None => return,
         };
-        let next_seat = current_seat.other();
 
         let players = self.state.players.get();
-
-        // FIX #4: Safe pattern - early return if player not found
-        let next_player = match players.iter().find(|p| p.seat == next_seat) {
-            Some(p) => p,
-            None => return,
-        };
         let current_bet = *self.state.current_bet.get();
 
-        if !next_player.has_folded && next_player.current_bet < current_bet {
-            self.state.turn_seat.set(Some(next_seat));
-            self.notify_turn().await;
-        } else {
-            // FIX #4: Safe pattern - early return if player not found
-            let current_player = match players.iter().find(|p| p.seat == current_seat) {
-                Some(p) => p,
-                None => return,
-            };
-
-            // FIX BUG #1: Only advance phase if both players have acted (actions >= 2) and bets match
-            let actions = *self.state.actions_this_round.get();
-            if current_player.current_bet == current_bet
-                && next_player.current_bet == current_bet
-                && actions >= 2
-            {
-                self.advance_phase();
-            } else {
+        // A player still needs to act if they haven't acted since the last
+        // raise, or haven't matched the current bet (all-in players are done)
+        let needs_action: Vec<Seat> = players
+            .iter()
+            .filter(|p| !p.has_folded && !p.is_all_in())
+            .filter(|p| !p.has_acted || p.current_bet < current_bet)
+            .map(|p| p.seat)
+            .collect();
+
+        // FIX BUG #1: Only advance phase once every active player has acted and bets match
+        match current_seat.next_in(&needs_action) {
+            Some(next_seat) => {
                 self.state.turn_seat.set(Some(next_seat));
                 self.notify_turn().await;
             }
+            None if needs_action.contains(&current_seat) => {
+                self.notify_turn().await;
+            }
+            None => self.close_street().await,
+        }
+    }
+
+    /// Move on from a finished street or draw
+    async fn close_street(&mut self) {
+        self.advance_phase().await;
+        // Nobody left to bet against: run out the board straight to showdown
+        // (a dealerless board carries on once the players open it)
+        while self.betting_closed() && !self.board_pending() {
+            self.advance_phase().await;
         }
     }
 
+    /// True on a betting street where at most one player still has chips behind,
+    /// so no further betting is possible
+    fn betting_closed(&self) -> bool {
+        if !self.state.phase.get().is_betting_round() {
+            return false;
+        }
+        let can_bet = self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| !p.has_folded && !p.is_all_in())
+            .count();
+        can_bet <= 1
+    }
+
     /// Advance to next game phase
-    fn advance_phase(&mut self) {
+    async fn advance_phase(&mut self) {
         let mut players = self.state.players.get().clone();
         for p in &mut players {
             p.current_bet = Amount::ZERO;
+            p.has_acted = false;
         }
-        self.state.players.set(players);
+        self.state.players.set(players.clone());
         self.state.current_bet.set(Amount::ZERO);
-        // Reset action counter for new betting round
-        self.state.actions_this_round.set(0);
 
         let phase = *self.state.phase.get();
-        let new_phase = match phase {
-            GamePhase::PreFlop => GamePhase::Flop,
-            GamePhase::Flop => GamePhase::Turn,
-            GamePhase::Turn => GamePhase::River,
-            GamePhase::River => GamePhase::Showdown,
-            _ => return,
+        let rules = self.state.variant.get().rules();
+        let new_phase = match rules.next_street(phase) {
+            Some(next) => next,
+            None => return,
         };
         self.state.phase.set(new_phase);
+        // Open the board cards the street shows (all of them at showdown)
+        self.open_board(new_phase);
+        let min_raise = self
+            .state
+            .betting
+            .get()
+            .opening_min_raise(new_phase, *self.state.big_blind.get());
+        self.state.min_raise.set(min_raise);
+        self.state.raises_this_street.set(0);
 
         if new_phase == GamePhase::Showdown {
             self.state.turn_seat.set(None);
             // Record showdown start for reveal timeout tracking
             let current_block = self.runtime.block_height().0;
             self.state.showdown_start_block.set(Some(current_block));
+
+            // Ask everyone still in the hand to reveal
+            // (dealerless: to share their hole cards, which opens them)
+            let game_id = *self.state.game_id.get();
+            if let Some(deal) = self.state.mental_deal.get().clone() {
+                for player in players.iter().filter(|p| !p.has_folded) {
+                    let cards = deal.cards_at(&deal.hole_positions(player.seat));
+                    let request = MentalRequest::Shares { game_id, cards };
+                    self.send_mental_request(&[player.seat], request);
+                }
+                return;
+            }
+            for player in players.iter().filter(|p| !p.has_folded && p.hand_app.is_some()) {
+                self.runtime
+                    .prepare_message(Message::RequestReveal { game_id })
+                    .with_authentication()
+                    .send_to(player.chain_id);
+            }
         } else {
-            // === STANDARD POKER: Post-flop, NON-BUTTON (BB) acts first ===
-            let button = self.state.dealer_button.get().unwrap_or(Seat::Player1);
-            let bb_seat = button.other();
-            self.state.turn_seat.set(Some(bb_seat));
+            // Stud deals the street's cards; flop games have opened the board
+            self.deal_street(new_phase).await;
+
+            // Dealerless: nobody acts until the players open the street's cards
+            if self.board_pending() {
+                self.state.turn_seat.set(None);
+                self.request_board_shares();
+                return;
+            }
+            self.set_first_to_act();
+        }
+    }
+
+    /// Open the board cards `street` shows that aren't open yet, at the
+    /// positions committed to when the hand was dealt, and send them to every
+    /// hand dealt in with their Merkle paths to the deck root
+    fn open_board(&mut self, street: GamePhase) {
+        let positions = self.state.board_positions.get().clone();
+        let rules = self.state.variant.get().rules();
+        let mut community = self.state.community_cards.get().clone();
+        let to = rules.board_dealt(street).min(positions.len());
+        if community.len() >= to {
+            return;
+        }
+
+        let deck = rules.shuffle(self.state.deck_seed.get());
+        let leaves = self.deck_leaves(&deck);
+        let board: Vec<BoardCardProof> = positions[community.len()..to]
+            .iter()
+            .map(|&position| BoardCardProof {
+                position,
+                card: deck[position as usize],
+                salt: self.leaf_salt(position),
+                path: deck_merkle_path(&leaves, position as usize),
+            })
+            .collect();
+        community.extend(board.iter().map(|proof| proof.card));
+        self.state.community_cards.set(community);
+
+        let game_id = *self.state.game_id.get();
+        let seats = self.state.dealt_in.get();
+        for player in self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| p.hand_app.is_some() && seats.contains(&p.seat))
+        {
+            self.runtime
+                .prepare_message(Message::CommunityCardsZK {
+                    game_id,
+                    phase: street,
+                    board: board.clone(),
+                })
+                .with_authentication()
+                .send_to(player.chain_id);
+        }
+    }
+
+    /// Post-flop, the first active seat left of the button acts first
+    /// (heads-up this is the BB; in stud the best hand showing)
+    fn set_first_to_act(&mut self) {
+        let active: Vec<Seat> = self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| !p.has_folded)
+            .map(|p| p.seat)
+            .collect();
+        let rules = self.state.variant.get().rules();
+        let button = self.state.dealer_button.get().unwrap_or(Seat::Player1);
+        let first = rules.first_to_act(button, &active, self.state.up_cards.get());
+        self.state.turn_seat.set(first);
+    }
+
+    /// Handle a draw: replace the discarded hole cards from the undealt deck
+    ///
+    /// Players draw in turn, each once. Replacements are committed like hole
+    /// cards and sent with a ZK draw proof that they come from deck positions
+    /// nobody was dealt. An empty discard stands pat.
+    async fn handle_draw(&mut self, player_chain: ChainId, game_id: u64, discarded: Vec<u8>) {
+        if game_id != *self.state.game_id.get() {
+            return;
+        }
+
+        if *self.state.phase.get() != GamePhase::Draw {
+            return;
+        }
+
+        let mut players = self.state.players.get().clone();
+        let player_idx = match players.iter().position(|p| p.chain_id == player_chain) {
+            Some(idx) => idx,
+            None => return,
+        };
+        let seat = players[player_idx].seat;
+        if self.state.turn_seat.get() != &Some(seat) {
+            return;
+        }
+
+        // At most `max_discards` distinct cards of the player's own hand
+        let rules = self.state.variant.get().rules();
+        let hand_size = rules.hole_cards();
+        let mut distinct = discarded.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() != discarded.len()
+            || discarded.len() > rules.max_discards()
+            || discarded.iter().any(|&i| i as usize >= hand_size)
+        {
+            return;
+        }
+
+        let deck = rules.shuffle(self.state.deck_seed.get());
+        let first_undealt = *self.state.deck_position.get();
+        let next_undealt = first_undealt as usize + discarded.len();
+        if next_undealt > deck.len() {
+            return;
+        }
+
+        if !discarded.is_empty() {
+            let replacements = &deck[first_undealt as usize..next_undealt];
+
+            // Fresh nonces: replacements are numbered after the dealt hand
+            let (commitments, sealed_openings) = self
+                .commit_cards(
+                    player_chain,
+                    replacements,
+                    hand_size + first_undealt as usize,
+                    game_id,
+                )
+                .await;
+            let mut held = self
+                .state
+                .player_commitments
+                .get(&player_chain)
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+            for (&position, commitment) in discarded.iter().zip(&commitments) {
+                if let Some(slot) = held.get_mut(position as usize) {
+                    *slot = commitment.clone();
+                }
+            }
+            let _ = self.state.player_commitments.insert(&player_chain, held);
+
+            // Phase 3: Mock proof - Phase 4 will use real Groth16
+            let draw_proof = DrawProof {
+                proof: vec![0u8; proof_size_for(discarded.len())],
+                discarded: discarded.clone(),
+                card_commitments: commitments,
+                deck_positions: (first_undealt..next_undealt as u8).collect(),
+                first_undealt,
+                deck_root: *self.state.deck_root.get(),
+                sealed_openings,
+            };
+
+            if players[player_idx].hand_app.is_some() {
+                self.runtime
+                    .prepare_message(Message::DrawCardsZK {
+                        game_id,
+                        draw_proof,
+                    })
+                    .with_authentication()
+                    .send_to(player_chain);
+            }
+            self.state.deck_position.set(next_undealt as u8);
+        }
+
+        // How many cards each player took is public
+        let mut draws = self.state.draws.get().clone();
+        draws.push((seat, discarded.len() as u8));
+        self.state.draws.set(draws);
+
+        players[player_idx].has_acted = true;
+        self.state.players.set(players.clone());
+
+        // Everyone still in the hand draws, all-in players included
+        let to_draw: Vec<Seat> = players
+            .iter()
+            .filter(|p| !p.has_folded && !p.has_acted)
+            .map(|p| p.seat)
+            .collect();
+        match seat.next_in(&to_draw) {
+            Some(next_seat) => {
+                self.state.turn_seat.set(Some(next_seat));
+                self.notify_turn().await;
+            }
+            None => self.close_street().await,
         }
     }
 
@@ -556,7 +1796,10 @@ impl TableContract {
             return;
         }
 
-        if *self.state.phase.get() != GamePhase::Showdown {
+        // Dealerless hands are shown by opening the cards, never by claiming them
+        if *self.state.phase.get() != GamePhase::Showdown
+            || self.state.mental_deal.get().is_some()
+        {
             return;
         }
 
@@ -570,17 +1813,22 @@ impl TableContract {
         if cards.len() != proofs.len() {
             return; // Reject mismatched lengths
         }
+        if cards.len() != self.state.variant.get().rules().hole_cards() {
+            return; // Reject a hand of the wrong size for this game
+        }
 
-        // PHASE 3 TODO: Replace with ZK proof verification
-        // For now, skip dealer_secret verification (field removed from state)
+        // Each card must open the commitment it was dealt under, its secret
+        // being the blinding factor; a player dealt no commitments has
+        // nothing to claim
+        let stored_commitments = match self.state.player_commitments.get(&player_chain).await {
+            Ok(Some(commitments)) if commitments.len() == cards.len() => commitments,
+            _ => return,
+        };
         #[allow(deprecated)]
-        for (card, proof) in cards.iter().zip(proofs.iter()) {
-            // Verify the proof card matches the claimed card
-            if proof.card != *card {
-                return; // Reject - proof doesn't match claimed card
+        for ((card, proof), commitment) in cards.iter().zip(&proofs).zip(&stored_commitments) {
+            if proof.card != *card || !commitment.opens(*card, &proof.secret) {
+                return; // Reject - the card isn't the one dealt
             }
-            // NOTE: dealer_secret verification removed - will be replaced by ZK proof verification
-            // Previously: if proof.secret != *dealer_secret { return; }
         }
 
         players[player_idx].has_revealed = true;
@@ -595,7 +1843,7 @@ impl TableContract {
         let all_revealed = players.iter().all(|p| p.has_folded || p.has_revealed);
         if all_revealed {
             self.determine_winner();
-            self.settle_game().await;
+            self.settle_game(false).await;
         }
     }
 
@@ -614,7 +1862,9 @@ impl TableContract {
             return;
         }
 
-        if *self.state.phase.get() != GamePhase::Showdown {
+        if *self.state.phase.get() != GamePhase::Showdown
+            || self.state.mental_deal.get().is_some()
+        {
             return;
         }
 
@@ -636,8 +1886,7 @@ impl TableContract {
             }
         };
 
-        // 4. Verify ZK proof (Phase 3: Mock verification - accepts valid-looking proofs)
-        // Phase 4 will use real Groth16 verification
+        // 4. Verify the cards open the commitments, and the ZK proof if any
         let is_valid = self.verify_reveal_proof(&reveal_proof, &stored_commitments);
 
         if !is_valid {
@@ -665,151 +1914,907 @@ impl TableContract {
         let all_revealed = players.iter().all(|p| p.has_folded || p.has_revealed);
         if all_revealed {
             self.determine_winner();
-            self.settle_game().await;
+            self.settle_game(false).await;
+        }
+    }
+
+    /// Verify ZK reveal proof against stored commitments
+    ///
+    /// Every card must open its stored commitment with the blinding revealed
+    /// for it. Phase 4: Real Groth16 verification with embedded verifying key
+    /// when a proof is attached (empty proofs are Phase 3 compatibility)
+    fn verify_reveal_proof(
+        &self,
+        reveal_proof: &RevealProof,
+        stored_commitments: &[CardCommitment],
+    ) -> bool {
+        // Basic structural validation
+        let hole_count = self.state.variant.get().rules().hole_cards();
+        if reveal_proof.cards.len() != hole_count {
+            return false;
+        }
+
+        if stored_commitments.len() != hole_count {
+            return false;
+        }
+
+        // Verify card indices are valid (0-51)
+        for card in &reveal_proof.cards {
+            if card.to_index() >= 52 {
+                return false;
+            }
+        }
+
+        // Every card must open the commitment it was dealt under
+        if reveal_proof.randomness.len() != hole_count {
+            return false;
+        }
+        let openings = reveal_proof.cards.iter().zip(&reveal_proof.randomness);
+        if !stored_commitments
+            .iter()
+            .zip(openings)
+            .all(|(commitment, (card, blinding))| commitment.opens(*card, blinding))
+        {
+            return false;
+        }
+
+        // Use real Groth16 verification with embedded verifying key
+        // Falls back to structural validation if proof is empty (Phase 3 compatibility)
+        if reveal_proof.proof.is_empty() {
+            // Phase 3 mock mode: the openings alone decide
+            true
+        } else {
+            // Phase 4: Real cryptographic verification
+            verify_reveal_proof_embedded(reveal_proof, stored_commitments)
+        }
+    }
+
+    /// Determine winner using actual hand evaluation
+    /// FIX #2: CRITICAL - Add bounds checking to prevent panics
+    ///
+    /// Builds the main and side pots from each player's contribution and
+    /// awards every pot to the best revealed hand among its eligible seats.
+    /// In hi/lo games the best high and the best qualifying low split each
+    /// pot, the odd chip going to the high; without a qualifying low the
+    /// high scoops.
+    fn determine_winner(&mut self) {
+        // FIX #2: Bounds check - need at least 2 players
+        if self.state.players.get().len() < 2 {
+            return;
+        }
+
+        // Nobody contests chips beyond the second-biggest contribution
+        let mut players = self.state.players.get().clone();
+        let uncalled = return_uncalled_bet(&mut players);
+        if let Some((_, refund)) = uncalled {
+            let pot = self.state.pot.get().saturating_sub(refund);
+            self.state.pot.set(pot);
+        }
+        self.state.uncalled_bet.set(uncalled);
+        self.state.players.set(players);
+        let players = self.state.players.get();
+
+        // Get revealed cards and community cards
+        let revealed = self.state.revealed_cards.get();
+        let community = self.state.community_cards.get();
+        let up_cards = self.state.up_cards.get();
+        let rules = self.state.variant.get().rules();
+
+        // Cards shown by every player still in the hand that revealed
+        let shown: Vec<(Seat, Vec<Card>)> = players
+            .iter()
+            .filter(|p| !p.has_folded)
+            .filter_map(|p| {
+                revealed
+                    .iter()
+                    .find(|(s, _)| *s == p.seat)
+                    .map(|(_, cards)| {
+                        // Stud: the hand is the revealed down cards plus the seat's up cards
+                        let mut cards = cards.clone();
+                        if let Some((_, up)) = up_cards.iter().find(|(s, _)| *s == p.seat) {
+                            cards.extend(up.iter().copied());
+                        }
+                        (p.seat, cards)
+                    })
+            })
+            .collect();
+
+        // Actual hand evaluation: the high hands, and the lows that qualify
+        let highs: Vec<(Seat, HandScore)> = if rules.plays_high() {
+            shown
+                .iter()
+                .map(|(seat, cards)| (*seat, rules.evaluate(cards, community)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let lows: Vec<(Seat, LowScore)> = shown
+            .iter()
+            .filter_map(|(seat, cards)| {
+                rules
+                    .evaluate_low(cards, community)
+                    .map(|score| (*seat, score))
+            })
+            .collect();
+
+        let mut pots = build_pots(players);
+        self.collect_rake(&mut pots);
+        let mut winners: Vec<Seat> = Vec::new();
+        let mut awards: Vec<PotAward> = Vec::new();
+        let button = *self.state.dealer_button.get();
+        for (index, pot) in pots.iter_mut().enumerate() {
+            // FIX #9: MEDIUM - Every eligible seat sharing the best hand splits the pot
+            // (or its half). A lone eligible seat, or a pot nobody showed down for, goes to
+            // everyone eligible.
+            let high = best_hands(&highs, &pot.eligible);
+            let low = best_hands(&lows, &pot.eligible);
+            let shares: Vec<(Vec<Seat>, Amount)> = if pot.eligible.len() <= 1
+                || (high.is_empty() && low.is_empty())
+            {
+                vec![(pot.eligible.clone(), pot.amount)]
+            } else if low.is_empty() {
+                vec![(high, pot.amount)]
+            } else if high.is_empty() {
+                vec![(low, pot.amount)]
+            } else {
+                let low_half = pot.amount.saturating_div(2);
+                vec![(high, pot.amount.saturating_sub(low_half)), (low, low_half)]
+            };
+
+            pot.winners.clear();
+            for (share_winners, amount) in shares {
+                for seat in &share_winners {
+                    if !pot.winners.contains(seat) {
+                        pot.winners.push(*seat);
+                    }
+                    if !winners.contains(seat) {
+                        winners.push(*seat);
+                    }
+                }
+                let share = Pot {
+                    amount,
+                    eligible: pot.eligible.clone(),
+                    winners: share_winners,
+                };
+                awards.extend(
+                    split_pot(&share, button)
+                        .into_iter()
+                        .map(|(seat, amount)| PotAward {
+                            pot: index,
+                            seat,
+                            amount,
+                        }),
+                );
+            }
+        }
+        winners.sort_by_key(|seat| seat.index());
+
+        self.state.pots.set(pots);
+        self.state.awards.set(awards);
+        self.state.winners.set(winners);
+        self.award_jackpot(&highs);
+        self.state.phase.set(GamePhase::Settlement);
+    }
+
+    /// Take the house's rake out of the pots; the chips leave the table
+    ///
+    /// With no flop, no drop, a hand that ends on its first betting round
+    /// isn't raked. The jackpot's drop stays at the table in the pool.
+    fn collect_rake(&mut self, pots: &mut [Pot]) {
+        self.state.hand_rake.set(Amount::ZERO);
+        let rake = match self.state.rake.get() {
+            Some(rake) => rake.clone(),
+            None => return,
+        };
+        let first_street = self.state.variant.get().rules().first_street();
+        if rake.no_flop_no_drop && *self.state.phase.get() == first_street {
+            return;
+        }
+
+        let cap = (rake.cap > 0).then(|| Amount::from_tokens(rake.cap.into()));
+        let taken = take_rake(pots, rake.percent, cap);
+        let pot = self.state.pot.get().saturating_sub(taken);
+        self.state.pot.set(pot);
+        let total_buy_ins = self.state.total_buy_ins.get().saturating_sub(taken);
+        self.state.total_buy_ins.set(total_buy_ins);
+        self.state.hand_rake.set(taken);
+
+        let drop = match &rake.jackpot {
+            Some(jackpot) => {
+                Amount::from_attos(taken.to_attos() * u128::from(jackpot.percent.min(100)) / 100)
+            }
+            None => Amount::ZERO,
+        };
+        let pool = self.state.jackpot.get().saturating_add(drop);
+        self.state.jackpot.set(pool);
+        let house = taken.saturating_sub(drop);
+        let collected = self.state.rake_collected.get().saturating_add(house);
+        self.state.rake_collected.set(collected);
+        let owed = self.state.rake_owed.get().saturating_add(house);
+        self.state.rake_owed.set(owed);
+    }
+
+    /// Pay the jackpot out of the pool when the hands shown down hit it; the
+    /// chips go straight into the winners' stacks
+    fn award_jackpot(&mut self, highs: &[(Seat, HandScore)]) {
+        self.state.jackpot_hit.set(None);
+        self.state.jackpot_awards.set(Vec::new());
+        let config = match self.state.rake.get().as_ref().and_then(|r| r.jackpot.clone()) {
+            Some(config) => config,
+            None => return,
+        };
+        let pool = *self.state.jackpot.get();
+        let hit = match jackpot_hit(&config, highs) {
+            Some(hit) if pool > Amount::ZERO => hit,
+            _ => return,
+        };
+
+        // Players who sat down during the hand take no table share
+        let mut players = self.state.players.get().clone();
+        let dealt_in = self.state.dealt_in.get().clone();
+        let payouts = jackpot_payouts(&config, hit, pool, &dealt_in);
+        let mut paid = Amount::ZERO;
+        for (seat, amount) in &payouts {
+            if let Some(player) = players.iter_mut().find(|p| p.seat == *seat) {
+                player.stack = player.stack.saturating_add(*amount);
+                paid = paid.saturating_add(*amount);
+            }
+        }
+        self.state.players.set(players);
+        let total_buy_ins = self.state.total_buy_ins.get().saturating_add(paid);
+        self.state.total_buy_ins.set(total_buy_ins);
+        self.state.jackpot.set(pool.saturating_sub(paid));
+        self.state.jackpot_hit.set(Some(hit));
+        self.state.jackpot_awards.set(payouts);
+    }
+
+    /// Pay the rake owed to the fee recipient through the token application,
+    /// in whole tokens; the fraction left over is paid with a later hand
+    fn pay_rake(&mut self) {
+        let rake = match self.state.rake.get() {
+            Some(rake) => rake.clone(),
+            None => return,
+        };
+        let token_app = match rake.token_app {
+            Some(token_app) => token_app,
+            None => return,
+        };
+        let owed = *self.state.rake_owed.get();
+        let tokens = u64::try_from(owed.to_attos() / Amount::ONE.to_attos()).unwrap_or(0);
+        if tokens == 0 {
+            return;
+        }
+
+        self.runtime.call_application(
+            /* authenticated */ true,
+            token_app.with_abi::<TokenAbi>(),
+            &TokenOperation::Transfer {
+                to_chain: rake.fee_recipient,
+                amount: tokens,
+                game_id: *self.state.game_id.get(),
+            },
+        );
+        self.state
+            .rake_owed
+            .set(owed.saturating_sub(Amount::from_tokens(tokens.into())));
+    }
+
+    /// Chips owed to a seat at settlement: its share of every pot it won,
+    /// plus the stack it never put into the pot (including any uncalled bet)
+    fn payout_for(&self, player: &PlayerInfo) -> Amount {
+        let winnings = self
+            .state
+            .awards
+            .get()
+            .iter()
+            .filter(|award| award.seat == player.seat)
+            .fold(Amount::ZERO, |sum, award| sum.saturating_add(award.amount));
+        winnings.saturating_add(player.stack)
+    }
+
+    /// Ledger invariant: chips behind plus the committed pot equal every buy-in still at the table
+    fn ledger_balanced(&self) -> bool {
+        let stacks = self
+            .state
+            .players
+            .get()
+            .iter()
+            .fold(Amount::ZERO, |sum, p| sum.saturating_add(p.stack));
+        stacks.saturating_add(*self.state.pot.get()) == *self.state.total_buy_ins.get()
+    }
+
+    /// Settle the game
+    /// FIX #9: MEDIUM - Handle pot splitting for ties
+    ///
+    /// `by_forfeit` marks results of a hand ended by an auto-forfeit.
+    async fn settle_game(&mut self, by_forfeit: bool) {
+        let game_id = *self.state.game_id.get();
+        let mut players = self.state.players.get().clone();
+
+        // Pay every pot out into the winners' stacks
+        let payouts: Vec<Amount> = players.iter().map(|p| self.payout_for(p)).collect();
+        for (player, payout) in players.iter_mut().zip(&payouts) {
+            player.stack = *payout;
+        }
+        self.state.players.set(players.clone());
+        self.state.pot.set(Amount::ZERO);
+        self.pay_rake();
+
+        let awards = self.state.awards.get().clone();
+        let rake = *self.state.hand_rake.get();
+        let uncalled_bet = *self.state.uncalled_bet.get();
+        for (player, payout) in players.iter().zip(payouts) {
+            // FIX #9: Split pots are shared; a player "won" if they took any pot outright
+            let you_won = self
+                .state
+                .pots
+                .get()
+                .iter()
+                .any(|pot| pot.winners == [player.seat]);
+
+            if player.hand_app.is_some() {
+                self.runtime
+                    .prepare_message(Message::GameResult {
+                        game_id,
+                        you_won,
+                        payout,
+                        opponent_cards: None,
+                        forfeited: by_forfeit,
+                        uncalled_refund: match uncalled_bet {
+                            Some((seat, refund)) if seat == player.seat => refund,
+                            _ => Amount::ZERO,
+                        },
+                        awards: awards.clone(),
+                        rake,
+                    })
+                    .with_authentication()
+                    .send_to(player.chain_id);
+            }
+        }
+
+        self.state.phase.set(GamePhase::Finished);
+
+        if self.state.tournament.get().is_some() {
+            self.start_next_tournament_hand().await;
+        } else if *self.state.cash_game.get() {
+            self.start_next_cash_hand().await;
+        }
+    }
+
+    /// Cash game: carry every stack into the next hand and deal it right away
+    ///
+    /// Stacks become what each player was paid at settlement, pending top-ups
+    /// are added (up to `max_stake`), players cashing out are paid their stack
+    /// and unseated, and busted players lose their seat.
+    async fn start_next_cash_hand(&mut self) {
+        let max_stake = *self.state.max_stake.get();
+        let mut total_buy_ins = *self.state.total_buy_ins.get();
+        let mut seated = Vec::new();
+        for mut player in self.state.players.get().clone() {
+            // Top-ups never take a stack above max_stake (winnings can)
+            let topped_up = player
+                .stack
+                .saturating_add(player.pending_top_up)
+                .min(max_stake);
+            let added = topped_up.saturating_sub(player.stack);
+            player.stack = player.stack.max(topped_up);
+            player.stake = player.stack;
+            player.pending_top_up = Amount::ZERO;
+            total_buy_ins = total_buy_ins.saturating_add(added);
+
+            if player.cashing_out {
+                total_buy_ins = total_buy_ins.saturating_sub(player.stack);
+                self.send_cash_out(&player);
+                continue;
+            }
+            if player.stack == Amount::ZERO {
+                continue;
+            }
+
+            player.has_folded = false;
+            player.current_bet = Amount::ZERO;
+            player.has_revealed = false;
+            player.has_acted = false;
+            player.total_contribution = Amount::ZERO;
+            seated.push(player);
+        }
+
+        self.reset_hand();
+        self.state.total_buy_ins.set(total_buy_ins);
+        self.state.players.set(seated.clone());
+
+        if Self::dealt_in_count(&seated) >= 2 {
+            self.deal_cards().await;
+        }
+    }
+
+    /// Tournament: knock out every player left without chips and deal the
+    /// next hand, until one player holds every chip and wins
+    ///
+    /// Players knocked out in the same hand finish in order of the stacks
+    /// they started it with, the biggest finishing highest.
+    async fn start_next_tournament_hand(&mut self) {
+        let hands_played = *self.state.hands_played.get() + 1;
+        self.state.hands_played.set(hands_played);
+
+        let entrants = self.state.finishes.get().len() + self.state.players.get().len();
+        let (mut busted, seated): (Vec<PlayerInfo>, Vec<PlayerInfo>) = self
+            .state
+            .players
+            .get()
+            .iter()
+            .cloned()
+            .partition(|p| p.stack == Amount::ZERO);
+        busted.sort_by(|a, b| b.stake.cmp(&a.stake));
+        for (place, player) in (seated.len() + 1..).zip(&busted) {
+            let prize = self.place_prize(place as u32, entrants);
+            self.finish_tournament(player, place as u32, prize);
+        }
+
+        // Players the coordinator moved to another table leave with their chips
+        let (moving, mut seated): (Vec<PlayerInfo>, Vec<PlayerInfo>) =
+            seated.into_iter().partition(|p| p.cashing_out);
+        for player in &moving {
+            self.record_departure(player);
+        }
+
+        // Everyone left agreed to a deal this hand: it ends the tournament
+        let deal = self.agreed_deal(&seated);
+        self.reset_hand();
+        if let Some(kind) = deal {
+            self.settle_deal(kind, &seated, entrants);
+            seated.clear();
+        }
+
+        // A multi-table tournament's last player at a table waits to be moved
+        if seated.len() <= 1 && !self.is_coordinated() {
+            if let Some(winner) = seated.pop() {
+                let prize = self.place_prize(1, entrants);
+                self.finish_tournament(&winner, 1, prize);
+            }
+            self.state.deal_votes.set(Vec::new());
+            self.state.players.set(Vec::new());
+            self.state.total_buy_ins.set(Amount::ZERO);
+            self.state.phase.set(GamePhase::Finished);
+            return;
+        }
+
+        for player in &mut seated {
+            player.stake = player.stack;
+            player.has_folded = false;
+            player.current_bet = Amount::ZERO;
+            player.has_revealed = false;
+            player.has_acted = false;
+            player.total_contribution = Amount::ZERO;
+        }
+        let chips = seated
+            .iter()
+            .fold(Amount::ZERO, |sum, p| sum.saturating_add(p.stack));
+        self.state.total_buy_ins.set(chips);
+        self.state.players.set(seated);
+        self.deal_cards().await;
+    }
+
+    /// Tournament: prize for finishing in `place` out of `entrants`
+    fn place_prize(&self, place: u32, entrants: usize) -> Amount {
+        let tournament = match self.state.tournament.get() {
+            Some(tournament) => tournament,
+            None => return Amount::ZERO,
+        };
+        prize_payouts(*self.state.prize_pool.get(), &tournament.payouts, entrants)
+            .get(place as usize - 1)
+            .copied()
+            .unwrap_or(Amount::ZERO)
+    }
+
+    /// Tournament: record where a player finished and pay their prize out of
+    /// the prize pool, through the token application
+    fn finish_tournament(&mut self, player: &PlayerInfo, place: u32, prize: Amount) {
+        let tournament = match self.state.tournament.get().clone() {
+            Some(tournament) => tournament,
+            None => return,
+        };
+
+        let mut finishes = self.state.finishes.get().clone();
+        finishes.push(TournamentFinish {
+            chain_id: player.chain_id,
+            seat: player.seat,
+            place,
+            prize,
+        });
+        self.state.finishes.set(finishes);
+
+        // The coordinator ranks a multi-table tournament and pays its prizes
+        if tournament.coordinator.is_some() {
+            return;
+        }
+
+        self.pay_tournament_tokens(player.chain_id, prize);
+
+        if player.hand_app.is_some() {
+            self.runtime
+                .prepare_message(Message::TournamentFinished { place, prize })
+                .with_authentication()
+                .send_to(player.chain_id);
+        }
+    }
+
+    /// Tournament: move up the blind schedule once the current level is over
+    fn raise_blinds(&mut self) {
+        let tournament = match self.state.tournament.get() {
+            Some(tournament) => tournament.clone(),
+            None => return,
+        };
+        let current_block = self.runtime.block_height().0;
+        let start_block = match *self.state.tournament_start_block.get() {
+            Some(block) => block,
+            None => {
+                self.state.tournament_start_block.set(Some(current_block));
+                current_block
+            }
+        };
+        // A multi-table tournament's coordinator keeps every table on the same level
+        let level = if tournament.coordinator.is_some() {
+            *self.state.blind_level.get() as usize
+        } else {
+            tournament.level_index(
+                *self.state.hands_played.get(),
+                current_block.saturating_sub(start_block),
+            )
+        };
+        self.set_blind_level(level);
+    }
+
+    /// Sit-and-go: record a player's agreement to settle with a deal at the
+    /// end of the current hand; it lapses if the hand ends without everyone
+    fn handle_accept_deal(&mut self, player_chain: ChainId, kind: Option<DealKind>) {
+        if self.state.tournament.get().is_none()
+            || self.is_coordinated()
+            || self.state.tournament_start_block.get().is_none()
+            || !self.state.players.get().iter().any(|p| p.chain_id == player_chain)
+        {
+            return;
+        }
+
+        let game_id = *self.state.game_id.get();
+        let mut votes: Vec<(ChainId, DealKind, u64)> = self
+            .state
+            .deal_votes
+            .get()
+            .iter()
+            .filter(|(chain_id, _, game)| *chain_id != player_chain && *game == game_id)
+            .copied()
+            .collect();
+        if let Some(kind) = kind {
+            votes.push((player_chain, kind, game_id));
+        }
+        self.state.deal_votes.set(votes);
+    }
+
+    /// Sit-and-go: deal every player still in agreed to during this hand
+    fn agreed_deal(&self, seated: &[PlayerInfo]) -> Option<DealKind> {
+        if seated.len() < 2 || self.is_coordinated() {
+            return None;
+        }
+        let game_id = *self.state.game_id.get();
+        let votes = self.state.deal_votes.get();
+        let vote = |player: &PlayerInfo| {
+            votes
+                .iter()
+                .find(|(chain_id, _, game)| *chain_id == player.chain_id && *game == game_id)
+                .map(|(_, kind, _)| *kind)
+        };
+        let kind = vote(&seated[0])?;
+        seated
+            .iter()
+            .all(|player| vote(player) == Some(kind))
+            .then_some(kind)
+    }
+
+    /// Sit-and-go: split the prizes still to be paid by the deal, on the
+    /// stacks the hand ended with; bigger stacks take the higher places
+    fn settle_deal(&mut self, kind: DealKind, seated: &[PlayerInfo], entrants: usize) {
+        let prizes: Vec<Amount> = (1..=seated.len() as u32)
+            .map(|place| self.place_prize(place, entrants))
+            .collect();
+        let stacks: Vec<Amount> = seated.iter().map(|p| p.stack).collect();
+        let payouts = deal_payouts(kind, &stacks, &prizes);
+
+        let mut order: Vec<usize> = (0..seated.len()).collect();
+        order.sort_by(|a, b| seated[*b].stack.cmp(&seated[*a].stack));
+        for (place, idx) in (1..).zip(order) {
+            self.finish_tournament(&seated[idx], place, payouts[idx]);
         }
     }
 
-    /// Verify ZK reveal proof against stored commitments
+    /// Tournament: take a player's buy-in out of the tokens their chain has
+    /// transferred to this one, as recorded by the tournament's token app
     ///
-    /// Phase 4: Real Groth16 verification with embedded verifying key
-    /// Falls back to structural validation for empty proofs (Phase 3 compatibility)
-    fn verify_reveal_proof(
-        &self,
-        reveal_proof: &RevealProof,
-        stored_commitments: &[CardCommitment],
-    ) -> bool {
-        // Basic structural validation
-        if reveal_proof.cards.len() != 2 {
-            return false;
+    /// Without a token app there is nothing to claim.
+    fn claim_tournament_tokens(&mut self, from_chain: ChainId, amount: Amount) -> bool {
+        let tournament = self.state.tournament.get().as_ref();
+        let token_app = match tournament.and_then(|tournament| tournament.token_app) {
+            Some(token_app) => token_app,
+            None => return true,
+        };
+        let tokens = match u64::try_from(amount.to_attos() / Amount::ONE.to_attos()) {
+            Ok(tokens) if Amount::from_tokens(tokens.into()) == amount => tokens,
+            _ => return false,
+        };
+        let claimed = self.runtime.call_application(
+            /* authenticated */ true,
+            token_app.with_abi::<TokenAbi>(),
+            &TokenOperation::ClaimReceived {
+                from_chain,
+                amount: tokens,
+            },
+        );
+        matches!(claimed, TokenResult::Success)
+    }
+
+    /// Tournament: transfer tokens from the prize pool to a player's chain
+    /// through the token application, if the tournament has one
+    ///
+    /// Payments are whole tokens (only the winner's prize can carry a
+    /// fraction of the pool).
+    fn pay_tournament_tokens(&mut self, to_chain: ChainId, amount: Amount) {
+        let tournament = self.state.tournament.get().as_ref();
+        let token_app = match tournament.and_then(|tournament| tournament.token_app) {
+            Some(token_app) => token_app,
+            None => return,
+        };
+        let tokens = u64::try_from(amount.to_attos() / Amount::ONE.to_attos()).unwrap_or(0);
+        if tokens > 0 {
+            self.runtime.call_application(
+                /* authenticated */ true,
+                token_app.with_abi::<TokenAbi>(),
+                &TokenOperation::Transfer {
+                    to_chain,
+                    amount: tokens,
+                    game_id: *self.state.game_id.get(),
+                },
+            );
         }
+    }
+
+    /// Whether this is a multi-table tournament table run by a coordinator
+    fn is_coordinated(&self) -> bool {
+        self.state
+            .tournament
+            .get()
+            .as_ref()
+            .is_some_and(|tournament| tournament.coordinator.is_some())
+    }
+
+    /// Multi-table tournament: whether the coordinator chain sent this
+    /// (an operation counts as sent by the chain it runs on)
+    fn from_coordinator(&mut self) -> bool {
+        let coordinator = match self.state.tournament.get().as_ref() {
+            Some(tournament) => tournament.coordinator,
+            None => return false,
+        };
+        let origin = self
+            .runtime
+            .message_origin_chain_id()
+            .unwrap_or_else(|| self.runtime.chain_id());
+        coordinator == Some(origin)
+    }
 
-        if stored_commitments.len() != 2 {
+    /// Multi-table tournament: hold a seat for a player the coordinator
+    /// registered (and so collected the buy-in from), with the chips they bring
+    fn handle_reserve_seat(&mut self, player_chain: ChainId, chips: Amount) -> bool {
+        if !self.from_coordinator() {
             return false;
         }
+        let mut reserved = self.state.reserved_seats.get().clone();
+        reserved.retain(|(chain_id, _)| *chain_id != player_chain);
+        reserved.push((player_chain, chips));
+        self.state.reserved_seats.set(reserved);
+        true
+    }
 
-        // Verify card indices are valid (0-51)
-        for card in &reveal_proof.cards {
-            if card.to_index() >= 52 {
-                return false;
-            }
+    /// Multi-table tournament: seat a player in the seat the coordinator held
+    /// for them, starting a hand if the table was waiting for players
+    async fn handle_seat_player(
+        &mut self,
+        player_chain: ChainId,
+        hand_app: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    ) {
+        let mut reserved = self.state.reserved_seats.get().clone();
+        let chips = match reserved.iter().position(|(chain, _)| *chain == player_chain) {
+            Some(idx) => reserved.remove(idx).1,
+            None => return,
+        };
+        if !self.take_seat(player_chain, hand_app, chips, entropy_commitment, card_key) {
+            return;
+        }
+        self.state.reserved_seats.set(reserved);
+        if *self.state.phase.get() == GamePhase::WaitingForPlayers
+            && Self::dealt_in_count(self.state.players.get()) >= 2
+        {
+            self.deal_cards().await;
         }
+    }
 
-        // Phase 4: Real Groth16 verification
-        // Convert slice to fixed array for verification function
-        let commitments_array: [CardCommitment; 2] = [
-            stored_commitments[0].clone(),
-            stored_commitments[1].clone(),
-        ];
+    /// Multi-table tournament: move a player off this table, right away
+    /// between hands or after the current hand
+    fn handle_move_player(&mut self, player_chain: ChainId) {
+        if !self.is_coordinated() {
+            return;
+        }
 
-        // Use real Groth16 verification with embedded verifying key
-        // Falls back to structural validation if proof is empty (Phase 3 compatibility)
-        if reveal_proof.proof.is_empty() {
-            // Phase 3 mock mode: accept structurally valid proofs
-            true
+        let mut players = self.state.players.get().clone();
+        let idx = match players.iter().position(|p| p.chain_id == player_chain) {
+            Some(idx) => idx,
+            None => return,
+        };
+
+        if *self.state.phase.get() == GamePhase::WaitingForPlayers {
+            let player = players.remove(idx);
+            self.state.players.set(players);
+            self.record_departure(&player);
         } else {
-            // Phase 4: Real cryptographic verification
-            verify_reveal_proof_embedded(reveal_proof, &commitments_array)
+            players[idx].cashing_out = true;
+            self.state.players.set(players);
         }
     }
 
-    /// Determine winner using actual hand evaluation
-    /// FIX #2: CRITICAL - Add bounds checking to prevent panics
-    fn determine_winner(&mut self) {
-        let players = self.state.players.get();
+    /// Multi-table tournament: take a departing player's chips off the table
+    /// until the coordinator collects them
+    fn record_departure(&mut self, player: &PlayerInfo) {
+        let total_buy_ins = self.state.total_buy_ins.get().saturating_sub(player.stack);
+        self.state.total_buy_ins.set(total_buy_ins);
+        let entropy_commitment = self
+            .state
+            .entropy_commitments
+            .get()
+            .iter()
+            .find(|(chain_id, _)| *chain_id == player.chain_id)
+            .map(|(_, commitment)| *commitment)
+            .unwrap_or_default();
+        let mut departures = self.state.departures.get().clone();
+        departures.push(Departure {
+            chain_id: player.chain_id,
+            hand_app: player.hand_app,
+            chips: player.stack,
+            entropy_commitment,
+        });
+        self.state.departures.set(departures);
+    }
 
-        // FIX #2: Bounds check - need exactly 2 players
-        if players.len() < 2 {
-            return; // Cannot determine winner without 2 players
+    /// Multi-table tournament: stacks, knockouts and departures for the
+    /// coordinator; departures are handed over once
+    fn tournament_report(&mut self) -> TableReport {
+        if !self.is_coordinated() {
+            return TableReport::default();
         }
 
-        // Check for fold first
-        if players[0].has_folded {
-            self.state.winner.set(Some(Seat::Player2));
-            self.state.phase.set(GamePhase::Settlement);
-            return;
+        let mut finishes = self.state.finishes.get().clone();
+        finishes.sort_by(|a, b| b.place.cmp(&a.place));
+        let departures = self.state.departures.get().clone();
+        self.state.departures.set(Vec::new());
+        TableReport {
+            stacks: self
+                .state
+                .players
+                .get()
+                .iter()
+                .map(|p| (p.chain_id, p.stack))
+                .collect(),
+            knocked_out: finishes.iter().map(|finish| finish.chain_id).collect(),
+            departures,
+            hands_played: *self.state.hands_played.get(),
+            blind_level: *self.state.blind_level.get(),
         }
-        if players[1].has_folded {
-            self.state.winner.set(Some(Seat::Player1));
-            self.state.phase.set(GamePhase::Settlement);
-            return;
+    }
+
+    /// Tournament: play the blinds and ante of this level of the schedule
+    fn set_blind_level(&mut self, level: usize) {
+        let blinds = match self.state.tournament.get() {
+            Some(tournament) => match tournament.levels.get(level) {
+                Some(blinds) => *blinds,
+                None => return,
+            },
+            None => return,
+        };
+        self.state.blind_level.set(level as u32);
+        self.state
+            .small_blind
+            .set(Amount::from_tokens(blinds.small_blind.into()));
+        self.state
+            .big_blind
+            .set(Amount::from_tokens(blinds.big_blind.into()));
+        self.state.ante.set(Amount::from_tokens(blinds.ante.into()));
+    }
+
+    /// Pay a cashed-out player their stack
+    fn send_cash_out(&mut self, player: &PlayerInfo) {
+        if player.hand_app.is_some() {
+            self.runtime
+                .prepare_message(Message::CashedOut {
+                    amount: player.stack,
+                })
+                .with_authentication()
+                .send_to(player.chain_id);
         }
+    }
 
-        // Get revealed cards and community cards
-        let revealed = self.state.revealed_cards.get();
-        let community = self.state.community_cards.get();
+    /// Cash game: stop dealing the player in from the next hand
+    fn handle_sit_out(&mut self, player_chain: ChainId) {
+        let mut players = self.state.players.get().clone();
+        if let Some(player) = players.iter_mut().find(|p| p.chain_id == player_chain) {
+            player.sitting_out = true;
+            self.state.players.set(players);
+        }
+    }
 
-        // Find each player's hole cards
-        let p1_cards = revealed
-            .iter()
-            .find(|(s, _)| *s == Seat::Player1)
-            .map(|(_, c)| c.clone());
-        let p2_cards = revealed
-            .iter()
-            .find(|(s, _)| *s == Seat::Player2)
-            .map(|(_, c)| c.clone());
-
-        // FIX #9: MEDIUM - Implement pot splitting for ties
-        let winner = match (p1_cards, p2_cards) {
-            (Some(p1), Some(p2)) => {
-                // Actual hand evaluation!
-                let score1 = evaluate_hand(&p1, community);
-                let score2 = evaluate_hand(&p2, community);
-
-                match score1.cmp(&score2) {
-                    std::cmp::Ordering::Greater => Some(Seat::Player1),
-                    std::cmp::Ordering::Less => Some(Seat::Player2),
-                    std::cmp::Ordering::Equal => None, // FIX #9: Tie - split pot
-                }
-            }
-            (Some(_), None) => Some(Seat::Player1),
-            (None, Some(_)) => Some(Seat::Player2),
-            (None, None) => Some(Seat::Player1), // Default to Player1 if both missing
+    /// Cash game: deal the player in again, starting a hand if the table was short
+    async fn handle_sit_in(&mut self, player_chain: ChainId) {
+        let mut players = self.state.players.get().clone();
+        let player = match players.iter_mut().find(|p| p.chain_id == player_chain) {
+            Some(p) => p,
+            None => return,
         };
+        player.sitting_out = false;
+        self.state.players.set(players.clone());
 
-        self.state.winner.set(winner);
-        self.state.phase.set(GamePhase::Settlement);
+        if *self.state.cash_game.get()
+            && *self.state.phase.get() == GamePhase::WaitingForPlayers
+            && Self::dealt_in_count(&players) >= 2
+        {
+            self.deal_cards().await;
+        }
     }
 
-    /// Settle the game
-    /// FIX #9: MEDIUM - Handle pot splitting for ties
-    async fn settle_game(&mut self) {
-        let pot = *self.state.pot.get();
-        let game_id = *self.state.game_id.get();
-        let players = self.state.players.get().clone();
+    /// Cash game: add chips to a stack, right away between hands or after the current hand
+    fn handle_top_up(&mut self, player_chain: ChainId, amount: Amount) {
+        if !*self.state.cash_game.get() {
+            return;
+        }
 
-        // FIX #9: Handle tie case (winner = None means split pot)
-        let (payout_p1, payout_p2, is_tie) = match self.state.winner.get() {
-            Some(Seat::Player1) => (pot, Amount::ZERO, false),
-            Some(Seat::Player2) => (Amount::ZERO, pot, false),
-            None => {
-                // Split pot evenly for tie
-                // Use saturating_div to split pot in half
-                let half = pot.saturating_div(2);
-                (half, half, true)
-            }
+        let max_stake = *self.state.max_stake.get();
+        let mut players = self.state.players.get().clone();
+        let player = match players.iter_mut().find(|p| p.chain_id == player_chain) {
+            Some(p) => p,
+            None => return,
         };
 
-        for player in &players {
-            let (payout, you_won) = match player.seat {
-                Seat::Player1 => (payout_p1, !is_tie && payout_p1 > Amount::ZERO),
-                Seat::Player2 => (payout_p2, !is_tie && payout_p2 > Amount::ZERO),
-            };
+        if *self.state.phase.get() == GamePhase::WaitingForPlayers {
+            let new_stack = player.stack.saturating_add(amount).min(max_stake);
+            let added = new_stack.saturating_sub(player.stack);
+            player.stack = new_stack;
+            player.stake = new_stack;
+            let total_buy_ins = self.state.total_buy_ins.get().saturating_add(added);
+            self.state.total_buy_ins.set(total_buy_ins);
+        } else {
+            player.pending_top_up = player.pending_top_up.saturating_add(amount);
+        }
+        self.state.players.set(players);
+    }
 
-            if player.hand_app.is_some() {
-                self.runtime
-                    .prepare_message(Message::GameResult {
-                        game_id,
-                        you_won,
-                        payout,
-                        opponent_cards: None,
-                        forfeited: false, // Normal win, not timeout
-                    })
-                    .with_authentication()
-                    .send_to(player.chain_id);
-            }
+    /// Cash game: leave with the stack, right away between hands or after the current hand
+    fn handle_cash_out(&mut self, player_chain: ChainId) {
+        if !*self.state.cash_game.get() {
+            return;
         }
 
-        self.state.phase.set(GamePhase::Finished);
+        let mut players = self.state.players.get().clone();
+        let idx = match players.iter().position(|p| p.chain_id == player_chain) {
+            Some(idx) => idx,
+            None => return,
+        };
+
+        if *self.state.phase.get() == GamePhase::WaitingForPlayers {
+            let player = players.remove(idx);
+            let total_buy_ins = self.state.total_buy_ins.get().saturating_sub(player.stack);
+            self.state.total_buy_ins.set(total_buy_ins);
+            self.send_cash_out(&player);
+        } else {
+            players[idx].cashing_out = true;
+        }
+        self.state.players.set(players);
     }
 
     /// Notify current player it's their turn
@@ -852,14 +2857,26 @@ impl TableContract {
 
     /// Handle player leaving
     fn handle_leave(&mut self, player_chain: ChainId) {
+        // Leaving a cash game pays out the remaining stack
+        if *self.state.cash_game.get() {
+            self.handle_cash_out(player_chain);
+        }
+
         let mut players = self.state.players.get().clone();
         if let Some(idx) = players.iter().position(|p| p.chain_id == player_chain) {
             if *self.state.phase.get() == GamePhase::WaitingForPlayers {
                 let player = players.remove(idx);
-                let mut pot = *self.state.pot.get();
-                pot = pot.saturating_sub(player.stake);
-                self.state.pot.set(pot);
+                let total_buy_ins = self.state.total_buy_ins.get().saturating_sub(player.stack);
+                self.state.total_buy_ins.set(total_buy_ins);
                 self.state.players.set(players);
+                // Leaving a tournament before it starts takes the buy-in back out of the prize pool
+                if let Some(tournament) = self.state.tournament.get().clone() {
+                    if self.state.tournament_start_block.get().is_none() {
+                        let buy_in = Amount::from_tokens(tournament.buy_in.into());
+                        let prize_pool = self.state.prize_pool.get().saturating_sub(buy_in);
+                        self.state.prize_pool.set(prize_pool);
+                    }
+                }
             } else {
                 players[idx].has_folded = true;
                 self.state.players.set(players);
@@ -867,33 +2884,59 @@ impl TableContract {
         }
     }
 
-    /// Start new game
-    fn start_new_game(&mut self) {
+    /// Start new game, unseating everyone
+    ///
+    /// Stacks are real balances that persist across hands, so the table is
+    /// only reset by its creator once nobody holds chips.
+    fn start_new_game(&mut self) -> Result<(), TableError> {
+        let creator = *self.state.creator.get();
+        if creator.is_none() || self.runtime.authenticated_signer() != creator {
+            return Err(TableError::NotCreator);
+        }
+        let chips_held = self.state.players.get().iter().any(|p| p.stack > Amount::ZERO);
+        if chips_held || *self.state.pot.get() > Amount::ZERO {
+            return Err(TableError::ChipsAtTable);
+        }
+
+        self.reset_hand();
+        self.state.players.set(Vec::new());
+        self.state.entropy_commitments.set(Vec::new());
+        self.state.card_keys.clear();
+        self.state.total_buy_ins.set(Amount::ZERO);
+        Ok(())
+    }
+
+    /// Clear per-hand state and move on to the next game ID
+    fn reset_hand(&mut self) {
         let game_id = *self.state.game_id.get() + 1;
         self.state.game_id.set(game_id);
         self.state.phase.set(GamePhase::WaitingForPlayers);
-        self.state.players.set(Vec::new());
         self.state.pot.set(Amount::ZERO);
         self.state.current_bet.set(Amount::ZERO);
         self.state.community_cards.set(Vec::new());
+        self.state.board_positions.set(Vec::new());
+        self.state.up_cards.set(Vec::new());
+        self.state.draws.set(Vec::new());
+        self.state.deck_position.set(0);
         self.state.turn_seat.set(None);
-        self.state.winner.set(None);
+        self.state.winners.set(Vec::new());
+        self.state.pots.set(Vec::new());
+        self.state.awards.set(Vec::new());
+        self.state.uncalled_bet.set(None);
         self.state.revealed_cards.set(Vec::new());
-        // Reset dealer button (will be reassigned in deal_cards based on game_id)
-        self.state.dealer_button.set(None);
-        self.state.actions_this_round.set(0);
+        self.state.entropy_start_block.set(None);
+        self.state.mental_deal.set(None);
+        self.state.mental_start_block.set(None);
+        // Dealer button is kept so deal_cards can move it one seat clockwise
     }
 
-    /// Generate deck seed
+    /// Generate deck seed from the entropy the players dealt in revealed
     fn generate_deck_seed(&mut self) -> Vec<u8> {
-        let mut hasher = Sha256::new();
-        hasher.update(b"LINERA_POKER_DECK");
-        hasher.update(self.state.game_id.get().to_le_bytes());
-        hasher.update(self.runtime.chain_id().to_string().as_bytes());
-        for player in self.state.players.get().iter() {
-            hasher.update(player.chain_id.to_string().as_bytes());
-        }
-        hasher.finalize().to_vec()
+        deck_seed(
+            self.runtime.chain_id(),
+            *self.state.game_id.get(),
+            self.state.entropy_reveals.get(),
+        )
     }
 
     /// Generate dealer secret
@@ -913,90 +2956,106 @@ impl TableContract {
     // ZK HELPER FUNCTIONS (Phase 3: Production-Ready Privacy)
     // ========================================================================
 
-    /// Build Merkle tree root from shuffled deck
+    /// Leaves of the Merkle tree over the shuffled deck
     ///
-    /// Creates a 32-byte root commitment to the entire deck ordering.
-    /// This root is included in the DealingProof to bind the dealer
-    /// to the specific shuffle before cards are revealed.
-    fn build_merkle_root(deck: &[Card]) -> [u8; 32] {
-        // Build leaf hashes for each card
-        let mut leaves: Vec<[u8; 32]> = Vec::with_capacity(deck.len());
-        for card in deck {
-            let mut hasher = Sha256::new();
-            hasher.update(&[card.to_index()]);
-            let leaf: [u8; 32] = hasher.finalize().into();
-            leaves.push(leaf);
-        }
-
-        // Build Merkle tree bottom-up
-        while leaves.len() > 1 {
-            let mut new_leaves = Vec::with_capacity((leaves.len() + 1) / 2);
-            for chunk in leaves.chunks(2) {
-                let mut hasher = Sha256::new();
-                hasher.update(&chunk[0]);
-                if chunk.len() > 1 {
-                    hasher.update(&chunk[1]);
-                } else {
-                    // Odd number of leaves - duplicate the last one
-                    hasher.update(&chunk[0]);
-                }
-                let parent: [u8; 32] = hasher.finalize().into();
-                new_leaves.push(parent);
-            }
-            leaves = new_leaves;
-        }
+    /// Its root is a 32-byte commitment to the entire deck ordering, included
+    /// in the DealingProof to bind the dealer to the specific shuffle before
+    /// cards are revealed. Board cards are opened against it.
+    fn deck_leaves(&self, deck: &[Card]) -> Vec<[u8; 32]> {
+        deck.iter()
+            .enumerate()
+            .map(|(position, card)| {
+                deck_leaf(position as u8, *card, &self.leaf_salt(position as u8))
+            })
+            .collect()
+    }
 
-        leaves.get(0).copied().unwrap_or([0u8; 32])
+    /// Salt of the deck leaf at `position`, from the players' entropy rather
+    /// than the deck seed: a salt is only published with its card, so the
+    /// leaves of cards still face down can't be tried against the 52 cards
+    fn leaf_salt(&self, position: u8) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(b"LINERA_POKER_LEAF");
+        for (seat, entropy) in self.state.entropy_reveals.get() {
+            hasher.update([seat.index() as u8]);
+            hasher.update(entropy);
+        }
+        hasher.update([position]);
+        hasher.finalize().into()
     }
 
-    /// Generate Pedersen-style commitments for cards
+    /// Generate Pedersen-style commitments for cards dealt to a player
     ///
-    /// Returns a tuple of (commitments, blinding_factors) where:
+    /// Returns a tuple of (commitments, sealed_openings) where:
     /// - commitments: CardCommitment structs for each card
-    /// - blinding_factors: The random values used (needed for reveal proof)
+    /// - sealed_openings: each card and the blinding factor that opens its
+    ///   commitment, sealed to the player's card key so only they can read
+    ///   them (needed for the reveal proof)
+    ///
+    /// `first_index` is the position of the first card among the player's
+    /// cards this hand, so cards dealt on later streets get fresh nonces.
     ///
     /// In Phase 3, this uses SHA256-based commitments as a placeholder.
     /// Phase 4 will upgrade to true BLS12-381 Pedersen commitments.
-    fn commit_cards(&mut self, cards: &[Card], game_id: u64) -> (Vec<CardCommitment>, Vec<Vec<u8>>) {
+    async fn commit_cards(
+        &mut self,
+        player_chain: ChainId,
+        cards: &[Card],
+        first_index: usize,
+        game_id: u64,
+    ) -> (Vec<CardCommitment>, Vec<SealedOpening>) {
+        let card_key = self
+            .state
+            .card_keys
+            .get(&player_chain)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        // The blinding comes from the player's entropy: the player can
+        // recompute it, and so can anyone replaying this chain's inputs
+        let seat = self
+            .state
+            .players
+            .get()
+            .iter()
+            .find(|p| p.chain_id == player_chain)
+            .map(|p| p.seat);
+        let entropy = self
+            .state
+            .entropy_reveals
+            .get()
+            .iter()
+            .find(|(s, _)| Some(*s) == seat)
+            .map(|(_, entropy)| *entropy)
+            .unwrap_or_default();
         let mut commitments = Vec::with_capacity(cards.len());
-        let mut blinding_factors = Vec::with_capacity(cards.len());
+        let mut sealed_openings = Vec::with_capacity(cards.len());
 
         for (idx, card) in cards.iter().enumerate() {
-            // Generate deterministic but unique nonce for each card
-            // In production, this should use proper randomness
+            // The nonce is public with the commitment; it only keeps
+            // commitments unique across games and cards
             let mut nonce_hasher = Sha256::new();
             nonce_hasher.update(b"LINERA_POKER_NONCE");
             nonce_hasher.update(game_id.to_le_bytes());
             nonce_hasher.update(self.runtime.chain_id().to_string().as_bytes());
-            nonce_hasher.update([idx as u8]);
+            nonce_hasher.update([(first_index + idx) as u8]);
             let nonce_hash: [u8; 32] = nonce_hasher.finalize().into();
             let nonce: [u8; 16] = nonce_hash[..16].try_into().unwrap_or([0u8; 16]);
 
-            // Generate blinding factor (used in Pedersen commitment)
-            let mut blinding_hasher = Sha256::new();
-            blinding_hasher.update(b"LINERA_POKER_BLINDING");
-            blinding_hasher.update(game_id.to_le_bytes());
-            blinding_hasher.update(self.runtime.chain_id().to_string().as_bytes());
-            blinding_hasher.update([card.to_index()]);
-            blinding_hasher.update(&nonce);
-            let blinding: Vec<u8> = blinding_hasher.finalize().to_vec();
+            let blinding = card_blinding(&entropy, game_id, (first_index + idx) as u8);
 
             // Create commitment: H(card_index || blinding || nonce)
-            let mut commit_hasher = Sha256::new();
-            commit_hasher.update([card.to_index()]);
-            commit_hasher.update(&blinding);
-            commit_hasher.update(&nonce);
-            let commitment_bytes: Vec<u8> = commit_hasher.finalize().to_vec();
+            commitments.push(commit_card(*card, &blinding, nonce));
 
-            // Pad to 48 bytes (BLS12-381 G1 point size) for Phase 2 compatibility
-            let mut padded_commitment = commitment_bytes.clone();
-            padded_commitment.resize(CardCommitment::COMMITMENT_SIZE, 0);
-
-            commitments.push(CardCommitment::new(padded_commitment, nonce));
-            blinding_factors.push(blinding);
+            // Seal the opening, with encryption randomness unique to the card
+            // (keeps it out of the table's queryable state, not off the chain)
+            let mut seed = blinding.clone();
+            seed.extend_from_slice(&nonce);
+            sealed_openings.extend(seal_opening(&card_key, *card, &blinding, &seed));
         }
 
-        (commitments, blinding_factors)
+        (commitments, sealed_openings)
     }
 
     /// Check if current player's betting turn has timed out
@@ -1029,9 +3088,138 @@ impl TableContract {
         current_block >= showdown_start + timeout_config.reveal_timeout_blocks as u64
     }
 
-    /// Mark a player as forfeited and award pot to opponent
+    /// Check if the players dealt in have had too long to reveal their entropy
+    fn check_entropy_timeout(&mut self) -> bool {
+        let entropy_start = match *self.state.entropy_start_block.get() {
+            Some(block) => block,
+            None => return false,
+        };
+
+        let current_block = self.runtime.block_height().0;
+        let timeout_config = self.state.timeout_config.get().clone();
+
+        if !timeout_config.auto_forfeit_enabled {
+            return false;
+        }
+
+        current_block >= entropy_start + timeout_config.entropy_timeout_blocks as u64
+    }
+
+    /// Check if the players have had too long for their step of a dealerless deal
+    fn check_mental_timeout(&mut self) -> bool {
+        let mental_start = match *self.state.mental_start_block.get() {
+            Some(block) => block,
+            None => return false,
+        };
+
+        let current_block = self.runtime.block_height().0;
+        let timeout_config = self.state.timeout_config.get().clone();
+
+        if !timeout_config.auto_forfeit_enabled {
+            return false;
+        }
+
+        current_block >= mental_start + timeout_config.entropy_timeout_blocks as u64
+    }
+
+    /// Dealerless: forfeit whoever is holding up the deal or the board
+    ///
+    /// While dealing, the deal starts over without them: the other players
+    /// keep their key shares and shuffle a fresh deck. A board that can no
+    /// longer be opened ends the hand, every pot split among the players
+    /// eligible for it; whoever held it back, folded or not, forfeits their
+    /// stack to the players left in the hand, so withholding a share never
+    /// pays.
+    async fn handle_mental_timeout(&mut self, game_id: u64) {
+        let mut deal = match self.state.mental_deal.get().clone() {
+            Some(deal) => deal,
+            None => return,
+        };
+        let dealing = *self.state.phase.get() == GamePhase::Dealing;
+        let owing = if dealing {
+            deal.dealing_owed()
+        } else {
+            let (from, to) = self.board_to_open();
+            deal.board_owed(from, to)
+        };
+
+        let players = self.state.players.get().clone();
+        for player in players.iter().filter(|p| !p.has_folded && owing.contains(&p.seat)) {
+            self.auto_forfeit(player.chain_id).await;
+            // The last player left took the pot
+            if *self.state.game_id.get() != game_id
+                || matches!(*self.state.phase.get(), GamePhase::Settlement | GamePhase::Finished)
+            {
+                return;
+            }
+        }
+
+        if dealing {
+            for seat in &owing {
+                deal.drop_seat(*seat);
+            }
+            self.state.mental_deal.set(Some(deal));
+            let current_block = self.runtime.block_height().0;
+            self.state.mental_start_block.set(Some(current_block));
+            self.request_mental_step();
+        } else {
+            let forfeited = self.forfeit_stacks(&owing);
+            self.state.mental_start_block.set(None);
+            self.determine_winner();
+            self.award_forfeited_stacks(forfeited);
+            self.settle_game(true).await;
+        }
+    }
+
+    /// Take the whole stack of every seat in `seats`; returns the chips taken
+    fn forfeit_stacks(&mut self, seats: &[Seat]) -> Amount {
+        let mut players = self.state.players.get().clone();
+        let mut forfeited = Amount::ZERO;
+        for player in players.iter_mut().filter(|p| seats.contains(&p.seat)) {
+            forfeited = forfeited.saturating_add(player.stack);
+            player.stack = Amount::ZERO;
+        }
+        self.state.players.set(players);
+        forfeited
+    }
+
+    /// Share forfeited chips among the players still in the hand, as part of
+    /// the main pot
+    fn award_forfeited_stacks(&mut self, amount: Amount) {
+        if amount == Amount::ZERO {
+            return;
+        }
+        let remaining: Vec<Seat> = self
+            .state
+            .players
+            .get()
+            .iter()
+            .filter(|p| !p.has_folded)
+            .map(|p| p.seat)
+            .collect();
+        let share = Pot {
+            amount,
+            eligible: remaining.clone(),
+            winners: remaining,
+        };
+        let button = *self.state.dealer_button.get();
+        let mut awards = self.state.awards.get().clone();
+        awards.extend(
+            split_pot(&share, button)
+                .into_iter()
+                .map(|(seat, amount)| PotAward { pot: 0, seat, amount }),
+        );
+        self.state.awards.set(awards);
+
+        let mut pots = self.state.pots.get().clone();
+        if let Some(main) = pots.first_mut() {
+            main.amount = main.amount.saturating_add(amount);
+        }
+        self.state.pots.set(pots);
+    }
+
+    /// Mark a player as forfeited; the last player left in the hand wins the pot
     async fn auto_forfeit(&mut self, player_chain: ChainId) {
-        let game_id = *self.state.game_id.get();
         let mut players = self.state.players.get().clone();
 
         // Find and mark player as folded
@@ -1051,43 +3239,31 @@ impl TableContract {
             self.state.timed_out_players.set(timed_out);
         }
 
-        // Award pot to opponent
-        let winner_seat = forfeited_seat.other();
-        let pot = *self.state.pot.get();
-        self.state.winner.set(Some(winner_seat));
-
-        // Notify winner
-        if let Some(winner) = players.iter().find(|p| p.seat == winner_seat) {
-            if winner.hand_app.is_some() {
-                self.runtime
-                    .prepare_message(Message::GameResult {
-                        game_id,
-                        you_won: true,
-                        payout: pot,
-                        opponent_cards: None,
-                        forfeited: true, // Opponent was auto-forfeited
-                    })
-                    .with_authentication()
-                    .send_to(winner.chain_id);
+        let remaining: Vec<Seat> = players
+            .iter()
+            .filter(|p| !p.has_folded)
+            .map(|p| p.seat)
+            .collect();
+
+        // Several players still in the hand: the forfeited player just drops out
+        // and hears the result at settlement
+        if remaining.len() > 1 {
+            if *self.state.phase.get() == GamePhase::Showdown {
+                if players.iter().all(|p| p.has_folded || p.has_revealed) {
+                    self.determine_winner();
+                    self.settle_game(false).await;
+                }
+            } else if *self.state.phase.get() != GamePhase::Dealing
+                && *self.state.turn_seat.get() == Some(forfeited_seat)
+            {
+                self.advance_turn().await;
             }
+            return;
         }
 
-        // Notify loser (forfeited player)
-        let loser = &players[player_idx];
-        if loser.hand_app.is_some() {
-            self.runtime
-                .prepare_message(Message::GameResult {
-                    game_id,
-                    you_won: false,
-                    payout: Amount::ZERO,
-                    opponent_cards: None,
-                    forfeited: true, // You were auto-forfeited
-                })
-                .with_authentication()
-                .send_to(loser.chain_id);
-        }
-
-        self.state.phase.set(GamePhase::Finished);
+        // Award every pot to the last remaining player
+        self.determine_winner();
+        self.settle_game(true).await;
     }
 
     /// Handle timeout check - can be triggered by anyone (permissionless)
@@ -1096,10 +3272,18 @@ impl TableContract {
             return;
         }
 
+        // Dealerless: waiting on the players to deal or to open the board
+        if self.state.mental_start_block.get().is_some() {
+            if self.check_mental_timeout() {
+                self.handle_mental_timeout(game_id).await;
+            }
+            return;
+        }
+
         let phase = *self.state.phase.get();
 
         match phase {
-            GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn | GamePhase::River => {
+            phase if phase.is_betting_round() || phase == GamePhase::Draw => {
                 if self.check_betting_timeout() {
                     // Find current player and forfeit them
                     if let Some(seat) = *self.state.turn_seat.get() {
@@ -1112,6 +3296,28 @@ impl TableContract {
                     }
                 }
             }
+            GamePhase::Dealing => {
+                if self.check_entropy_timeout() {
+                    // Withholding entropy forfeits the hand; the deck is then
+                    // shuffled with the entropy the other players revealed
+                    let players = self.state.players.get().clone();
+                    let dealt_in = self.state.dealt_in.get().clone();
+                    let reveals = self.state.entropy_reveals.get().clone();
+                    for player in &players {
+                        if !player.has_folded
+                            && dealt_in.contains(&player.seat)
+                            && reveals.iter().all(|(seat, _)| *seat != player.seat)
+                        {
+                            self.auto_forfeit(player.chain_id).await;
+                            // The last player left took the pot
+                            if *self.state.game_id.get() != game_id {
+                                return;
+                            }
+                        }
+                    }
+                }
+                self.deal_if_revealed().await;
+            }
             GamePhase::Showdown => {
                 if self.check_reveal_timeout() {
                     // Find players who haven't revealed and forfeit them
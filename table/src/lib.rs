@@ -8,7 +8,10 @@ use linera_sdk::linera_base_types::{Amount, ApplicationId, ChainId, ContractAbi,
 use serde::{Deserialize, Serialize};
 
 pub use linera_poker_shared::{
-    BetAction, Card, CardReveal, EncryptedCard, GamePhase, PlayerInfo, Seat, TableState,
+    BetAction, BettingStructure, BlindLevel, Card, CardReveal, DealKind, Departure,
+    EncryptedCard, GamePhase, HandRank, JackpotConfig, JackpotHit, LevelDuration, MentalResponse,
+    PlayerInfo, PokerVariant, Pot, PotAward, RakeConfig, Seat, TableReport, TableState,
+    TournamentConfig, TournamentFinish,
 };
 
 /// Table contract ABI
@@ -29,12 +32,14 @@ impl ServiceAbi for TableAbi {
 pub enum TableResult {
     Success,
     Error(TableError),
+    /// Multi-table tournament table's standings, for its coordinator
+    TournamentReport(TableReport),
 }
 
 /// Table errors
 #[derive(Debug, Clone, Serialize, Deserialize, thiserror::Error)]
 pub enum TableError {
-    #[error("Game is full (2 players max)")]
+    #[error("Table is full")]
     GameFull,
     #[error("Invalid game phase for this action")]
     InvalidPhase,
@@ -50,6 +55,12 @@ pub enum TableError {
     InsufficientStake,
     #[error("Invalid card reveal")]
     InvalidReveal,
+    #[error("Not the tournament coordinator")]
+    NotCoordinator,
+    #[error("Only the table creator can do this")]
+    NotCreator,
+    #[error("Players still hold chips at the table")]
+    ChipsAtTable,
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -57,17 +68,26 @@ pub enum TableError {
 /// Table operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TableOperation {
-    /// Start a new game (reset table)
+    /// Start a new game (reset table): only the table creator, once no
+    /// player holds chips
     StartNewGame,
     /// Force advance phase (testing only)
     ForceAdvance,
+    /// Deal a hand to the players currently seated (needs at least 2).
+    ///
+    /// Hands are dealt automatically once every seat is taken; this lets
+    /// short-handed tables start without waiting for the table to fill.
+    StartHand,
 
     // Player actions (relayed from hand app on table chain)
-    /// Player joins table with stake (relayed message)
+    /// Player joins table with stake, their entropy commitment and the card
+    /// key their cards are sealed to (relayed message)
     RelayJoinTable {
         player_chain: ChainId,
         stake: Amount,
         hand_app_id: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
     },
     /// Player's betting action (relayed message)
     RelayBetAction {
@@ -75,6 +95,12 @@ pub enum TableOperation {
         game_id: u64,
         action: BetAction,
     },
+    /// Player discards and draws replacements (relayed message)
+    RelayDraw {
+        player_chain: ChainId,
+        game_id: u64,
+        discarded: Vec<u8>,
+    },
     /// Player reveals cards (relayed message)
     RelayRevealCards {
         player_chain: ChainId,
@@ -86,6 +112,21 @@ pub enum TableOperation {
     RelayLeaveTable { player_chain: ChainId },
     /// Player acknowledges cards received (relayed message)
     RelayCardsReceived { player_chain: ChainId, game_id: u64 },
+    /// Player reveals their committed deck entropy and commits to the next
+    /// (relayed message)
+    RelayRevealEntropy {
+        player_chain: ChainId,
+        game_id: u64,
+        entropy: [u8; 32],
+        next_commitment: [u8; 32],
+    },
+    /// Player's key share, shuffle or decryption shares for a dealerless
+    /// hand (relayed message)
+    RelayMental {
+        player_chain: ChainId,
+        game_id: u64,
+        response: MentalResponse,
+    },
 
     // ========================================================================
     // TIMEOUT & LIVENESS OPERATIONS (Phase 3: Anti-Griefing)
@@ -94,12 +135,60 @@ pub enum TableOperation {
     /// Trigger timeout check - can be called by anyone (permissionless)
     ///
     /// This operation allows any observer to trigger a timeout check for a game.
-    /// If the current player has exceeded their betting timeout or a player
-    /// in showdown has exceeded their reveal timeout, they will be auto-forfeited.
+    /// If the current player has exceeded their betting timeout, a player
+    /// in showdown has exceeded their reveal timeout, or a player dealt in
+    /// has withheld their deck entropy (or their part of a dealerless deal)
+    /// too long, they will be auto-forfeited.
     ///
     /// This is a key anti-griefing mechanism that ensures the game progresses
     /// even if a player becomes unresponsive or intentionally stalls.
     TriggerTimeoutCheck { game_id: u64 },
+
+    // ========================================================================
+    // CASH-GAME SESSION OPERATIONS (relayed from hand app on table chain)
+    // ========================================================================
+
+    /// Player stops being dealt in but keeps their seat and stack
+    RelaySitOut { player_chain: ChainId },
+    /// Player is dealt in again from the next hand
+    RelaySitIn { player_chain: ChainId },
+    /// Player adds chips to their stack (applied between hands)
+    RelayTopUp {
+        player_chain: ChainId,
+        amount: Amount,
+    },
+    /// Player leaves with their stack (after the current hand)
+    RelayCashOut { player_chain: ChainId },
+    /// Sit-and-go: player agrees to settle with a deal at the end of this
+    /// hand (None withdraws)
+    RelayAcceptDeal {
+        player_chain: ChainId,
+        kind: Option<DealKind>,
+    },
+
+    // ========================================================================
+    // MULTI-TABLE TOURNAMENT OPERATIONS (relayed from the coordinator app on table chain)
+    // ========================================================================
+
+    /// Hold a seat for a registered player with the chips they bring
+    /// (coordinator only)
+    RelayReserveSeat {
+        player_chain: ChainId,
+        chips: Amount,
+    },
+    /// Seat a tournament player in the seat held for them (dealt in from the next hand)
+    RelaySeatPlayer {
+        player_chain: ChainId,
+        hand_app_id: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    },
+    /// Move a player off this table with their chips (after the current hand)
+    RelayMovePlayer { player_chain: ChainId },
+    /// Play this level of the blind schedule from the next hand
+    RelaySetBlindLevel { level: u32 },
+    /// Report stacks, knockouts and departures to the coordinator
+    ReportTournament,
 }
 
 /// Instantiation argument
@@ -107,10 +196,59 @@ pub enum TableOperation {
 pub struct InstantiationArgument {
     pub min_stake: u64,
     pub max_stake: u64,
-    /// Small blind amount (button posts this)
+    /// Small blind amount (seat left of the button posts this; the button when heads-up).
+    /// In stud this is the bring-in posted by the lowest up card.
     pub small_blind: u64,
-    /// Big blind amount (non-button posts this)
+    /// Big blind amount (seat left of the small blind posts this).
+    /// In stud there are no blinds and this sizes the opening raise.
     pub big_blind: u64,
+    /// Number of seats at the table (2 = heads-up, 6 = 6-max, 9 = full ring;
+    /// stud seats at most 7 and draw at most 6)
+    #[serde(default = "default_max_players")]
+    pub max_players: u8,
+    /// Cash game: players stay seated between hands with their remaining
+    /// stack, and the next hand starts as soon as the previous one finishes
+    #[serde(default)]
+    pub cash_game: bool,
+    /// No-limit (default), pot-limit or fixed-limit betting
+    #[serde(default)]
+    pub betting: BettingStructure,
+    /// Ante posted by every player dealt in (0 = no ante)
+    #[serde(default)]
+    pub ante: u64,
+    /// Big-blind ante: the big blind posts `ante` once for the whole table
+    /// instead of every player posting it
+    #[serde(default)]
+    pub big_blind_ante: bool,
+    /// Straddle posted by the seat left of the big blind (0 = no straddle).
+    /// Only applies with three or more players and when larger than the big blind.
+    #[serde(default)]
+    pub straddle: u64,
+    /// Hold'em (default), Omaha, short deck, seven-card stud, five-card draw,
+    /// Omaha hi/lo or razz; Pot-Limit Omaha is `Omaha` with `PotLimit` betting
+    #[serde(default)]
+    pub variant: PokerVariant,
+    /// Mixed game: variants dealt in turn, switching after every orbit
+    /// (e.g. Hold'em, Omaha, stud). Overrides `variant` when not empty.
+    #[serde(default)]
+    pub mixed_games: Vec<PokerVariant>,
+    /// Sit-and-go tournament: buy-in, starting chips, blind schedule and
+    /// payouts. Replaces the stakes and blinds above (None = not a tournament).
+    #[serde(default)]
+    pub tournament: Option<TournamentConfig>,
+    /// House fee taken from every pot and paid to a fee recipient
+    /// (None = no rake; tournaments are never raked)
+    #[serde(default)]
+    pub rake: Option<RakeConfig>,
+    /// Dealerless: the players shuffle and open the cards among themselves
+    /// (mental poker), so no chain sees a card before it is opened. Flop
+    /// games only; ignored if any variant dealt has no board.
+    #[serde(default)]
+    pub dealerless: bool,
+}
+
+fn default_max_players() -> u8 {
+    2
 }
 
 // Re-export unified Message from shared crate for cross-chain messaging
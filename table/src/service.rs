@@ -6,8 +6,11 @@ use std::sync::Arc;
 
 use self::state::TableState;
 use async_graphql::{EmptySubscription, Enum, InputObject, Object, Request, Response, Schema};
-use linera_poker_shared::{Rank, Suit};
-use linera_poker_table::{BetAction, Card, CardReveal, TableAbi, TableOperation};
+use hex::FromHex;
+use linera_poker_shared::{
+    build_pots, deal_payouts, icm_equity, prize_payouts, DealKind, Rank, StreetBetting, Suit,
+};
+use linera_poker_table::{BetAction, Card, CardReveal, GamePhase, Seat, TableAbi, TableOperation};
 use linera_sdk::{
     linera_base_types::{Amount, ApplicationId, ChainId, WithServiceAbi},
     views::View,
@@ -73,12 +76,17 @@ impl QueryRoot {
                     seat: format!("{:?}", p.seat),
                     chain_id: p.chain_id.to_string(),
                     stake: p.stake.to_string(),
+                stack: p.stack.to_string(),
                     has_folded: p.has_folded,
                     current_bet: p.current_bet.to_string(),
                     has_revealed: p.has_revealed,
+                    total_contribution: p.total_contribution.to_string(),
+                    sitting_out: p.sitting_out,
+                    cashing_out: p.cashing_out,
                 })
                 .collect(),
             pot: self.state.pot.get().to_string(),
+            total_buy_ins: self.state.total_buy_ins.get().to_string(),
             current_bet: self.state.current_bet.get().to_string(),
             min_raise: self.state.min_raise.get().to_string(),
             community_cards: self
@@ -91,14 +99,47 @@ impl QueryRoot {
                     rank: format!("{:?}", c.rank),
                 })
                 .collect(),
+            up_cards: self.up_card_views(),
+            draws: self
+                .state
+                .draws
+                .get()
+                .iter()
+                .map(|(seat, cards)| DrawView {
+                    seat: format!("{:?}", seat),
+                    cards: *cards,
+                })
+                .collect(),
             turn_seat: self.state.turn_seat.get().map(|s| format!("{:?}", s)),
-            winner: self.state.winner.get().map(|s| format!("{:?}", s)),
+            winner: single_winner(self.state.winners.get()),
+            winners: self
+                .state
+                .winners
+                .get()
+                .iter()
+                .map(|s| format!("{:?}", s))
+                .collect(),
+            max_players: *self.state.max_players.get(),
+            cash_game: *self.state.cash_game.get(),
+            betting: format!("{:?}", self.state.betting.get()),
+            variant: format!("{:?}", self.state.variant.get()),
+            mixed_games: self
+                .state
+                .mixed_games
+                .get()
+                .iter()
+                .map(|v| format!("{:?}", v))
+                .collect(),
+            pots: self.pot_views(),
             min_stake: self.state.min_stake.get().to_string(),
             max_stake: self.state.max_stake.get().to_string(),
             small_blind: self.state.small_blind.get().to_string(),
             big_blind: self.state.big_blind.get().to_string(),
+            ante: self.state.ante.get().to_string(),
+            big_blind_ante: *self.state.big_blind_ante.get(),
+            straddle: self.state.straddle.get().to_string(),
             dealer_button: self.state.dealer_button.get().map(|s| format!("{:?}", s)),
-            deck_seed: self.state.deck_seed.get().clone(),
+            deck_seed: self.finished_deck_seed(),
             // REMOVED: dealer_secret (security improvement - no longer exposed via GraphQL)
         }
     }
@@ -128,9 +169,13 @@ impl QueryRoot {
                 seat: format!("{:?}", p.seat),
                 chain_id: p.chain_id.to_string(),
                 stake: p.stake.to_string(),
+                stack: p.stack.to_string(),
                 has_folded: p.has_folded,
                 current_bet: p.current_bet.to_string(),
                 has_revealed: p.has_revealed,
+                total_contribution: p.total_contribution.to_string(),
+                sitting_out: p.sitting_out,
+                cashing_out: p.cashing_out,
             })
             .collect()
     }
@@ -140,12 +185,53 @@ impl QueryRoot {
         self.state.turn_seat.get().map(|s| format!("{:?}", s))
     }
 
-    /// Get winner
+    /// Legal bet sizes for the player to act
+    async fn legal_bet(&self) -> Option<LegalBetView> {
+        let seat = (*self.state.turn_seat.get())?;
+        let players = self.state.players.get();
+        let player = players.iter().find(|p| p.seat == seat)?;
+        let current_bet = *self.state.current_bet.get();
+        let limits = self.state.betting.get().raise_limits(
+            player,
+            &StreetBetting {
+                current_bet,
+                min_raise: *self.state.min_raise.get(),
+                pot: *self.state.pot.get(),
+                raises: *self.state.raises_this_street.get(),
+            },
+        );
+        Some(LegalBetView {
+            seat: format!("{:?}", seat),
+            to_call: current_bet
+                .saturating_sub(player.current_bet)
+                .min(player.stack)
+                .to_string(),
+            min_raise_to: limits.map(|(min, _)| min.to_string()),
+            max_raise_to: limits.map(|(_, max)| max.to_string()),
+        })
+    }
+
+    /// Get winner (None when the pot was split)
     async fn winner(&self) -> Option<String> {
-        self.state.winner.get().map(|s| format!("{:?}", s))
+        single_winner(self.state.winners.get())
+    }
+
+    /// Get all winning seats
+    async fn winners(&self) -> Vec<String> {
+        self.state
+            .winners
+            .get()
+            .iter()
+            .map(|s| format!("{:?}", s))
+            .collect()
+    }
+
+    /// Get main pot and side pots (live during the hand, with winners after showdown)
+    async fn pots(&self) -> Vec<PotView> {
+        self.pot_views()
     }
 
-    /// Get community cards
+    /// Get community cards opened so far
     async fn community_cards(&self) -> Vec<CardView> {
         self.state
             .community_cards
@@ -157,6 +243,284 @@ impl QueryRoot {
             })
             .collect()
     }
+
+    /// Deck positions of the board, committed to when the hand was dealt
+    async fn board_positions(&self) -> Vec<u8> {
+        self.state.board_positions.get().clone()
+    }
+
+    /// Merkle root of the shuffled deck (hex) the board is opened against
+    async fn deck_root(&self) -> String {
+        hex::encode(self.state.deck_root.get())
+    }
+
+    /// Get every seat's face-up cards (seven-card stud)
+    async fn up_cards(&self) -> Vec<UpCardsView> {
+        self.up_card_views()
+    }
+
+    /// Get the sit-and-go tournament: prize pool, blind level and finishers
+    async fn tournament(&self) -> Option<TournamentView> {
+        let tournament = self.state.tournament.get().as_ref()?;
+        let prize_pool = *self.state.prize_pool.get();
+        let finishes = self.state.finishes.get();
+        let entrants = finishes.len() + self.state.players.get().len();
+        Some(TournamentView {
+            buy_in: Amount::from_tokens(tournament.buy_in.into()).to_string(),
+            starting_chips: Amount::from_tokens(tournament.starting_chips.into()).to_string(),
+            prize_pool: prize_pool.to_string(),
+            prizes: prize_payouts(prize_pool, &tournament.payouts, entrants)
+                .iter()
+                .map(|prize| prize.to_string())
+                .collect(),
+            hands_played: *self.state.hands_played.get(),
+            level: *self.state.blind_level.get(),
+            finishes: finishes
+                .iter()
+                .map(|finish| TournamentFinishView {
+                    seat: format!("{:?}", finish.seat),
+                    chain_id: finish.chain_id.to_string(),
+                    place: finish.place,
+                    prize: finish.prize.to_string(),
+                })
+                .collect(),
+            departures: self
+                .state
+                .departures
+                .get()
+                .iter()
+                .map(|departure| DepartureView {
+                    chain_id: departure.chain_id.to_string(),
+                    chips: departure.chips.to_string(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Get the rake settings and the rake taken, last hand and in total
+    async fn rake(&self) -> Option<RakeView> {
+        let rake = self.state.rake.get().as_ref()?;
+        Some(RakeView {
+            percent: rake.percent,
+            cap: Amount::from_tokens(rake.cap.into()).to_string(),
+            no_flop_no_drop: rake.no_flop_no_drop,
+            fee_recipient: rake.fee_recipient.to_string(),
+            hand_rake: self.state.hand_rake.get().to_string(),
+            collected: self.state.rake_collected.get().to_string(),
+            owed: self.state.rake_owed.get().to_string(),
+        })
+    }
+
+    /// Get the deck entropy: the commitment each player holds for their next
+    /// hand, the seats that revealed for the current one, and the deck seed
+    /// once the hand is over (`deck_seed` over the reveals)
+    ///
+    /// The entropy itself stays private: the cards' blinding factors and the
+    /// board's leaf salts come from it.
+    async fn entropy(&self) -> EntropyView {
+        let reveals = self.state.entropy_reveals.get();
+        let dealt_in = self.state.dealt_in.get();
+        let waiting_for = match self.state.entropy_start_block.get() {
+            Some(_) => self
+                .state
+                .players
+                .get()
+                .iter()
+                .filter(|p| !p.has_folded && dealt_in.contains(&p.seat))
+                .filter(|p| reveals.iter().all(|(seat, _)| *seat != p.seat))
+                .map(|p| format!("{:?}", p.seat))
+                .collect(),
+            None => Vec::new(),
+        };
+        EntropyView {
+            commitments: self
+                .state
+                .entropy_commitments
+                .get()
+                .iter()
+                .map(|(chain_id, commitment)| EntropyCommitmentView {
+                    chain_id: chain_id.to_string(),
+                    commitment: hex::encode(commitment),
+                })
+                .collect(),
+            reveals: reveals
+                .iter()
+                .map(|(seat, _)| EntropyRevealView {
+                    seat: format!("{:?}", seat),
+                })
+                .collect(),
+            requested_at: *self.state.entropy_start_block.get(),
+            waiting_for,
+            deck_seed: hex::encode(self.finished_deck_seed()),
+        }
+    }
+
+    /// Get the dealerless deal of the current hand: the key shares and
+    /// shuffles in, the deck as last shuffled, and who the table is waiting
+    /// on (None = the table deals)
+    async fn mental(&self) -> Option<MentalView> {
+        let deal = self.state.mental_deal.get().as_ref()?;
+        let phase = *self.state.phase.get();
+        let waiting_for = match self.state.mental_start_block.get() {
+            Some(_) if phase == GamePhase::Dealing => deal.dealing_owed(),
+            Some(_) => {
+                let rules = self.state.variant.get().rules();
+                let from = self.state.community_cards.get().len();
+                deal.board_owed(from, rules.board_dealt(phase))
+            }
+            None => Vec::new(),
+        };
+        let seats = |seats: &[Seat]| seats.iter().map(|seat| format!("{:?}", seat)).collect();
+        Some(MentalView {
+            seats: seats(&deal.seats),
+            keys: deal
+                .keys
+                .iter()
+                .map(|(seat, key)| MentalKeyView {
+                    seat: format!("{:?}", seat),
+                    public_key: hex::encode(key),
+                })
+                .collect(),
+            joint_key: deal.joint_key().map(hex::encode),
+            shuffled: seats(&deal.shuffled),
+            deck: deal
+                .deck
+                .iter()
+                .map(|card| MaskedCardView {
+                    c1: hex::encode(&card.c1),
+                    c2: hex::encode(&card.c2),
+                })
+                .collect(),
+            delivered: seats(&deal.delivered),
+            requested_at: *self.state.mental_start_block.get(),
+            waiting_for: seats(&waiting_for),
+        })
+    }
+
+    /// Get the jackpot settings, the pool and the last jackpot won
+    async fn jackpot(&self) -> Option<JackpotView> {
+        let jackpot = self.state.rake.get().as_ref()?.jackpot.as_ref()?;
+        Some(JackpotView {
+            percent: jackpot.percent,
+            bad_beat: jackpot.bad_beat.map(|rank| format!("{:?}", rank)),
+            loser_share: jackpot.loser_share,
+            winner_share: jackpot.winner_share,
+            table_share: jackpot.table_share,
+            high_hand: jackpot.high_hand.map(|rank| format!("{:?}", rank)),
+            high_hand_share: jackpot.high_hand_share,
+            pool: self.state.jackpot.get().to_string(),
+            hit: self.state.jackpot_hit.get().map(|hit| format!("{:?}", hit)),
+            awards: self
+                .state
+                .jackpot_awards
+                .get()
+                .iter()
+                .map(|(seat, amount)| JackpotAwardView {
+                    seat: format!("{:?}", seat),
+                    amount: amount.to_string(),
+                })
+                .collect(),
+        })
+    }
+
+    /// Sit-and-go: what each player left would be paid under an ICM deal or
+    /// a chip chop of the prizes still to be paid, on the current stacks,
+    /// and the deal each has agreed to this hand. An agreed deal is settled
+    /// on the stacks the hand ends with.
+    async fn deal(&self) -> Option<DealView> {
+        let tournament = self.state.tournament.get().as_ref()?;
+        if tournament.coordinator.is_some() {
+            return None;
+        }
+        let players = self.state.players.get();
+        let entrants = self.state.finishes.get().len() + players.len();
+        let paid = prize_payouts(*self.state.prize_pool.get(), &tournament.payouts, entrants);
+        let prizes: Vec<Amount> = (0..players.len())
+            .map(|place| paid.get(place).copied().unwrap_or(Amount::ZERO))
+            .collect();
+        let stacks: Vec<Amount> = players.iter().map(|p| p.stack).collect();
+        let icm = deal_payouts(DealKind::Icm, &stacks, &prizes);
+        let chip_chop = deal_payouts(DealKind::ChipChop, &stacks, &prizes);
+        let equity = icm_equity(&stacks, &prizes);
+
+        let game_id = *self.state.game_id.get();
+        let votes = self.state.deal_votes.get();
+        Some(DealView {
+            prizes: prizes.iter().map(|prize| prize.to_string()).collect(),
+            players: players
+                .iter()
+                .enumerate()
+                .map(|(i, player)| DealShareView {
+                    seat: format!("{:?}", player.seat),
+                    chain_id: player.chain_id.to_string(),
+                    stack: player.stack.to_string(),
+                    icm_equity: equity[i].to_string(),
+                    icm: icm[i].to_string(),
+                    chip_chop: chip_chop[i].to_string(),
+                    accepted: votes
+                        .iter()
+                        .find(|(chain_id, _, game)| {
+                            *chain_id == player.chain_id && *game == game_id
+                        })
+                        .map(|(_, kind, _)| format!("{:?}", kind)),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl QueryRoot {
+    /// Seed of the last deck shuffled, once its hand is over; while the hand
+    /// is played it would give away every card still face down
+    fn finished_deck_seed(&self) -> Vec<u8> {
+        let phase = *self.state.phase.get();
+        if phase.is_betting_round() || matches!(phase, GamePhase::Draw | GamePhase::Showdown) {
+            return Vec::new();
+        }
+        self.state.deck_seed.get().clone()
+    }
+
+    fn up_card_views(&self) -> Vec<UpCardsView> {
+        self.state
+            .up_cards
+            .get()
+            .iter()
+            .map(|(seat, cards)| UpCardsView {
+                seat: format!("{:?}", seat),
+                cards: cards
+                    .iter()
+                    .map(|c| CardView {
+                        suit: format!("{:?}", c.suit),
+                        rank: format!("{:?}", c.rank),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn pot_views(&self) -> Vec<PotView> {
+        let settled = self.state.pots.get();
+        let pots = if settled.is_empty() {
+            build_pots(self.state.players.get())
+        } else {
+            settled.clone()
+        };
+        pots.iter()
+            .map(|pot| PotView {
+                amount: pot.amount.to_string(),
+                eligible: pot.eligible.iter().map(|s| format!("{:?}", s)).collect(),
+                winners: pot.winners.iter().map(|s| format!("{:?}", s)).collect(),
+            })
+            .collect()
+    }
+}
+
+/// The winning seat, if the pot was not split
+fn single_winner(winners: &[Seat]) -> Option<String> {
+    match winners {
+        [seat] => Some(format!("{:?}", seat)),
+        _ => None,
+    }
 }
 
 struct MutationRoot {
@@ -165,17 +529,25 @@ struct MutationRoot {
 
 #[Object]
 impl MutationRoot {
-    /// Join table with stake amount
+    /// Join table with stake amount, the player's entropy commitment and the
+    /// card key their cards are sealed to (hex; see the hand's `cardKey`)
     async fn join_table(
         &self,
         player_chain_id: String,
         stake: String,
         hand_app_id: Option<String>,
+        entropy_commitment: String,
+        card_key: String,
     ) -> bool {
         let player_chain = match player_chain_id.parse::<ChainId>() {
             Ok(c) => c,
             Err(_) => return false,
         };
+        let (entropy_commitment, card_key) =
+            match (<[u8; 32]>::from_hex(&entropy_commitment), Vec::from_hex(&card_key)) {
+                (Ok(commitment), Ok(card_key)) => (commitment, card_key),
+                _ => return false,
+            };
         let stake_amount: u64 = stake.parse().unwrap_or(0);
         let app_id = hand_app_id
             .and_then(|s| s.parse::<ApplicationId>().ok())
@@ -185,6 +557,36 @@ impl MutationRoot {
             player_chain,
             stake: Amount::from_tokens(stake_amount.into()),
             hand_app_id: app_id,
+            entropy_commitment,
+            card_key,
+        };
+        self.runtime.schedule_operation(&operation);
+        true
+    }
+
+    /// Reveal the player's committed entropy and commit to the next (hex)
+    async fn reveal_entropy(
+        &self,
+        player_chain_id: String,
+        game_id: u64,
+        entropy: String,
+        next_commitment: String,
+    ) -> bool {
+        let player_chain = match player_chain_id.parse::<ChainId>() {
+            Ok(c) => c,
+            Err(_) => return false,
+        };
+        let (entropy, next_commitment) =
+            match (<[u8; 32]>::from_hex(&entropy), <[u8; 32]>::from_hex(&next_commitment)) {
+                (Ok(entropy), Ok(next_commitment)) => (entropy, next_commitment),
+                _ => return false,
+            };
+
+        let operation = TableOperation::RelayRevealEntropy {
+            player_chain,
+            game_id,
+            entropy,
+            next_commitment,
         };
         self.runtime.schedule_operation(&operation);
         true
@@ -200,14 +602,8 @@ impl MutationRoot {
         let bet_action = match action.action_type {
             BetActionType::Check => BetAction::Check,
             BetActionType::Call => BetAction::Call,
-            BetActionType::Raise => {
-                let amount = action
-                    .amount
-                    .unwrap_or_default()
-                    .parse::<u128>()
-                    .unwrap_or(0);
-                BetAction::Raise(Amount::from_attos(amount))
-            }
+            BetActionType::Bet => BetAction::Bet(parse_bet_amount(action.amount.as_deref())),
+            BetActionType::RaiseTo => BetAction::RaiseTo(parse_bet_amount(action.amount.as_deref())),
             BetActionType::AllIn => BetAction::AllIn,
             BetActionType::Fold => BetAction::Fold,
         };
@@ -241,12 +637,17 @@ impl MutationRoot {
             })
             .collect();
 
-        // Create empty proofs for now (verification disabled for demo)
+        // Each card must open the commitment it was dealt under
         let proofs: Vec<CardReveal> = revealed_cards
             .iter()
-            .map(|card| CardReveal {
+            .zip(&cards)
+            .map(|(card, input)| CardReveal {
                 card: *card,
-                secret: vec![],
+                secret: input
+                    .blinding
+                    .as_deref()
+                    .and_then(|blinding| hex::decode(blinding).ok())
+                    .unwrap_or_default(),
             })
             .collect();
 
@@ -272,6 +673,7 @@ impl MutationRoot {
 #[derive(InputObject)]
 struct BetActionInput {
     action_type: BetActionType,
+    /// Total for the street on BET and RAISE_TO
     amount: Option<String>,
 }
 
@@ -280,16 +682,29 @@ struct BetActionInput {
 enum BetActionType {
     Check,
     Call,
-    Raise,
+    Bet,
+    RaiseTo,
     AllIn,
     Fold,
 }
 
+/// Bet/raise amount in attos (sent as a string to handle large numbers)
+fn parse_bet_amount(amount: Option<&str>) -> Amount {
+    let attos = amount
+        .unwrap_or_default()
+        .parse::<u128>()
+        .unwrap_or(0);
+    Amount::from_attos(attos)
+}
+
 /// GraphQL input for cards
 #[derive(InputObject)]
 struct CardInput {
     suit: String,
     rank: String,
+    /// Blinding factor (hex) that opens the card's commitment; the table
+    /// rejects a reveal without it
+    blinding: Option<String>,
 }
 
 fn parse_rank(rank_str: &str) -> Rank {
@@ -317,33 +732,258 @@ struct TableStateView {
     phase: String,
     players: Vec<PlayerInfoView>,
     pot: String,
+    /// Chips brought to the table; always equals the stacks plus the pot
+    total_buy_ins: String,
     current_bet: String,
     min_raise: String,
     community_cards: Vec<CardView>,
+    /// Stud: face-up cards of each seat
+    up_cards: Vec<UpCardsView>,
+    /// Draw: cards each seat exchanged so far
+    draws: Vec<DrawView>,
     turn_seat: Option<String>,
     winner: Option<String>,
+    /// All winning seats (more than one on a split pot)
+    winners: Vec<String>,
+    /// Number of seats at the table
+    max_players: u8,
+    /// Stacks carry over and hands start back to back
+    cash_game: bool,
+    /// Betting structure: NoLimit, PotLimit or FixedLimit
+    betting: String,
+    /// Game dealt this hand: HoldEm, Omaha, ShortDeck, SevenCardStud, FiveCardDraw,
+    /// OmahaHiLo or Razz
+    variant: String,
+    /// Mixed game rotation, one orbit per variant (empty = single game)
+    mixed_games: Vec<String>,
+    /// Main pot first, then side pots
+    pots: Vec<PotView>,
     min_stake: String,
     max_stake: String,
     /// Small blind amount
     small_blind: String,
     /// Big blind amount
     big_blind: String,
+    /// Ante per player (or for the table with a big-blind ante)
+    ante: String,
+    /// The big blind posts the ante for everyone
+    big_blind_ante: bool,
+    /// Straddle left of the big blind (zero = none)
+    straddle: String,
     /// Current dealer button position
     dealer_button: Option<String>,
-    /// Deck seed for provable fairness
+    /// Deck seed for provable fairness, once the hand is over (empty while
+    /// it is played)
     deck_seed: Vec<u8>,
     // REMOVED: dealer_secret (SECURITY ISSUE - exposed secret via GraphQL!)
     // Now using ZK commitments instead
 }
 
+#[derive(async_graphql::SimpleObject)]
+struct LegalBetView {
+    /// Seat to act
+    seat: String,
+    /// Chips needed to call (capped at the stack)
+    to_call: String,
+    /// Smallest total to bet or raise to (None: check, call or fold only)
+    min_raise_to: Option<String>,
+    /// Largest total to bet or raise to
+    max_raise_to: Option<String>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct PotView {
+    amount: String,
+    /// Seats that can win this pot
+    eligible: Vec<String>,
+    /// Seats that won this pot (empty until showdown)
+    winners: Vec<String>,
+}
+
 #[derive(async_graphql::SimpleObject)]
 struct PlayerInfoView {
     seat: String,
     chain_id: String,
     stake: String,
+    /// Chips behind, not yet in the pot
+    stack: String,
     has_folded: bool,
     current_bet: String,
     has_revealed: bool,
+    /// Chips put into the pot this hand
+    total_contribution: String,
+    /// Cash game: seated but not dealt in
+    sitting_out: bool,
+    /// Cash game: leaves after the current hand
+    cashing_out: bool,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct TournamentView {
+    /// Tokens paid to enter
+    buy_in: String,
+    /// Tournament chips each player started with
+    starting_chips: String,
+    /// Buy-ins collected, in tokens
+    prize_pool: String,
+    /// Prize for each paid place, first place first
+    prizes: Vec<String>,
+    hands_played: u32,
+    /// Index into the blind schedule of the level in play
+    level: u32,
+    /// Players knocked out so far, then the winner
+    finishes: Vec<TournamentFinishView>,
+    /// Multi-table tournament: players moved off the table, not yet reported
+    departures: Vec<DepartureView>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct TournamentFinishView {
+    seat: String,
+    chain_id: String,
+    /// Finishing position (1 = winner)
+    place: u32,
+    prize: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct RakeView {
+    /// Percentage of each pot taken
+    percent: u8,
+    /// Most taken from one hand (zero = no cap)
+    cap: String,
+    /// Hands ending on the first betting round aren't raked
+    no_flop_no_drop: bool,
+    fee_recipient: String,
+    /// Taken from the pots of the last hand settled
+    hand_rake: String,
+    /// House's share taken since the table opened
+    collected: String,
+    /// Not yet paid to the fee recipient
+    owed: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct EntropyView {
+    commitments: Vec<EntropyCommitmentView>,
+    /// Seats that revealed their entropy for the current hand
+    reveals: Vec<EntropyRevealView>,
+    /// Block height the table asked for reveals at (None = not waiting)
+    requested_at: Option<u64>,
+    /// Seats dealt in that haven't revealed yet
+    waiting_for: Vec<String>,
+    /// Seed of the last deck shuffled, once its hand is over
+    deck_seed: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct EntropyCommitmentView {
+    chain_id: String,
+    commitment: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct EntropyRevealView {
+    seat: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct MentalView {
+    /// Seats dealing the hand
+    seats: Vec<String>,
+    /// Key share of each seat, in the order they came in
+    keys: Vec<MentalKeyView>,
+    /// Sum of the key shares, once every seat has one in
+    joint_key: Option<String>,
+    /// Seats that have shuffled, in order
+    shuffled: Vec<String>,
+    /// Deck as last shuffled (ElGamal ciphertexts)
+    deck: Vec<MaskedCardView>,
+    /// Seats sent their hole cards
+    delivered: Vec<String>,
+    /// Block height the table started waiting at (None = not waiting)
+    requested_at: Option<u64>,
+    /// Seats holding up the deal or the board
+    waiting_for: Vec<String>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct MentalKeyView {
+    seat: String,
+    public_key: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct MaskedCardView {
+    c1: String,
+    c2: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct JackpotView {
+    /// Percentage of the rake dropped into the pool
+    percent: u8,
+    /// Weakest losing hand that hits the bad-beat jackpot
+    bad_beat: Option<String>,
+    /// Percentages of the pool paid on a bad beat
+    loser_share: u8,
+    winner_share: u8,
+    table_share: u8,
+    /// Weakest winning hand that hits the high-hand jackpot
+    high_hand: Option<String>,
+    high_hand_share: u8,
+    pool: String,
+    /// Jackpot won in the last hand settled
+    hit: Option<String>,
+    awards: Vec<JackpotAwardView>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct JackpotAwardView {
+    seat: String,
+    amount: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct DealView {
+    /// Prizes still to be paid, first place first
+    prizes: Vec<String>,
+    players: Vec<DealShareView>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct DealShareView {
+    seat: String,
+    chain_id: String,
+    stack: String,
+    /// Independent Chip Model equity, exact
+    icm_equity: String,
+    /// Paid under an ICM deal (whole tokens; the chip leader takes the rounding)
+    icm: String,
+    /// Paid under a chip chop (whole tokens; the chip leader takes the rounding)
+    chip_chop: String,
+    /// Deal agreed to this hand, if any (Icm or ChipChop)
+    accepted: Option<String>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct DepartureView {
+    chain_id: String,
+    /// Chips the player takes to their next table
+    chips: String,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct DrawView {
+    seat: String,
+    /// Cards discarded and replaced (0 = stood pat)
+    cards: u8,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct UpCardsView {
+    seat: String,
+    cards: Vec<CardView>,
 }
 
 #[derive(async_graphql::SimpleObject)]
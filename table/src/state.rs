@@ -1,8 +1,12 @@
 //! Table contract state using Linera views
 
-use linera_poker_shared::{Card, CardCommitment, GamePhase, PlayerInfo, RevealProof, Seat};
+use linera_poker_shared::{
+    BettingStructure, Card, CardCommitment, DealKind, Departure, GamePhase, JackpotHit,
+    MentalDeal, PlayerInfo, PokerVariant, Pot, PotAward, RakeConfig, RevealProof, Seat,
+    TournamentConfig, TournamentFinish,
+};
 use linera_sdk::{
-    linera_base_types::{Amount, ChainId},
+    linera_base_types::{AccountOwner, Amount, ChainId},
     views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
 };
 use serde::{Deserialize, Serialize};
@@ -19,6 +23,9 @@ pub struct TimeoutConfig {
     pub bet_timeout_blocks: u32,
     /// Blocks until reveal times out (default: 100 blocks ~ 10 minutes)
     pub reveal_timeout_blocks: u32,
+    /// Blocks until a deck entropy reveal, or a step of a dealerless deal,
+    /// times out (default: 50 blocks ~ 5 minutes)
+    pub entropy_timeout_blocks: u32,
     /// Whether auto-forfeit is enabled
     pub auto_forfeit_enabled: bool,
 }
@@ -28,6 +35,7 @@ impl Default for TimeoutConfig {
         Self {
             bet_timeout_blocks: 50,      // ~5 minutes at 6 seconds/block
             reveal_timeout_blocks: 100,   // ~10 minutes
+            entropy_timeout_blocks: 50,   // ~5 minutes
             auto_forfeit_enabled: true,
         }
     }
@@ -68,22 +76,57 @@ pub struct TableState {
     // ========================================================================
     /// Current game ID
     pub game_id: RegisterView<u64>,
+    /// Owner who created the table; only they may reset it
+    pub creator: RegisterView<Option<AccountOwner>>,
     /// Current game phase
     pub phase: RegisterView<GamePhase>,
-    /// Players (max 2)
+    /// Seated players (up to `max_players`)
     pub players: RegisterView<Vec<PlayerInfo>>,
     /// Total pot
     pub pot: RegisterView<Amount>,
+    /// Sum of all buy-ins and top-ups of seated players (stacks + pot)
+    pub total_buy_ins: RegisterView<Amount>,
     /// Current bet to call
     pub current_bet: RegisterView<Amount>,
     /// Minimum raise amount
     pub min_raise: RegisterView<Amount>,
-    /// Community cards (flop/turn/river)
+    /// No-limit, pot-limit or fixed-limit
+    pub betting: RegisterView<BettingStructure>,
+    /// Hold'em, Omaha, short deck or stud: the deck, cards dealt and how hands are evaluated.
+    /// In a mixed game, the variant of the current orbit.
+    pub variant: RegisterView<PokerVariant>,
+    /// Mixed game: variants dealt in turn, one orbit each (empty = always `variant`)
+    pub mixed_games: RegisterView<Vec<PokerVariant>>,
+    /// Mixed game: index in `mixed_games` of the variant being dealt
+    pub mixed_game_index: RegisterView<u8>,
+    /// Mixed game: hands dealt so far in the current orbit
+    pub orbit_hands: RegisterView<u8>,
+    /// Bets and full raises made on the current street (the big blind counts pre-flop)
+    pub raises_this_street: RegisterView<u8>,
+    /// Ante per player, or for the whole table with a big-blind ante
+    pub ante: RegisterView<Amount>,
+    /// The big blind posts the ante for everyone
+    pub big_blind_ante: RegisterView<bool>,
+    /// Straddle left of the big blind (zero = none)
+    pub straddle: RegisterView<Amount>,
+    /// Community cards opened so far (flop/turn/river)
     pub community_cards: RegisterView<Vec<Card>>,
+    /// Stud: face-up cards of every seat dealt in, in the order they were dealt
+    pub up_cards: RegisterView<Vec<(Seat, Vec<Card>)>>,
+    /// Draw: how many cards each seat exchanged, in the order they drew
+    pub draws: RegisterView<Vec<(Seat, u8)>>,
+    /// Stud: next card to deal from the shuffled deck
+    pub deck_position: RegisterView<u8>,
     /// Whose turn it is
     pub turn_seat: RegisterView<Option<Seat>>,
-    /// Winner (if determined)
-    pub winner: RegisterView<Option<Seat>>,
+    /// Winning seats once determined (several on a split pot)
+    pub winners: RegisterView<Vec<Seat>>,
+    /// Main pot and side pots with their winners, set at showdown
+    pub pots: RegisterView<Vec<Pot>>,
+    /// Exact split of every pot, set at showdown
+    pub awards: RegisterView<Vec<PotAward>>,
+    /// Bet nobody called, returned to its seat before the pots were built
+    pub uncalled_bet: RegisterView<Option<(Seat, Amount)>>,
 
     // ========================================================================
     // TABLE CONFIGURATION (Existing)
@@ -92,13 +135,57 @@ pub struct TableState {
     pub min_stake: RegisterView<Amount>,
     /// Maximum stake to join
     pub max_stake: RegisterView<Amount>,
-    /// Small blind amount (button posts this)
+    /// Number of seats at the table (2-9)
+    pub max_players: RegisterView<u8>,
+    /// Cash game: stacks carry over and hands start back to back
+    pub cash_game: RegisterView<bool>,
+    /// Small blind amount (posted by the button when heads-up)
     pub small_blind: RegisterView<Amount>,
-    /// Big blind amount (non-button posts this)
+    /// Big blind amount (posted by the seat after the small blind)
     pub big_blind: RegisterView<Amount>,
-    /// Current dealer button position (alternates each hand)
+    /// Current dealer button position (moves one seat clockwise each hand)
     pub dealer_button: RegisterView<Option<Seat>>,
 
+    // ========================================================================
+    // SIT-AND-GO TOURNAMENT
+    // ========================================================================
+    /// Tournament settings (None = not a tournament)
+    pub tournament: RegisterView<Option<TournamentConfig>>,
+    /// Buy-ins paid into the tournament, in tokens (stacks are tournament chips)
+    pub prize_pool: RegisterView<Amount>,
+    /// Hands dealt since the tournament started
+    pub hands_played: RegisterView<u32>,
+    /// Block height when the first hand was dealt
+    pub tournament_start_block: RegisterView<Option<u64>>,
+    /// Index into the blind schedule of the level in play
+    pub blind_level: RegisterView<u32>,
+    /// Players knocked out so far, and the winner once it's over
+    pub finishes: RegisterView<Vec<TournamentFinish>>,
+    /// Multi-table tournament: players moved to another table, not yet reported
+    pub departures: RegisterView<Vec<Departure>>,
+    /// Multi-table tournament: seats the coordinator holds for registered
+    /// players, with the chips each brings
+    pub reserved_seats: RegisterView<Vec<(ChainId, Amount)>>,
+    /// Sit-and-go: deal each player agreed to, and the hand they agreed in
+    pub deal_votes: RegisterView<Vec<(ChainId, DealKind, u64)>>,
+
+    // ========================================================================
+    // RAKE
+    // ========================================================================
+    /// House fee settings (None = no rake)
+    pub rake: RegisterView<Option<RakeConfig>>,
+    /// Rake taken from the pots of the last hand settled
+    pub hand_rake: RegisterView<Amount>,
+    /// House's share of the rake taken since the table opened
+    pub rake_collected: RegisterView<Amount>,
+    /// Rake not yet paid to the fee recipient (the token app pays whole tokens)
+    pub rake_owed: RegisterView<Amount>,
+    /// Jackpot pool: the rake dropped into it and not yet won
+    pub jackpot: RegisterView<Amount>,
+    /// Jackpot won in the last hand settled, and what each seat was paid
+    pub jackpot_hit: RegisterView<Option<JackpotHit>>,
+    pub jackpot_awards: RegisterView<Vec<(Seat, Amount)>>,
+
     // ========================================================================
     // DEPRECATED: INSECURE FIELDS (Phase 3: Removed)
     // ========================================================================
@@ -106,10 +193,39 @@ pub struct TableState {
     // ^^^ SECURITY ISSUE: This exposed secret to GraphQL queries!
     // ^^^ Replaced by ZK commitments below
 
-    /// Deck seed (for deterministic shuffle)
-    /// NOTE: Still deterministic - will be replaced by commit-reveal in future
+    /// Deck seed (for deterministic shuffle), mixed from the entropy every
+    /// player dealt in revealed
     pub deck_seed: RegisterView<Vec<u8>>,
 
+    // ========================================================================
+    // DECK ENTROPY (Player Commit-Reveal)
+    // ========================================================================
+    /// Entropy commitment each seated player holds for their next hand
+    pub entropy_commitments: RegisterView<Vec<(ChainId, [u8; 32])>>,
+
+    /// Entropy revealed for the current hand (kept so anyone can recompute the seed)
+    pub entropy_reveals: RegisterView<Vec<(Seat, [u8; 32])>>,
+
+    /// Block height when the table asked for reveals (None = not waiting on any)
+    pub entropy_start_block: RegisterView<Option<u64>>,
+
+    /// Seats dealt into the current hand, clockwise
+    pub dealt_in: RegisterView<Vec<Seat>>,
+
+    // ========================================================================
+    // DEALERLESS SHUFFLE (Mental Poker)
+    // ========================================================================
+    /// Players shuffle and open the cards themselves (flop games only)
+    pub dealerless: RegisterView<bool>,
+
+    /// Deal of the current hand: key shares, the shuffled deck and the
+    /// decryption shares in so far (None = dealt by the table)
+    pub mental_deal: RegisterView<Option<MentalDeal>>,
+
+    /// Block height when the table started waiting on players to deal or to
+    /// open the board (None = not waiting on any)
+    pub mental_start_block: RegisterView<Option<u64>>,
+
     // ========================================================================
     // ZK-SNARK STATE (Phase 3: Production-Ready Privacy)
     // ========================================================================
@@ -119,8 +235,16 @@ pub struct TableState {
     /// Merkle root of shuffled deck (for proving card inclusion)
     pub deck_root: RegisterView<[u8; 32]>,
 
+    /// Deck positions of the board, committed to when the hand is dealt and
+    /// opened street by street against `deck_root` (empty = no board, or dealerless)
+    pub board_positions: RegisterView<Vec<u8>>,
+
+    /// Card key each seated player registered when they sat down: the public
+    /// key their hole cards and commitment openings are sealed to
+    pub card_keys: MapView<ChainId, Vec<u8>>,
+
     /// Player card commitments (Pedersen commitments)
-    /// Maps ChainId -> [hole_card_1_commitment, hole_card_2_commitment]
+    /// Maps ChainId -> one commitment per hole card (stud: per down card dealt so far)
     pub player_commitments: MapView<ChainId, Vec<CardCommitment>>,
 
     /// Revealed hole cards with ZK proofs (for showdown)
@@ -148,10 +272,4 @@ pub struct TableState {
 
     /// Players who have timed out (auto-forfeited)
     pub timed_out_players: RegisterView<Vec<ChainId>>,
-
-    // ========================================================================
-    // BETTING ROUND STATE (Existing)
-    // ========================================================================
-    /// Number of actions taken in current betting round (to prevent premature phase advance)
-    pub actions_this_round: RegisterView<u8>,
 }
@@ -66,6 +66,10 @@ impl Contract for TokenContract {
                 self.transfer(to_chain, Amount::from_tokens(amount.into()), game_id)
                     .await
             }
+            TokenOperation::ClaimReceived { from_chain, amount } => {
+                self.claim_received(from_chain, Amount::from_tokens(amount.into()))
+                    .await
+            }
         }
     }
 
@@ -79,7 +83,7 @@ impl Contract for TokenContract {
                 // Acknowledgment - stake already locked via operation
             }
             Message::Payout { game_id: _, amount } => {
-                self.receive_payout(amount);
+                self.receive_payout(amount).await;
             }
             Message::Refund { game_id: _, amount } => {
                 self.unlock_stake(amount);
@@ -146,11 +150,44 @@ impl TokenContract {
         TokenResult::Success
     }
 
-    /// Receive payout
-    fn receive_payout(&mut self, amount: Amount) {
+    /// Receive payout, remembering which chain sent it
+    async fn receive_payout(&mut self, amount: Amount) {
         self.state.locked.set(Amount::ZERO);
         let balance = *self.state.balance.get();
         self.state.balance.set(balance.saturating_add(amount));
+
+        if let Some(origin) = self.runtime.message_origin_chain_id() {
+            let received = match self.state.received.get(&origin).await {
+                Ok(Some(received)) => received,
+                _ => Amount::ZERO,
+            };
+            let _ = self
+                .state
+                .received
+                .insert(&origin, received.saturating_add(amount));
+        }
+    }
+
+    /// Claim tokens received from a chain, failing unless they have arrived
+    async fn claim_received(&mut self, from_chain: ChainId, amount: Amount) -> TokenResult {
+        if amount == Amount::ZERO {
+            return TokenResult::Error(TokenError::InvalidAmount);
+        }
+        let received = match self.state.received.get(&from_chain).await {
+            Ok(Some(received)) => received,
+            _ => Amount::ZERO,
+        };
+        if amount > received {
+            return TokenResult::Error(TokenError::InsufficientBalance);
+        }
+
+        let left = received.saturating_sub(amount);
+        if left == Amount::ZERO {
+            let _ = self.state.received.remove(&from_chain);
+        } else {
+            let _ = self.state.received.insert(&from_chain, left);
+        }
+        TokenResult::Success
     }
 
     /// Unlock stake (refund)
@@ -61,6 +61,9 @@ pub enum TokenOperation {
         amount: u64,
         game_id: u64,
     },
+    /// Take `amount` out of the tokens `from_chain` has transferred to this
+    /// chain, so each payment is only counted once by the caller
+    ClaimReceived { from_chain: ChainId, amount: u64 },
 }
 
 /// Instantiation argument
@@ -1,8 +1,8 @@
 //! Token contract state using Linera views
 
 use linera_sdk::{
-    linera_base_types::{AccountOwner, Amount},
-    views::{linera_views, RegisterView, RootView, ViewStorageContext},
+    linera_base_types::{AccountOwner, Amount, ChainId},
+    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
 };
 
 /// Token state - chip balance for a player
@@ -15,4 +15,6 @@ pub struct TokenState {
     pub locked: RegisterView<Amount>,
     /// Owner of these tokens
     pub owner: RegisterView<Option<AccountOwner>>,
+    /// Tokens transferred to this chain and not yet claimed, by sending chain
+    pub received: MapView<ChainId, Amount>,
 }
@@ -0,0 +1,662 @@
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+mod state;
+
+use self::state::TournamentState;
+use linera_poker_hand::{HandAbi, HandOperation};
+use linera_poker_shared::prize_payouts;
+use linera_poker_shared::zk::is_card_key;
+use linera_poker_table::{TableAbi, TableOperation, TableResult};
+use linera_poker_token::{TokenAbi, TokenOperation, TokenResult};
+use linera_poker_tournament::{
+    Entrant, InstantiationArgument, Message, Standing, TableReport, TournamentAbi, TournamentError,
+    TournamentOperation, TournamentResult, TournamentStatus, TournamentTable,
+};
+use linera_sdk::{
+    linera_base_types::{Amount, ApplicationId, ChainId, WithContractAbi},
+    views::{RootView, View},
+    Contract, ContractRuntime,
+};
+
+pub struct TournamentContract {
+    state: TournamentState,
+    runtime: ContractRuntime<Self>,
+}
+
+linera_sdk::contract!(TournamentContract);
+
+impl WithContractAbi for TournamentContract {
+    type Abi = TournamentAbi;
+}
+
+impl Contract for TournamentContract {
+    type Message = Message;
+    type Parameters = ();
+    type InstantiationArgument = InstantiationArgument;
+    type EventValue = ();
+
+    async fn load(runtime: ContractRuntime<Self>) -> Self {
+        let state = TournamentState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to load state");
+        TournamentContract { state, runtime }
+    }
+
+    async fn instantiate(&mut self, arg: InstantiationArgument) {
+        self.state.config.set(Some(arg.tournament));
+        self.state.seats_per_table.set(arg.seats_per_table.max(2));
+        self.state.tables.set(
+            arg.tables
+                .into_iter()
+                .map(|(chain_id, table_app)| TournamentTable {
+                    chain_id,
+                    table_app,
+                    blind_level: 0,
+                    broken: false,
+                })
+                .collect(),
+        );
+        self.state.entrants.set(Vec::new());
+        self.state.prize_pool.set(Amount::ZERO);
+        self.state.status.set(TournamentStatus::Registering);
+        self.state.start_block.set(None);
+        self.state.blind_level.set(0);
+        self.state.hands_played.set(0);
+        self.state.standings.set(Vec::new());
+    }
+
+    async fn execute_operation(&mut self, operation: TournamentOperation) -> TournamentResult {
+        match operation {
+            TournamentOperation::Register {
+                hand_app_id,
+                buy_in,
+                entropy_commitment,
+                card_key,
+                token_app,
+            } => {
+                let coordinator = self.runtime.application_creator_chain_id();
+                // Pay first: the coordinator only takes a buy-in into the prize
+                // pool once it has been paid to its chain
+                if let Some(token_app) = token_app {
+                    let paid = self.runtime.call_application(
+                        /* authenticated */ true,
+                        token_app.with_abi::<TokenAbi>(),
+                        &TokenOperation::Transfer {
+                            to_chain: coordinator,
+                            amount: buy_in,
+                            game_id: 0,
+                        },
+                    );
+                    if !matches!(paid, TokenResult::Success) {
+                        return TournamentResult::Error(TournamentError::BuyInNotPaid);
+                    }
+                }
+                self.runtime
+                    .prepare_message(Message::Register {
+                        hand_app_id,
+                        buy_in: Amount::from_tokens(buy_in.into()),
+                        entropy_commitment,
+                        card_key,
+                        token_app,
+                    })
+                    .with_authentication()
+                    .send_to(coordinator);
+                TournamentResult::Success
+            }
+            TournamentOperation::Start => {
+                if !self.is_coordinator() {
+                    return TournamentResult::Error(TournamentError::Unauthorized);
+                }
+                self.start()
+            }
+            TournamentOperation::Sync => {
+                if !self.is_coordinator() {
+                    return TournamentResult::Error(TournamentError::Unauthorized);
+                }
+                if *self.state.status.get() != TournamentStatus::Running {
+                    return TournamentResult::Error(TournamentError::NotRunning);
+                }
+                self.request_reports();
+                TournamentResult::Success
+            }
+        }
+    }
+
+    async fn execute_message(&mut self, message: Message) {
+        let source_chain = match self.runtime.message_origin_chain_id() {
+            Some(chain_id) => chain_id,
+            None => return,
+        };
+        let coordinator = self.runtime.application_creator_chain_id();
+
+        match message {
+            // On the coordinator chain
+            Message::Register {
+                hand_app_id,
+                buy_in,
+                entropy_commitment,
+                card_key,
+                token_app,
+            } => {
+                if self.is_coordinator() {
+                    self.handle_register(
+                        source_chain,
+                        hand_app_id,
+                        buy_in,
+                        token_app,
+                        entropy_commitment,
+                        card_key,
+                    );
+                }
+            }
+            Message::TableReport { report } => {
+                if self.is_coordinator() {
+                    self.handle_report(source_chain, report);
+                }
+            }
+
+            // On a player chain: follow the seat to its table, then take it
+            Message::Assign {
+                hand_app_id,
+                table_chain,
+                table_app,
+                entropy_commitment,
+                card_key,
+            } => {
+                if source_chain != table_chain {
+                    return; // Only the table holding the seat assigns it
+                }
+                self.runtime.call_application(
+                    /* authenticated */ true,
+                    hand_app_id.with_abi::<HandAbi>(),
+                    &HandOperation::MoveTable {
+                        table_chain,
+                        table_app,
+                    },
+                );
+                self.runtime
+                    .prepare_message(Message::SeatPlayer {
+                        table_app,
+                        hand_app_id,
+                        entropy_commitment,
+                        card_key,
+                    })
+                    .with_authentication()
+                    .send_to(table_chain);
+            }
+
+            // On a table chain: relay to the table app
+            Message::ReserveSeat {
+                table_app,
+                player_chain,
+                hand_app_id,
+                chips,
+                entropy_commitment,
+                card_key,
+            } => {
+                if source_chain != coordinator {
+                    return;
+                }
+                // The seat is held before the player is told to take it
+                if let TableResult::Success = self.relay_to_table(
+                    table_app,
+                    TableOperation::RelayReserveSeat {
+                        player_chain,
+                        chips,
+                    },
+                ) {
+                    let table_chain = self.runtime.chain_id();
+                    self.runtime
+                        .prepare_message(Message::Assign {
+                            hand_app_id,
+                            table_chain,
+                            table_app,
+                            entropy_commitment,
+                            card_key,
+                        })
+                        .with_authentication()
+                        .send_to(player_chain);
+                }
+            }
+            Message::SeatPlayer {
+                table_app,
+                hand_app_id,
+                entropy_commitment,
+                card_key,
+            } => {
+                self.relay_to_table(
+                    table_app,
+                    TableOperation::RelaySeatPlayer {
+                        player_chain: source_chain,
+                        hand_app_id,
+                        entropy_commitment,
+                        card_key,
+                    },
+                );
+            }
+            Message::MovePlayer {
+                table_app,
+                player_chain,
+            } => {
+                if source_chain != coordinator {
+                    return;
+                }
+                self.relay_to_table(table_app, TableOperation::RelayMovePlayer { player_chain });
+            }
+            Message::SetBlindLevel { table_app, level } => {
+                if source_chain != coordinator {
+                    return;
+                }
+                self.relay_to_table(table_app, TableOperation::RelaySetBlindLevel { level });
+            }
+            Message::RequestReport { table_app } => {
+                if source_chain != coordinator {
+                    return;
+                }
+                if let TableResult::TournamentReport(report) =
+                    self.relay_to_table(table_app, TableOperation::ReportTournament)
+                {
+                    self.runtime
+                        .prepare_message(Message::TableReport { report })
+                        .with_authentication()
+                        .send_to(coordinator);
+                }
+            }
+        }
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+impl TournamentContract {
+    /// Whether this is the chain the tournament was created on
+    fn is_coordinator(&mut self) -> bool {
+        self.runtime.chain_id() == self.runtime.application_creator_chain_id()
+    }
+
+    /// Call the table app on this (table) chain
+    fn relay_to_table(
+        &mut self,
+        table_app: ApplicationId,
+        operation: TableOperation,
+    ) -> TableResult {
+        self.runtime.call_application(
+            /* authenticated */ true,
+            table_app.with_abi::<TableAbi>(),
+            &operation,
+        )
+    }
+
+    /// Register a player for the exact buy-in, until the tournament starts
+    ///
+    /// With a token app, the buy-in must have been paid to this chain through
+    /// it; only then does it join the prize pool, and a registration turned
+    /// down is refunded. Without one, buy-ins and prizes are results only.
+    fn handle_register(
+        &mut self,
+        player_chain: ChainId,
+        hand_app: ApplicationId,
+        buy_in: Amount,
+        token_app: Option<ApplicationId>,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    ) {
+        let config = match self.state.config.get() {
+            Some(config) => config.clone(),
+            None => return,
+        };
+        // Paid through some other app: nothing reached the prize pool's account
+        if token_app != config.token_app {
+            return;
+        }
+
+        let mut entrants = self.state.entrants.get().clone();
+        if *self.state.status.get() != TournamentStatus::Registering
+            || buy_in != Amount::from_tokens(config.buy_in.into())
+            || !is_card_key(&card_key)
+            || entrants.iter().any(|e| e.chain_id == player_chain)
+        {
+            self.pay(player_chain, buy_in);
+            return;
+        }
+
+        entrants.push(Entrant {
+            chain_id: player_chain,
+            hand_app,
+            chips: Amount::from_tokens(config.starting_chips.into()),
+            table: None,
+            moving: false,
+            entropy_commitment,
+            card_key,
+        });
+        self.state.entrants.set(entrants);
+
+        let prize_pool = self.state.prize_pool.get().saturating_add(buy_in);
+        self.state.prize_pool.set(prize_pool);
+    }
+
+    /// Close registration and deal the entrants round the fewest tables
+    /// that seat them all; tables left over are broken from the start
+    fn start(&mut self) -> TournamentResult {
+        if *self.state.status.get() != TournamentStatus::Registering {
+            return TournamentResult::Error(TournamentError::RegistrationClosed);
+        }
+
+        let mut entrants = self.state.entrants.get().clone();
+        let mut tables = self.state.tables.get().clone();
+        let seats = *self.state.seats_per_table.get() as usize;
+        if entrants.len() < 2 {
+            return TournamentResult::Error(TournamentError::NotEnoughPlayers);
+        }
+        if entrants.len() > tables.len() * seats {
+            return TournamentResult::Error(TournamentError::TooManyPlayers);
+        }
+
+        let in_play = entrants.len().div_ceil(seats);
+        for table in tables.iter_mut().skip(in_play) {
+            table.broken = true;
+        }
+        for (i, entrant) in entrants.iter_mut().enumerate() {
+            let table = &tables[i % in_play];
+            entrant.table = Some(table.chain_id);
+            self.assign(entrant, table);
+        }
+
+        self.state.entrants.set(entrants);
+        self.state.tables.set(tables);
+        self.state.status.set(TournamentStatus::Running);
+        self.state
+            .start_block
+            .set(Some(self.runtime.block_height().0));
+        TournamentResult::Success
+    }
+
+    /// Send a player to a table: the table holds a seat with their chips and
+    /// assigns it to them, then their chain points the hand app at it and
+    /// takes the seat, so the table can't deal them in before they're there
+    ///
+    /// Only a registered (and so paid-up) player is ever held a seat.
+    fn assign(&mut self, entrant: &Entrant, table: &TournamentTable) {
+        self.runtime
+            .prepare_message(Message::ReserveSeat {
+                table_app: table.table_app,
+                player_chain: entrant.chain_id,
+                hand_app_id: entrant.hand_app,
+                chips: entrant.chips,
+                entropy_commitment: entrant.entropy_commitment,
+                card_key: entrant.card_key.clone(),
+            })
+            .with_authentication()
+            .send_to(table.chain_id);
+    }
+
+    /// Ask every table with players assigned to it for a report
+    fn request_reports(&mut self) {
+        let entrants = self.state.entrants.get().clone();
+        for table in self.state.tables.get().clone() {
+            if entrants.iter().any(|e| e.table == Some(table.chain_id)) {
+                self.runtime
+                    .prepare_message(Message::RequestReport {
+                        table_app: table.table_app,
+                    })
+                    .with_authentication()
+                    .send_to(table.chain_id);
+            }
+        }
+    }
+
+    /// Take in a table's report: update stacks, place and pay knocked-out
+    /// players, reseat players who left their table, then level the blinds
+    /// and balance the tables
+    fn handle_report(&mut self, table_chain: ChainId, report: TableReport) {
+        if *self.state.status.get() != TournamentStatus::Running {
+            return;
+        }
+        if !self
+            .state
+            .tables
+            .get()
+            .iter()
+            .any(|t| t.chain_id == table_chain)
+        {
+            return; // Reject reports from chains that aren't our tables
+        }
+
+        let hands_played = (*self.state.hands_played.get()).max(report.hands_played);
+        self.state.hands_played.set(hands_played);
+
+        let mut entrants = self.state.entrants.get().clone();
+        for (chain_id, chips) in &report.stacks {
+            if let Some(entrant) = entrants
+                .iter_mut()
+                .find(|e| e.chain_id == *chain_id && e.table == Some(table_chain))
+            {
+                entrant.chips = *chips;
+            }
+        }
+
+        // Knockouts are reported every time; only new ones are placed
+        for chain_id in &report.knocked_out {
+            let standings = self.state.standings.get();
+            if standings.iter().any(|s| s.chain_id == *chain_id) {
+                continue;
+            }
+            let place = entrants.len() - standings.len();
+            if let Some(entrant) = entrants.iter_mut().find(|e| e.chain_id == *chain_id) {
+                entrant.chips = Amount::ZERO;
+                entrant.table = None;
+                entrant.moving = false;
+                self.finish(*chain_id, place as u32, entrants.len());
+            }
+        }
+
+        // Departed players take a seat at the table with the fewest players
+        let mut arrivals = Vec::new();
+        for departure in &report.departures {
+            if let Some(idx) = entrants
+                .iter()
+                .position(|e| e.chain_id == departure.chain_id && e.table == Some(table_chain))
+            {
+                entrants[idx].chips = departure.chips;
+                entrants[idx].table = None;
+                entrants[idx].moving = false;
+                entrants[idx].entropy_commitment = departure.entropy_commitment;
+                arrivals.push(idx);
+            }
+        }
+        for idx in arrivals {
+            let table = match self.emptiest_table(&entrants, Some(table_chain)) {
+                Some(table) => table,
+                None => continue,
+            };
+            entrants[idx].table = Some(table.chain_id);
+            self.assign(&entrants[idx], &table);
+        }
+
+        // Last player standing wins
+        let alive: Vec<ChainId> = entrants
+            .iter()
+            .filter(|e| {
+                !self
+                    .state
+                    .standings
+                    .get()
+                    .iter()
+                    .any(|s| s.chain_id == e.chain_id)
+            })
+            .map(|e| e.chain_id)
+            .collect();
+        if let [winner] = alive[..] {
+            self.finish(winner, 1, entrants.len());
+            self.state.entrants.set(entrants);
+            self.state.status.set(TournamentStatus::Finished);
+            return;
+        }
+
+        self.level_blinds();
+        self.balance_tables(&mut entrants);
+        self.state.entrants.set(entrants);
+    }
+
+    /// Record where a player finished and pay the prize for that place out of
+    /// the prize pool, through the token application
+    fn finish(&mut self, chain_id: ChainId, place: u32, entrants: usize) {
+        let config = match self.state.config.get() {
+            Some(config) => config.clone(),
+            None => return,
+        };
+        let prize = prize_payouts(*self.state.prize_pool.get(), &config.payouts, entrants)
+            .get(place as usize - 1)
+            .copied()
+            .unwrap_or(Amount::ZERO);
+
+        let mut standings = self.state.standings.get().clone();
+        standings.push(Standing {
+            chain_id,
+            place,
+            prize,
+        });
+        self.state.standings.set(standings);
+        self.pay(chain_id, prize);
+    }
+
+    /// Transfer tokens from this chain to a player's through the tournament's
+    /// token app, if it has one
+    ///
+    /// Payments are whole tokens (only the winner's prize can carry a
+    /// fraction of the pool).
+    fn pay(&mut self, chain_id: ChainId, amount: Amount) {
+        let token_app = match self.state.config.get().as_ref().and_then(|c| c.token_app) {
+            Some(token_app) => token_app,
+            None => return,
+        };
+        let tokens = u64::try_from(amount.to_attos() / Amount::ONE.to_attos()).unwrap_or(0);
+        if tokens > 0 {
+            self.runtime.call_application(
+                /* authenticated */ true,
+                token_app.with_abi::<TokenAbi>(),
+                &TokenOperation::Transfer {
+                    to_chain: chain_id,
+                    amount: tokens,
+                    // Buy-ins and prizes aren't won in any one hand
+                    game_id: 0,
+                },
+            );
+        }
+    }
+
+    /// Move every table in play to the level the schedule has reached
+    fn level_blinds(&mut self) {
+        let config = match self.state.config.get() {
+            Some(config) => config.clone(),
+            None => return,
+        };
+        let start_block = self.state.start_block.get().unwrap_or_default();
+        let blocks_elapsed = self.runtime.block_height().0.saturating_sub(start_block);
+        let level = config.level_index(*self.state.hands_played.get(), blocks_elapsed) as u32;
+        self.state.blind_level.set(level);
+
+        let mut tables = self.state.tables.get().clone();
+        for table in tables
+            .iter_mut()
+            .filter(|t| !t.broken && t.blind_level != level)
+        {
+            table.blind_level = level;
+            self.runtime
+                .prepare_message(Message::SetBlindLevel {
+                    table_app: table.table_app,
+                    level,
+                })
+                .with_authentication()
+                .send_to(table.chain_id);
+        }
+        self.state.tables.set(tables);
+    }
+
+    /// Break the smallest table once the others have seats for its players,
+    /// otherwise move one player from the biggest table to the smallest when
+    /// they're two or more apart. Waits while any move is still under way.
+    fn balance_tables(&mut self, entrants: &mut [Entrant]) {
+        if entrants.iter().any(|e| e.moving) {
+            return;
+        }
+
+        let mut tables = self.state.tables.get().clone();
+        let seats = *self.state.seats_per_table.get() as usize;
+        let mut counts: Vec<(usize, usize)> = tables
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| !t.broken)
+            .map(|(i, t)| (i, Self::seated_at(entrants, t.chain_id)))
+            .collect();
+        if counts.len() < 2 {
+            return;
+        }
+        counts.sort_by_key(|(_, count)| *count);
+        let (smallest, smallest_count) = counts[0];
+        let (largest, largest_count) = counts[counts.len() - 1];
+        let seated: usize = counts.iter().map(|(_, count)| count).sum();
+
+        let moving: Vec<usize> = if seated <= (counts.len() - 1) * seats {
+            tables[smallest].broken = true;
+            entrants
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| e.table == Some(tables[smallest].chain_id))
+                .map(|(i, _)| i)
+                .collect()
+        } else if largest_count >= smallest_count + 2 {
+            entrants
+                .iter()
+                .position(|e| e.table == Some(tables[largest].chain_id))
+                .into_iter()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for idx in moving {
+            let table = match tables
+                .iter()
+                .find(|t| Some(t.chain_id) == entrants[idx].table)
+            {
+                Some(table) => table,
+                None => continue,
+            };
+            entrants[idx].moving = true;
+            self.runtime
+                .prepare_message(Message::MovePlayer {
+                    table_app: table.table_app,
+                    player_chain: entrants[idx].chain_id,
+                })
+                .with_authentication()
+                .send_to(table.chain_id);
+        }
+        self.state.tables.set(tables);
+    }
+
+    /// Players at a table who are staying there
+    fn seated_at(entrants: &[Entrant], table_chain: ChainId) -> usize {
+        entrants
+            .iter()
+            .filter(|e| e.table == Some(table_chain) && !e.moving)
+            .count()
+    }
+
+    /// Table in play with the fewest players staying, other than `except`
+    fn emptiest_table(
+        &self,
+        entrants: &[Entrant],
+        except: Option<ChainId>,
+    ) -> Option<TournamentTable> {
+        self.state
+            .tables
+            .get()
+            .iter()
+            .filter(|t| !t.broken && Some(t.chain_id) != except)
+            .min_by_key(|t| Self::seated_at(entrants, t.chain_id))
+            .cloned()
+    }
+}
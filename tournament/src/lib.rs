@@ -0,0 +1,202 @@
+//! Linera Poker - Tournament Contract (Coordinator Chain) ABI
+//!
+//! Runs a multi-table tournament across table chains whose `TournamentConfig`
+//! names this chain as coordinator. Players register here; the coordinator
+//! seats them, keeps every table on the same blind level, moves players to
+//! balance the tables, breaks tables as the field shrinks and pays the prizes.
+
+use async_graphql::{Request, Response};
+use linera_sdk::linera_base_types::{Amount, ApplicationId, ChainId, ContractAbi, ServiceAbi};
+use serde::{Deserialize, Serialize};
+
+pub use linera_poker_shared::{TableReport, TournamentConfig};
+
+/// Tournament contract ABI
+pub struct TournamentAbi;
+
+impl ContractAbi for TournamentAbi {
+    type Operation = TournamentOperation;
+    type Response = TournamentResult;
+}
+
+impl ServiceAbi for TournamentAbi {
+    type Query = Request;
+    type QueryResponse = Response;
+}
+
+/// Result of tournament operations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TournamentResult {
+    Success,
+    Error(TournamentError),
+}
+
+/// Tournament errors
+#[derive(Debug, Clone, Serialize, Deserialize, thiserror::Error)]
+pub enum TournamentError {
+    #[error("Only the coordinator chain can run the tournament")]
+    Unauthorized,
+    #[error("Registration is closed")]
+    RegistrationClosed,
+    #[error("Not enough players")]
+    NotEnoughPlayers,
+    #[error("Too many players for the tables")]
+    TooManyPlayers,
+    #[error("Tournament is not running")]
+    NotRunning,
+    #[error("Buy-in could not be paid")]
+    BuyInNotPaid,
+}
+
+/// Tournament operations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TournamentOperation {
+    /// Register for the tournament (player chain), playing through `hand_app_id`,
+    /// committing to the entropy for the first hand dealt and registering the
+    /// card key every table seals the player's cards to.
+    ///
+    /// The buy-in is paid to the coordinator chain through `token_app`, the
+    /// tournament's token application (None when it has none), before the
+    /// registration is sent.
+    Register {
+        hand_app_id: ApplicationId,
+        buy_in: u64,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+        token_app: Option<ApplicationId>,
+    },
+    /// Close registration and seat the entrants (coordinator chain)
+    Start,
+    /// Collect stacks, knockouts and departures from every table in play,
+    /// then level the blinds and balance the tables (coordinator chain)
+    Sync,
+}
+
+/// Instantiation argument
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstantiationArgument {
+    pub tournament: TournamentConfig,
+    /// Table chains and their table applications, each instantiated with
+    /// this chain as the tournament's coordinator
+    pub tables: Vec<(ChainId, ApplicationId)>,
+    /// Players seated at each table (at most the tables' `max_players`)
+    pub seats_per_table: u8,
+}
+
+/// Cross-chain messages for the Tournament contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    // ═══════════════════════════════════════════════════════════════════
+    // Player chain → Coordinator
+    // ═══════════════════════════════════════════════════════════════════
+    /// Player registers with their buy-in, paid through `token_app`
+    Register {
+        hand_app_id: ApplicationId,
+        buy_in: Amount,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+        token_app: Option<ApplicationId>,
+    },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Table chain → Player chain
+    // ═══════════════════════════════════════════════════════════════════
+    /// Player is assigned the seat the table holds for them
+    Assign {
+        hand_app_id: ApplicationId,
+        table_chain: ChainId,
+        table_app: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Player chain → Table chain
+    // ═══════════════════════════════════════════════════════════════════
+    /// Player takes the seat they were assigned
+    SeatPlayer {
+        table_app: ApplicationId,
+        hand_app_id: ApplicationId,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Coordinator → Table chain
+    // ═══════════════════════════════════════════════════════════════════
+    /// Table holds a seat for a registered player with their chips, then
+    /// assigns it to them
+    ReserveSeat {
+        table_app: ApplicationId,
+        player_chain: ChainId,
+        hand_app_id: ApplicationId,
+        chips: Amount,
+        entropy_commitment: [u8; 32],
+        card_key: Vec<u8>,
+    },
+    /// Player leaves the table with their chips, to be seated elsewhere
+    MovePlayer {
+        table_app: ApplicationId,
+        player_chain: ChainId,
+    },
+    /// Table plays this level of the blind schedule from its next hand
+    SetBlindLevel {
+        table_app: ApplicationId,
+        level: u32,
+    },
+    /// Table reports back to the coordinator
+    RequestReport { table_app: ApplicationId },
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Table chain → Coordinator
+    // ═══════════════════════════════════════════════════════════════════
+    /// Stacks, knockouts and departures at a table
+    TableReport { report: TableReport },
+}
+
+/// Tournament progress
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentStatus {
+    #[default]
+    Registering,
+    Running,
+    Finished,
+}
+
+/// Table the tournament plays at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TournamentTable {
+    pub chain_id: ChainId,
+    pub table_app: ApplicationId,
+    /// Blind level last sent to the table
+    pub blind_level: u32,
+    /// Table was broken up; its players were moved to the other tables
+    pub broken: bool,
+}
+
+/// Registered player
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entrant {
+    pub chain_id: ChainId,
+    pub hand_app: ApplicationId,
+    /// Chips as of the last report from the player's table
+    pub chips: Amount,
+    /// Table the player sits at (None between tables or once knocked out)
+    pub table: Option<ChainId>,
+    /// Player was told to leave their table and hasn't been reported gone yet
+    pub moving: bool,
+    /// Entropy commitment the player holds for their next hand, handed to
+    /// every table they are seated at
+    pub entropy_commitment: [u8; 32],
+    /// Card key the player registered, handed to every table they are seated at
+    pub card_key: Vec<u8>,
+}
+
+/// Where a player finished and what they won
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    pub chain_id: ChainId,
+    /// Finishing position (1 = winner)
+    pub place: u32,
+    pub prize: Amount,
+}
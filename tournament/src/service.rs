@@ -0,0 +1,167 @@
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+mod state;
+
+use std::sync::Arc;
+
+use self::state::TournamentState;
+use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
+use linera_poker_shared::prize_payouts;
+use linera_poker_tournament::TournamentAbi;
+use linera_sdk::{
+    linera_base_types::{Amount, WithServiceAbi},
+    views::View,
+    Service, ServiceRuntime,
+};
+
+pub struct TournamentService {
+    state: Arc<TournamentState>,
+}
+
+linera_sdk::service!(TournamentService);
+
+impl WithServiceAbi for TournamentService {
+    type Abi = TournamentAbi;
+}
+
+impl Service for TournamentService {
+    type Parameters = ();
+
+    async fn new(runtime: ServiceRuntime<Self>) -> Self {
+        let state = TournamentState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to load state");
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    async fn handle_query(&self, request: Request) -> Response {
+        let schema = Schema::build(
+            QueryRoot {
+                state: self.state.clone(),
+            },
+            MutationRoot,
+            EmptySubscription,
+        )
+        .finish();
+        schema.execute(request).await
+    }
+}
+
+struct QueryRoot {
+    state: Arc<TournamentState>,
+}
+
+#[Object]
+impl QueryRoot {
+    /// Get the tournament: prizes, blind level, tables and standings
+    async fn tournament(&self) -> Option<TournamentView> {
+        let config = self.state.config.get().as_ref()?;
+        let prize_pool = *self.state.prize_pool.get();
+        let entrants = self.state.entrants.get();
+        Some(TournamentView {
+            status: format!("{:?}", self.state.status.get()),
+            buy_in: Amount::from_tokens(config.buy_in.into()).to_string(),
+            starting_chips: Amount::from_tokens(config.starting_chips.into()).to_string(),
+            entrants: entrants.len() as u32,
+            prize_pool: prize_pool.to_string(),
+            prizes: prize_payouts(prize_pool, &config.payouts, entrants.len())
+                .iter()
+                .map(|prize| prize.to_string())
+                .collect(),
+            hands_played: *self.state.hands_played.get(),
+            level: *self.state.blind_level.get(),
+            tables: self
+                .state
+                .tables
+                .get()
+                .iter()
+                .map(|table| TableView {
+                    chain_id: table.chain_id.to_string(),
+                    players: entrants
+                        .iter()
+                        .filter(|e| e.table == Some(table.chain_id))
+                        .map(|e| PlayerView {
+                            chain_id: e.chain_id.to_string(),
+                            chips: e.chips.to_string(),
+                            moving: e.moving,
+                        })
+                        .collect(),
+                    level: table.blind_level,
+                    broken: table.broken,
+                })
+                .collect(),
+            standings: self
+                .state
+                .standings
+                .get()
+                .iter()
+                .map(|standing| StandingView {
+                    chain_id: standing.chain_id.to_string(),
+                    place: standing.place,
+                    prize: standing.prize.to_string(),
+                })
+                .collect(),
+        })
+    }
+}
+
+struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    /// Placeholder mutation
+    async fn noop(&self) -> bool {
+        true
+    }
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct TournamentView {
+    /// Registering, Running or Finished
+    status: String,
+    /// Tokens paid to enter
+    buy_in: String,
+    /// Tournament chips each player started with
+    starting_chips: String,
+    entrants: u32,
+    /// Buy-ins collected, in tokens
+    prize_pool: String,
+    /// Prize for each paid place, first place first
+    prizes: Vec<String>,
+    /// Most hands played at any table
+    hands_played: u32,
+    /// Index into the blind schedule of the level in play
+    level: u32,
+    tables: Vec<TableView>,
+    /// Players knocked out so far, then the winner
+    standings: Vec<StandingView>,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct TableView {
+    chain_id: String,
+    players: Vec<PlayerView>,
+    /// Blind level last sent to the table
+    level: u32,
+    /// Table was broken up and its players moved
+    broken: bool,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct PlayerView {
+    chain_id: String,
+    /// Chips as of the table's last report
+    chips: String,
+    /// Leaving for another table
+    moving: bool,
+}
+
+#[derive(async_graphql::SimpleObject)]
+struct StandingView {
+    chain_id: String,
+    /// Finishing position (1 = winner)
+    place: u32,
+    prize: String,
+}
@@ -0,0 +1,32 @@
+//! Tournament contract state using Linera views
+
+use linera_poker_tournament::{
+    Entrant, Standing, TournamentConfig, TournamentStatus, TournamentTable,
+};
+use linera_sdk::{
+    linera_base_types::Amount,
+    views::{linera_views, RegisterView, RootView, ViewStorageContext},
+};
+
+/// Tournament state - the field, the tables and the results
+#[derive(RootView)]
+#[view(context = ViewStorageContext)]
+pub struct TournamentState {
+    /// Buy-in, chips, blind schedule and payouts
+    pub config: RegisterView<Option<TournamentConfig>>,
+    /// Players seated at each table
+    pub seats_per_table: RegisterView<u8>,
+    pub tables: RegisterView<Vec<TournamentTable>>,
+    pub entrants: RegisterView<Vec<Entrant>>,
+    /// Buy-ins collected, in tokens
+    pub prize_pool: RegisterView<Amount>,
+    pub status: RegisterView<TournamentStatus>,
+    /// Block height the tournament started at (blind levels timed in blocks)
+    pub start_block: RegisterView<Option<u64>>,
+    /// Index into the blind schedule of the level in play
+    pub blind_level: RegisterView<u32>,
+    /// Most hands played at any table
+    pub hands_played: RegisterView<u32>,
+    /// Players knocked out so far, then the winner
+    pub standings: RegisterView<Vec<Standing>>,
+}
@@ -0,0 +1,129 @@
+//! Integration tests for Linera Poker Tournament Contract
+//!
+//! Tests registration on the coordinator chain, with buy-ins paid through
+//! the token application.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use linera_poker_shared::entropy_commitment;
+use linera_poker_shared::zk::card_key;
+use linera_poker_table::{BlindLevel, LevelDuration};
+use linera_poker_token::{
+    InstantiationArgument as TokenInstantiationArgument, TokenAbi, TokenOperation,
+};
+use linera_poker_tournament::{
+    InstantiationArgument, TournamentAbi, TournamentConfig, TournamentOperation,
+};
+use linera_sdk::{
+    linera_base_types::{AccountOwner, Amount},
+    test::{QueryOutcome, TestValidator},
+};
+
+/// Tournament with a 50-token buy-in held by `token_app`
+fn tournament(token_app: Option<linera_sdk::linera_base_types::ApplicationId>) -> TournamentConfig {
+    TournamentConfig {
+        buy_in: 50,
+        starting_chips: 1000,
+        levels: vec![BlindLevel {
+            small_blind: 5,
+            big_blind: 10,
+            ante: 0,
+        }],
+        level_duration: LevelDuration::Hands(10),
+        payouts: vec![100],
+        token_app,
+        coordinator: None,
+    }
+}
+
+/// Only a buy-in paid through the token app registers a player and joins
+/// the prize pool
+#[tokio::test(flavor = "multi_thread")]
+async fn test_register_requires_paid_buy_in() {
+    let (validator, module_id) =
+        TestValidator::with_current_module::<TournamentAbi, (), InstantiationArgument>().await;
+
+    let mut coordinator = validator.new_chain().await;
+    let broke = validator.new_chain().await;
+    let funded = validator.new_chain().await;
+
+    let token_module = coordinator
+        .publish_bytecode_files_in::<TokenAbi, (), TokenInstantiationArgument>("../token")
+        .await;
+    let token_app = coordinator
+        .create_application(
+            token_module,
+            (),
+            TokenInstantiationArgument {
+                owner: AccountOwner::CHAIN,
+                initial_balance: 0,
+            },
+            vec![],
+        )
+        .await;
+    let app_id = coordinator
+        .create_application(
+            module_id,
+            (),
+            InstantiationArgument {
+                tournament: tournament(Some(token_app.forget_abi())),
+                tables: Vec::new(),
+                seats_per_table: 2,
+            },
+            vec![],
+        )
+        .await;
+
+    funded
+        .add_block(|block| {
+            block.with_operation(token_app, TokenOperation::Deposit { amount: 50 });
+        })
+        .await;
+    for player_chain in [&broke, &funded] {
+        player_chain
+            .add_block(|block| {
+                block.with_operation(
+                    app_id,
+                    TournamentOperation::Register {
+                        hand_app_id: app_id.forget_abi(),
+                        buy_in: 50,
+                        entropy_commitment: entropy_commitment(&[7; 32]),
+                        card_key: card_key(&[11; 32]),
+                        token_app: Some(token_app.forget_abi()),
+                    },
+                );
+            })
+            .await;
+    }
+    // Claiming a buy-in without paying it through the token app
+    broke
+        .add_block(|block| {
+            block.with_operation(
+                app_id,
+                TournamentOperation::Register {
+                    hand_app_id: app_id.forget_abi(),
+                    buy_in: 50,
+                    entropy_commitment: entropy_commitment(&[7; 32]),
+                    card_key: card_key(&[11; 32]),
+                    token_app: None,
+                },
+            );
+        })
+        .await;
+    coordinator.handle_received_messages().await;
+
+    let QueryOutcome { response, .. } = coordinator
+        .graphql_query(app_id, "query { tournament { entrants prizePool } }")
+        .await;
+    assert_eq!(response["tournament"]["entrants"], 1);
+    assert_eq!(
+        response["tournament"]["prizePool"],
+        Amount::from_tokens(50).to_string()
+    );
+    let QueryOutcome { response, .. } = coordinator
+        .graphql_query(token_app, "query { balance }")
+        .await;
+    assert_eq!(response["balance"], Amount::from_tokens(50).to_string());
+    let QueryOutcome { response, .. } = broke.graphql_query(token_app, "query { balance }").await;
+    assert_eq!(response["balance"], Amount::ZERO.to_string());
+}